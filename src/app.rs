@@ -1,14 +1,39 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Local, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 
-use crate::domain::{Plant, HarvestResult};
+use crate::ascii::hires::RenderStyle;
+use crate::config::{Action, KeyConfig};
+use crate::domain::breeding::{self, Parent};
+use crate::domain::curing::CuringJar;
+use crate::domain::difficulty::{CareBands, Difficulty};
+use crate::domain::drying::DryingBatch;
+use crate::domain::environment;
+use crate::domain::goals::Goal;
+use crate::domain::events::{push_event, RoomEvent};
+use crate::domain::plant::{
+    HUMIDITY_OPTIMAL_MAX, HUMIDITY_OPTIMAL_MIN, OVERWATER_LEVEL, PARTIAL_HARVEST_SHARE, PARTIAL_RIPENING_DAYS,
+    TEMP_OPTIMAL_MAX, TEMP_OPTIMAL_MIN,
+};
+use crate::domain::genetics::Genetics;
+use crate::domain::rng::{random_uuid, GameRng};
+use crate::domain::share::SharedPlant;
+use crate::domain::strains::{self, StrainDatabase};
+use crate::domain::{Grade, GrowMedium, GrowthStage, Plant, HarvestResult, LifetimeStats, Seed};
 use crate::message::Screen;
-use crate::ui::colors::{ColorPalette, create_palette};
+use crate::storage::{SaveFormat, SaveState, AUTOSAVE_INTERVALS_SECS, SAVE_VERSION};
+use crate::ui::colors::{ColorDepth, ColorPalette, create_palette};
+use crate::ui::export::Export;
+use crate::ui::goals;
+use crate::ui::smoothing::DisplayedGauges;
+use crate::ui::strains::{matches_query, LibrarySort};
+use crate::ui::themes::{self, CustomPalette};
+use crate::ui::units::{format_weight, WeightUnit};
 use crate::ui::visual_mode::VisualMode;
 
 /// Default color palette for deserialization (fallback to Basic16)
 fn default_color_palette() -> Box<dyn ColorPalette> {
-    create_palette(false, VisualMode::Normal)
+    create_palette(ColorDepth::Basic16, VisualMode::Normal, None)
 }
 
 /// Default visual mode for deserialization
@@ -16,16 +41,162 @@ fn default_visual_mode() -> VisualMode {
     VisualMode::Normal
 }
 
+/// Default auto-care setting for deserialization
+fn default_auto_care() -> bool {
+    true
+}
+
+/// Default frame interval for deserialization
+fn default_frame_interval_ms() -> u64 {
+    FRAME_INTERVALS_MS[0]
+}
+
+/// Default auto-harvest delay for deserialization
+fn default_auto_harvest_delay_days() -> u32 {
+    DEFAULT_AUTO_HARVEST_DELAY_DAYS
+}
+
+/// Default autosave interval for deserialization
+fn default_autosave_seconds() -> u64 {
+    AUTOSAVE_INTERVALS_SECS[0]
+}
+
+/// Local calendar date when the session started
+fn today() -> NaiveDate {
+    Local::now().date_naive()
+}
+
+/// Frame interval presets: smooth, then two "eco" modes
+pub const FRAME_INTERVALS_MS: [u64; 3] = [50, 100, 250];
+
+/// Poll interval while the terminal doesn't have focus
+pub const UNFOCUSED_INTERVAL_MS: u64 = 1000;
+
+/// How long the plant border flashes when it becomes ready
+const HARVEST_FLASH_MS: u64 = 1200;
+
+/// How long a toast stays on screen
+const TOAST_MS: u64 = 3000;
+
+/// How long a harvest can be undone (short enough that the batch is still hanging)
+const UNDO_HARVEST_MS: u64 = 3000;
+
+/// How long "Saved" shows on the status line after a manual save
+const SAVED_FLASH_MS: u64 = 1500;
+
+/// Game time runs this many times faster than real time
+const TIME_SCALE: f32 = 130000.0;
+
+/// Real seconds simulated per fast-forward step (~6 game hours)
+const FAST_FORWARD_STEP_SECONDS: f32 = 6.0 * 3600.0 / TIME_SCALE;
+
+/// Offline growth presets in game days (0 = the grow waits while the game is closed)
+pub const OFFLINE_GROWTH_DAYS: [u32; 4] = [0, 1, 3, 7];
+
+/// Upper bound on fast-forward steps, in case a plant never progresses
+const FAST_FORWARD_MAX_STEPS: u32 = 10_000;
+
+/// Light intensity step for manual adjustment
+const LIGHT_STEP: f32 = 5.0;
+
+/// Humidity below which strong light scorches the buds
+const LIGHT_BURN_HUMIDITY: f32 = 55.0;
+
+/// Days a ready plant waits before auto-harvest takes it, unless the player changes it
+const DEFAULT_AUTO_HARVEST_DELAY_DAYS: u32 = 10;
+/// Longest wait the auto-harvest delay can be set to
+const MAX_AUTO_HARVEST_DELAY_DAYS: u32 = 30;
+
+/// Shown when the visual mode keys are pressed on a 16-color terminal
+const VISUAL_MODES_NEED_COLORS: &str = "Visual modes require a 256-color or truecolor terminal";
+
+/// Canopy growth multiplier while the roots are drowning
+const OVERWATER_CANOPY_PENALTY: f32 = 0.85;
+
+/// Number of frames covering a duration at a given frame interval
+fn frames_for(duration_ms: u64, frame_interval_ms: u64) -> u64 {
+    (duration_ms / frame_interval_ms.max(1)).max(1)
+}
+
+/// A harvest that can still be taken back
+#[derive(Debug, Clone)]
+pub struct HarvestUndo {
+    pub plant: Plant,                 // The plant as it was before the cut
+    pub batch_started: DateTime<Utc>, // Identifies the drying batch it became
+    pub seed: Option<Seed>,           // Queued seed the replant used up
+    pub frames_left: u16,
+}
+
+/// Short-lived status message shown over the current screen
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub text: String,
+    pub frames_left: u16,
+    pub grade: Option<Grade>, // Drawn as a big letter beside the text
+}
+
 /// Main application state (Model in TEA)
 #[derive(Debug, Serialize, Deserialize)]
 pub struct App {
+    #[serde(default)]
+    pub version: u32, // Save format version (0 = saved before versioning)
     pub current_plant: Option<Plant>,
     pub harvest_history: Vec<HarvestResult>,
     pub last_tick: DateTime<Utc>,
     pub total_harvests: u32,
-    pub auto_harvest: bool, // Full auto mode - auto-harvest 10 days after ReadyToHarvest
+    pub auto_harvest: bool, // Full auto mode - auto-harvest once a ready plant has waited the delay
+    #[serde(default = "default_auto_harvest_delay_days")]
+    pub auto_harvest_delay_days: u32, // Days a ready plant ages before auto-harvest takes it
     #[serde(default = "default_visual_mode")]
     pub visual_mode: VisualMode,
+    #[serde(default)]
+    pub theme: Option<String>, // Custom palette (palettes/<name>.toml) drawn in Normal mode
+    #[serde(default = "default_auto_care")]
+    pub auto_care: bool, // Auto-care keeps water, nutrients and light in range
+    #[serde(default)]
+    pub co2_enrichment: bool, // CO2 tank/burner running
+    #[serde(default)]
+    pub enable_bell: bool, // Ring the terminal bell when a plant becomes ready
+    #[serde(default)]
+    pub compress_saves: bool, // Write the save gzipped (save.json.gz, save.ron.gz)
+    #[serde(default)]
+    pub save_format: SaveFormat, // JSON or RON (switched over on the next save)
+    #[serde(default)]
+    pub pause_when_ready: bool, // Freeze a ready plant until harvested (with auto-harvest off)
+    #[serde(default)]
+    pub weight_unit: WeightUnit, // Grams or ounces in displayed weights
+    #[serde(default)]
+    pub seed_inventory: Vec<Seed>, // Bred seeds, planted oldest first
+    #[serde(default)]
+    pub seeds_bred: u32, // Number of crosses made (used in hybrid names)
+    #[serde(default = "default_frame_interval_ms")]
+    pub frame_interval_ms: u64, // Event poll interval (higher = less CPU)
+    #[serde(default = "default_autosave_seconds")]
+    pub autosave_seconds: u64, // Real seconds between autosaves (0 = only on exit)
+    #[serde(default)]
+    pub offline_growth_days: u32, // Game days the grow may catch up after the game was closed
+    #[serde(default)]
+    pub difficulty: Difficulty, // Width of the water/nutrient bands care must stay in
+    #[serde(default)]
+    pub medium: GrowMedium, // Medium new plants are potted in
+    #[serde(default)]
+    pub render_style: RenderStyle, // ASCII, half-block or braille plant
+    #[serde(default)]
+    pub reduced_motion: bool, // Hold every animation still (sway, blinks, sparkles, falling leaves)
+    #[serde(default)]
+    pub drying: Vec<DryingBatch>, // Harvests hanging to dry, oldest first
+    #[serde(default)]
+    pub curing: Option<CuringJar>, // Optional jar curing one finished harvest
+    #[serde(default)]
+    pub rng: GameRng, // Every random roll draws from here (seed + position are saved)
+    #[serde(default)]
+    pub events: Vec<RoomEvent>, // Grow-room ticker (light switches, stage changes, auto-harvests)
+    #[serde(default)]
+    pub goal: Option<Goal>, // Season goal picked on the goals screen
+    #[serde(default)]
+    pub goal_completed: bool, // Active goal was met (celebrated once)
+    #[serde(default)]
+    pub lifetime: LifetimeStats, // Totals across every game (new games keep them)
 
     // UI state (not serialized in some cases, but we'll keep it simple)
     #[serde(skip)]
@@ -36,86 +207,1060 @@ pub struct App {
     pub animation_frame: usize,
     #[serde(skip, default = "default_color_palette")]
     pub color_palette: Box<dyn ColorPalette>,
+    #[serde(skip)]
+    pub custom_palette: Option<CustomPalette>, // Loaded from `theme`
+    #[serde(skip)]
+    pub palettes_dir: Option<PathBuf>, // Where `theme` palettes are read from
+    #[serde(skip)]
+    pub show_help: bool,
+    #[serde(skip)]
+    pub keys: KeyConfig,
+    #[serde(skip)]
+    pub harvest_flash_frames: u16, // Remaining frames of the "ready" border flash
+    #[serde(skip)]
+    pub last_harvest_undo: Option<HarvestUndo>, // Most recent harvest, while it can be undone
+    #[serde(skip)]
+    pub bell_pending: bool, // Set when the event loop should ring the bell
+    #[serde(skip)]
+    pub toast: Option<Toast>,
+    #[serde(skip)]
+    pub status_error: Option<String>, // Persistent error shown on the status line
+    #[serde(skip)]
+    pub focused: bool, // Terminal has focus (assumed until told otherwise)
+    #[serde(skip)]
+    pub unicode: bool, // Terminal locale is UTF-8 (set at startup; otherwise the plant stays ASCII)
+    #[serde(skip)]
+    pub breeding_cursor: usize, // Highlighted harvest on the breeding screen
+    #[serde(skip)]
+    pub breeding_parents: Vec<usize>, // Selected harvest indices (at most 2)
+    #[serde(skip)]
+    pub library_cursor: usize, // Highlighted row on the strain library
+    #[serde(skip)]
+    pub library_sort: LibrarySort,
+    #[serde(skip)]
+    pub library_query: String, // Strain library name filter (empty shows every strain)
+    #[serde(skip)]
+    pub library_typing: bool, // Keys go into the library filter until Enter or Esc
+    #[serde(skip)]
+    pub compare_cursors: [usize; 2], // Strain picked on each side of the comparison
+    #[serde(skip)]
+    pub compare_focus: usize, // Side the cursor keys move (0 left, 1 right)
+    #[serde(skip)]
+    pub goal_cursor: usize, // Highlighted preset on the goals screen
+    #[serde(skip)]
+    pub gauges: DisplayedGauges, // Eased gauge readings (presentation only)
+    #[serde(skip)]
+    pub pending_harvest: Option<HarvestResult>, // Projected harvest awaiting confirmation
+    #[serde(skip)]
+    pub profile: Option<String>, // Save profile being played (None when nothing is saved)
+    #[serde(skip)]
+    pub save_dir: Option<PathBuf>, // Directory the save is written to (None when nothing is saved)
+    #[serde(skip)]
+    pub confirm_new_game: bool, // New game key pressed once; the next press starts over
+    #[serde(skip)]
+    pub confirm_quit: bool, // Quit pressed with unsaved changes; waiting for save / discard
+    #[serde(skip)]
+    pub save_state: SaveState, // Unsaved changes and time of the last save
+    #[serde(skip)]
+    pub save_requested: bool, // Save key pressed; the event loop saves right away
+    #[serde(skip)]
+    pub export_requested: Option<Export>, // Export key pressed; the event loop writes the file
+    #[serde(skip)]
+    pub saved_flash_frames: u16, // Remaining frames of the "Saved" flash
+    #[serde(skip)]
+    pub share_code: Option<String>, // Exported plant code shown in a popup
+    #[serde(skip)]
+    pub code_input: Option<String>, // Plant code being typed/pasted for import
+    #[serde(skip)]
+    pub notice: Option<String>, // Important message that stays up until a key is pressed
+    #[serde(skip, default = "today")]
+    pub session_date: NaiveDate, // Picks the strain of the day (pinned for the session)
 }
 
 impl App {
     /// Create a new application with default state - starts with a plant
-    pub fn new(supports_truecolor: bool) -> Self {
+    pub fn new(depth: ColorDepth) -> Self {
+        Self::with_rng(depth, GameRng::from_entropy())
+    }
+
+    /// New game whose plants, genetics and crosses all replay from `seed`
+    pub fn with_seed(depth: ColorDepth, seed: u64) -> Self {
+        Self::with_rng(depth, GameRng::from_seed(seed))
+    }
+
+    fn with_rng(depth: ColorDepth, rng: GameRng) -> Self {
         let mut app = Self {
+            version: SAVE_VERSION,
             current_plant: None,
             harvest_history: Vec::new(),
+            lifetime: LifetimeStats::default(),
             last_tick: Utc::now(),
             total_harvests: 0,
             auto_harvest: false, // Full auto mode off by default
+            auto_harvest_delay_days: DEFAULT_AUTO_HARVEST_DELAY_DAYS,
             visual_mode: VisualMode::Normal,
+            theme: None,
+            auto_care: true,
+            co2_enrichment: false,
+            enable_bell: false,
+            compress_saves: false,
+            save_format: SaveFormat::default(),
+            pause_when_ready: false,
+            weight_unit: WeightUnit::Grams,
+            seed_inventory: Vec::new(),
+            seeds_bred: 0,
+            frame_interval_ms: default_frame_interval_ms(),
+            autosave_seconds: default_autosave_seconds(),
+            offline_growth_days: 0,
+            difficulty: Difficulty::default(),
+            medium: GrowMedium::default(),
+            render_style: RenderStyle::default(),
+            reduced_motion: false,
+            drying: Vec::new(),
+            curing: None,
+            rng,
+            events: Vec::new(),
+            goal: None,
+            goal_completed: false,
             current_screen: Screen::GrowingRoom,
             running: true,
             animation_frame: 0,
-            color_palette: create_palette(supports_truecolor, VisualMode::Normal),
+            color_palette: create_palette(depth, VisualMode::Normal, None),
+            custom_palette: None,
+            palettes_dir: themes::palettes_dir(),
+            show_help: false,
+            keys: KeyConfig::default(),
+            harvest_flash_frames: 0,
+            last_harvest_undo: None,
+            bell_pending: false,
+            toast: None,
+            status_error: None,
+            focused: true,
+            unicode: true,
+            breeding_cursor: 0,
+            breeding_parents: Vec::new(),
+            library_cursor: 0,
+            library_sort: LibrarySort::Name,
+            library_query: String::new(),
+            library_typing: false,
+            compare_cursors: [0, 1],
+            compare_focus: 0,
+            goal_cursor: 0,
+            gauges: DisplayedGauges::default(),
+            session_date: today(),
+            pending_harvest: None,
+            profile: None,
+            save_dir: None,
+            confirm_new_game: false,
+            confirm_quit: false,
+            save_state: SaveState::default(),
+            save_requested: false,
+            export_requested: None,
+            saved_flash_frames: 0,
+            share_code: None,
+            code_input: None,
+            notice: None,
         };
         // Auto-plant first seed
         app.plant_new_seed();
         app
     }
 
-    /// Plant a new seed with random genetics
+    /// Make a freshly loaded save internally consistent
+    /// Out-of-range plant values are clamped and unknown settings reset to defaults
+    pub fn sanitize(&mut self) {
+        if let Some(ref mut plant) = self.current_plant {
+            plant.sanitize();
+        }
+        if !FRAME_INTERVALS_MS.contains(&self.frame_interval_ms) {
+            self.frame_interval_ms = default_frame_interval_ms();
+        }
+        // Partial cuts share a plant with the final harvest
+        let plants_harvested = self.harvest_history.iter().filter(|h| !h.partial).count();
+        self.total_harvests = self.total_harvests.max(plants_harvested as u32);
+        let drying = self.drying.iter_mut().map(|batch| &mut batch.harvest);
+        let curing = self.curing.iter_mut().map(|jar| &mut jar.harvest);
+        for harvest in self.harvest_history.iter_mut().chain(drying).chain(curing) {
+            harvest.migrate_split();
+        }
+    }
+
+    /// Plant the next bred seed from the inventory, or random genetics if empty
     pub fn plant_new_seed(&mut self) {
-        self.current_plant = Some(Plant::new_random());
+        let mut plant = if self.seed_inventory.is_empty() {
+            Plant::new_random(&mut self.rng)
+        } else {
+            Plant::from_seed(self.seed_inventory.remove(0))
+        };
+        plant.medium = self.medium;
+        self.current_plant = Some(plant);
+    }
+
+    /// Project the harvest of a flowering or ready plant and ask for confirmation
+    pub fn preview_harvest(&mut self) {
+        if let Some(ref plant) = self.current_plant {
+            if matches!(plant.stage, GrowthStage::Flowering | GrowthStage::ReadyToHarvest) {
+                self.pending_harvest = Some(HarvestResult::from_plant(plant));
+            }
+        }
+    }
+
+    /// Cut the ripe top colas of a ready plant and leave the lower buds
+    /// to ripen for a few more days (the final harvest comes later)
+    pub fn partial_harvest(&mut self) {
+        let Some(ref mut plant) = self.current_plant else { return };
+        if !plant.can_partial_harvest() {
+            return;
+        }
+
+        let mut tops = HarvestResult::from_plant(plant);
+        tops.set_weight(tops.weight_grams * PARTIAL_HARVEST_SHARE);
+        tops.partial = true;
+        plant.partial_harvest_day = Some(plant.days_alive);
+        plant.canopy_density *= 1.0 - PARTIAL_HARVEST_SHARE;
+
+        let batch = DryingBatch::start(tops, &mut self.rng);
+        self.show_toast(format!(
+            "Cut the top colas: {} wet - the rest ripens for {} more days",
+            format_weight(batch.wet_grams, self.weight_unit),
+            PARTIAL_RIPENING_DAYS
+        ));
+        self.drying.push(batch);
     }
 
     /// Harvest current plant and auto-plant a new one
     pub fn harvest_and_replant(&mut self) {
+        // A preview open on this plant is stale once it is cut
+        self.pending_harvest = None;
         if let Some(plant) = self.current_plant.take() {
-            // Calculate harvest result with yield and quality
+            // Calculate harvest result with yield and quality, then hang it to dry
             let harvest_result = HarvestResult::from_plant(&plant);
-
-            // Record harvest
-            self.harvest_history.push(harvest_result);
+            let batch = DryingBatch::start(harvest_result, &mut self.rng);
+            self.show_toast(format!(
+                "Harvested {} wet - drying for about {:.0} days ([{}] undo)",
+                format_weight(batch.wet_grams, self.weight_unit),
+                batch.target_days,
+                self.keys.label(Action::UndoHarvest)
+            ));
+            self.last_harvest_undo = Some(HarvestUndo {
+                plant,
+                batch_started: batch.started_at,
+                seed: self.seed_inventory.first().cloned(),
+                frames_left: frames_for(UNDO_HARVEST_MS, self.frame_interval_ms) as u16,
+            });
+            self.drying.push(batch);
             self.total_harvests += 1;
+            self.lifetime.harvests += 1;
 
             // Auto-plant new seed
             self.plant_new_seed();
         }
     }
 
+    /// Put the last harvest back on the plant, if it is still hanging
+    pub fn undo_harvest(&mut self) {
+        let Some(undo) = self.last_harvest_undo.take() else {
+            return;
+        };
+        let Some(index) = self.drying.iter().rposition(|b| b.started_at == undo.batch_started) else {
+            self.show_toast("Too late to undo - that harvest has already dried");
+            return;
+        };
+        self.drying.remove(index);
+        self.total_harvests = self.total_harvests.saturating_sub(1);
+        self.lifetime.harvests = self.lifetime.harvests.saturating_sub(1);
+        if let Some(seed) = undo.seed {
+            self.seed_inventory.insert(0, seed);
+        }
+        self.show_toast(format!("Harvest undone - {} is back in the room", undo.plant.strain_name));
+        self.current_plant = Some(undo.plant);
+    }
+
+    /// Take down every dry batch and record it as a finished harvest
+    pub fn finish_drying(&mut self) {
+        let (dry, hanging): (Vec<_>, Vec<_>) = std::mem::take(&mut self.drying)
+            .into_iter()
+            .partition(DryingBatch::is_dry);
+        self.drying = hanging;
+
+        for batch in dry {
+            let harvest = batch.finish(&mut self.rng);
+
+            // Explain the score so the player knows what to improve
+            if let Some(ref breakdown) = harvest.breakdown {
+                let tip = if harvest.moldy {
+                    " - mold! take batches down sooner".to_string()
+                } else {
+                    breakdown
+                        .biggest_issue(self.difficulty.bands())
+                        .map(|tip| format!(" - tip: {}", tip))
+                        .unwrap_or_default()
+                };
+                self.show_grade_toast(
+                    format!(
+                        "Dried {} at {:.0}% quality ({}){}",
+                        format_weight(harvest.weight_grams, self.weight_unit),
+                        harvest.quality_score,
+                        breakdown.summary(),
+                        tip
+                    ),
+                    harvest.grade(),
+                );
+            }
+
+            self.lifetime.record_dried(&harvest);
+            self.harvest_history.push(harvest);
+        }
+        self.check_goal();
+    }
+
+    /// Burp the open jar, or seal the latest finished harvest in a fresh one
+    pub fn burp_jar(&mut self) {
+        if let Some(ref mut jar) = self.curing {
+            jar.burp();
+            let quality = jar.harvest.quality_score;
+            self.show_toast(format!("Burped the jar - {:.0}% quality so far", quality));
+            return;
+        }
+
+        // Only harvests that haven't been cured yet can go in a jar
+        if self.harvest_history.last().is_some_and(|h| h.cure_days.is_none()) {
+            if let Some(harvest) = self.harvest_history.pop() {
+                let jar = CuringJar::seal(harvest, &mut self.rng);
+                self.show_toast(format!(
+                    "Jarred {} - cure for about {:.0} days, burp every couple of days",
+                    jar.harvest.strain_name, jar.target_days
+                ));
+                self.curing = Some(jar);
+            }
+        }
+    }
+
+    /// Open the jar and record the cured harvest
+    pub fn finish_cure(&mut self) {
+        if let Some(jar) = self.curing.take() {
+            let harvest = jar.finish();
+            self.show_grade_toast(
+                format!(
+                    "Cured {} for {:.0} days - {:.0}% quality",
+                    harvest.strain_name,
+                    harvest.cure_days.unwrap_or_default(),
+                    harvest.quality_score
+                ),
+                harvest.grade(),
+            );
+            self.lifetime.record_quality(harvest.quality_score);
+            self.harvest_history.push(harvest);
+            self.check_goal();
+        }
+    }
+
     /// Toggle auto-harvest mode on/off
     pub fn toggle_auto_harvest(&mut self) {
         self.auto_harvest = !self.auto_harvest;
     }
 
+    /// Lengthen or shorten the wait before auto-harvest (only while auto mode is on)
+    pub fn adjust_auto_harvest_delay(&mut self, delta: i32) {
+        if !self.auto_harvest {
+            self.show_toast("Turn on auto mode to set the auto-harvest delay");
+            return;
+        }
+        self.auto_harvest_delay_days = self
+            .auto_harvest_delay_days
+            .saturating_add_signed(delta)
+            .min(MAX_AUTO_HARVEST_DELAY_DAYS);
+        self.show_toast(match self.auto_harvest_delay_days {
+            0 => "Auto-harvest as soon as the plant is ready".to_string(),
+            1 => "Auto-harvest 1 day after the plant is ready".to_string(),
+            days => format!("Auto-harvest {} days after the plant is ready", days),
+        });
+    }
+
+    /// Toggle auto-care on/off (off = manual watering, feeding and lighting)
+    pub fn toggle_auto_care(&mut self) {
+        self.auto_care = !self.auto_care;
+    }
+
+    /// Toggle the CO2 tank on/off
+    pub fn toggle_co2(&mut self) {
+        self.co2_enrichment = !self.co2_enrichment;
+    }
+
+    /// Toggle the ready-to-harvest bell on/off
+    pub fn toggle_bell(&mut self) {
+        self.enable_bell = !self.enable_bell;
+    }
+
+    /// Toggle pausing a ready plant until it's harvested
+    pub fn toggle_pause_when_ready(&mut self) {
+        self.pause_when_ready = !self.pause_when_ready;
+        self.show_toast(if self.pause_when_ready {
+            "Ready plants now pause until harvested"
+        } else {
+            "Ready plants keep aging until harvested"
+        });
+    }
+
+    /// Whether the plant's clock is frozen waiting for the harvest key
+    /// (never after a partial harvest: the lower buds are meant to keep ripening)
+    pub fn paused_for_harvest(&self) -> bool {
+        self.pause_when_ready
+            && !self.auto_harvest
+            && self.current_plant.as_ref().is_some_and(|plant| {
+                plant.stage == GrowthStage::ReadyToHarvest && plant.partial_harvest_day.is_none()
+            })
+    }
+
+    /// Switch between gzipped and plain saves (takes effect on the next save)
+    pub fn toggle_compression(&mut self) {
+        self.compress_saves = !self.compress_saves;
+        let file_name = self.save_format.file_name(self.compress_saves);
+        self.show_toast(if self.compress_saves {
+            format!("Saves are now compressed ({})", file_name)
+        } else {
+            format!("Saves are now uncompressed ({})", file_name)
+        });
+    }
+
+    /// Switch to the next save format (the file is converted on the next save)
+    pub fn cycle_save_format(&mut self) {
+        self.save_format = self.save_format.next();
+        self.show_toast(format!(
+            "Saves are now written as {} ({})",
+            self.save_format.name(),
+            self.save_format.file_name(self.compress_saves)
+        ));
+    }
+
+    /// Water and nutrient bands for the current plant: the difficulty's,
+    /// tightened by the medium it grows in
+    pub fn care_bands(&self) -> CareBands {
+        let medium = self.current_plant.as_ref().map_or(self.medium, |p| p.medium);
+        medium.bands(self.difficulty.bands())
+    }
+
+    /// Step through Easy, Normal and Hard care bands
+    pub fn cycle_difficulty(&mut self) {
+        self.difficulty = self.difficulty.next();
+        let bands = self.care_bands();
+        self.show_toast(format!(
+            "Difficulty: {} (water {:.0}-{:.0}%, nutrients {:.0}-{:.0}%)",
+            self.difficulty.name(),
+            bands.water_optimal.0,
+            bands.water_optimal.1,
+            bands.nutrient_optimal.0,
+            bands.nutrient_optimal.1
+        ));
+    }
+
+    /// Step through soil, coco and hydro for the next plant; a plant that
+    /// hasn't sprouted yet is repotted straight away
+    pub fn cycle_medium(&mut self) {
+        self.medium = self.medium.next();
+        let when = if self.repot_seed() { "this plant" } else { "the next plant" };
+        self.show_toast(format!("{} for {} ({})", self.medium.name(), when, self.medium.description()));
+    }
+
+    /// Move an unsprouted plant into the chosen medium; false once it has sprouted
+    fn repot_seed(&mut self) -> bool {
+        match self.current_plant.as_mut().filter(|p| p.stage == GrowthStage::Seed) {
+            Some(plant) => {
+                plant.medium = self.medium;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Step the plant through ASCII, half-block and braille drawing
+    pub fn cycle_render_style(&mut self) {
+        self.render_style = self.render_style.next();
+        if self.render_style.effective(self.unicode) == self.render_style {
+            self.show_toast(format!("Plant drawn with {}", self.render_style.name()));
+        } else {
+            self.show_toast(format!(
+                "Plant style: {} (needs a UTF-8 terminal - showing ASCII)",
+                self.render_style.name()
+            ));
+        }
+    }
+
+    /// Stop (or restart) the animations: the breeze, blinking gauges, sparkles and falling leaves
+    pub fn toggle_reduced_motion(&mut self) {
+        self.reduced_motion = !self.reduced_motion;
+        self.show_toast(if self.reduced_motion {
+            "Reduced motion on - animations hold still"
+        } else {
+            "Reduced motion off - the plant sways in the breeze"
+        });
+    }
+
+    /// Switch displayed weights between grams and ounces
+    pub fn toggle_weight_unit(&mut self) {
+        self.weight_unit = self.weight_unit.toggle();
+        self.show_toast(format!("Showing weights in {}", self.weight_unit.name()));
+    }
+
+    /// Re-read the strain files; plants already growing keep their genetics
+    pub fn reload_strains(&mut self) {
+        let db = strains::reload();
+        self.status_error = None;
+        self.report_strain_problems(&db);
+        self.show_toast(format!(
+            "Reloaded {} strains ({} pack{}, {} override{})",
+            db.strains.len(),
+            db.packs.len(),
+            if db.packs.len() == 1 { "" } else { "s" },
+            db.overrides.len(),
+            if db.overrides.len() == 1 { "" } else { "s" },
+        ));
+    }
+
+    /// Put strain file errors, or a count of skipped entries, on the status line
+    pub fn report_strain_problems(&mut self, db: &StrainDatabase) {
+        if !db.errors.is_empty() {
+            self.report_error(format!("Strain database error - {}", db.errors.join("; ")));
+        } else if !db.warnings.is_empty() {
+            self.report_error(format!("Skipped {} invalid strain(s) - see Stats for details", db.warnings.len()));
+        }
+    }
+
+    /// Add an error to the status line, after any already shown
+    pub fn report_error(&mut self, error: String) {
+        self.status_error = Some(match self.status_error.take() {
+            Some(shown) if shown.contains(&error) => shown,
+            Some(shown) => format!("{} | {}", shown, error),
+            None => error,
+        });
+    }
+
+    /// Load the custom palette `theme` names and draw with it. A broken file
+    /// is reported on the status line and the built-in colors stay
+    pub fn load_theme(&mut self) {
+        self.custom_palette = None;
+        if let Some(name) = self.theme.clone() {
+            let loaded = match self.palettes_dir {
+                Some(ref dir) => themes::load(dir, &name),
+                None => Err("no config directory for palettes".to_string()),
+            };
+            match loaded {
+                Ok(palette) => self.custom_palette = Some(palette),
+                Err(e) => self.report_error(format!("Palette '{}' not loaded - {}", name, e)),
+            }
+        }
+        self.color_palette = create_palette(self.color_palette.depth(), self.visual_mode, self.custom_palette.as_ref());
+    }
+
+    /// Step through the built-in colors and each palette in `palettes_dir`
+    pub fn cycle_theme(&mut self) {
+        let names = self.palettes_dir.as_deref().map(themes::list).unwrap_or_default();
+        if names.is_empty() {
+            let dir = self.palettes_dir.as_ref().map_or("the config directory".to_string(), |d| d.display().to_string());
+            self.show_toast(format!("No palettes in {}", dir));
+            return;
+        }
+        // After the last palette comes the built-in colors, then the first again
+        self.theme = match self.theme {
+            Some(ref current) => names.iter().skip_while(|name| *name != current).nth(1).cloned(),
+            None => names.first().cloned(),
+        };
+        self.load_theme();
+        let toast = match self.theme {
+            Some(ref name) if self.custom_palette.is_some() => format!("Palette: {}", name),
+            Some(ref name) => format!("Palette '{}' has errors - see the status line", name),
+            None => "Palette: built-in colors".to_string(),
+        };
+        self.show_toast(toast);
+    }
+
+    /// Toggle the help overlay
+    pub fn toggle_help(&mut self) {
+        self.show_help = !self.show_help;
+    }
+
+    /// Water the current plant
+    pub fn water_plant(&mut self) {
+        if let Some(ref mut plant) = self.current_plant {
+            plant.water();
+        }
+    }
+
+    /// Feed the current plant
+    pub fn feed_plant(&mut self) {
+        if let Some(ref mut plant) = self.current_plant {
+            plant.feed();
+        }
+    }
+
+    /// Raise (+1) or lower (-1) the lamp intensity
+    pub fn adjust_light(&mut self, direction: f32) {
+        if let Some(ref mut plant) = self.current_plant {
+            plant.adjust_light(direction * LIGHT_STEP);
+        }
+    }
+
+    /// Ask for a second press before starting over
+    pub fn request_new_game(&mut self) {
+        self.confirm_new_game = true;
+        self.show_toast(format!(
+            "Start a NEW GAME? This run is archived, then wiped. Press [{}] again to confirm, any other key cancels",
+            self.keys.label(Action::NewGame)
+        ));
+    }
+
+    /// Start over with a fresh plant and history, keeping the player's settings
+    /// (the caller archives the old run first)
+    pub fn start_new_game(&mut self) {
+        let mut fresh = App::new(self.color_palette.depth());
+        fresh.auto_harvest = self.auto_harvest;
+        fresh.auto_harvest_delay_days = self.auto_harvest_delay_days;
+        fresh.auto_care = self.auto_care;
+        fresh.enable_bell = self.enable_bell;
+        fresh.compress_saves = self.compress_saves;
+        fresh.pause_when_ready = self.pause_when_ready;
+        fresh.save_format = self.save_format;
+        fresh.weight_unit = self.weight_unit;
+        fresh.frame_interval_ms = self.frame_interval_ms;
+        fresh.autosave_seconds = self.autosave_seconds;
+        fresh.offline_growth_days = self.offline_growth_days;
+        fresh.difficulty = self.difficulty;
+        fresh.medium = self.medium;
+        fresh.repot_seed();
+        fresh.render_style = self.render_style;
+        fresh.reduced_motion = self.reduced_motion;
+        fresh.unicode = self.unicode;
+        fresh.keys = self.keys.clone();
+        fresh.profile = self.profile.clone();
+        fresh.save_dir = self.save_dir.clone();
+        fresh.lifetime = self.lifetime;
+        fresh.theme = self.theme.clone();
+        fresh.custom_palette = self.custom_palette.clone();
+        fresh.palettes_dir = self.palettes_dir.clone();
+        fresh.apply_visual_mode(self.visual_mode);
+        *self = fresh;
+        self.show_toast("New game started");
+    }
+
     /// Cycle to the next visual mode
     pub fn cycle_visual_mode(&mut self) {
-        // Only allow mode cycling in truecolor terminals
-        if !self.color_palette.supports_rgb() {
+        self.set_visual_mode(self.visual_mode.next());
+    }
+
+    /// Cycle to the previous visual mode
+    pub fn cycle_visual_mode_prev(&mut self) {
+        self.set_visual_mode(self.visual_mode.prev());
+    }
+
+    /// Jump straight to a visual mode, explaining why not on 16-color terminals
+    pub fn set_visual_mode(&mut self, mode: VisualMode) {
+        if !self.apply_visual_mode(mode) {
+            self.show_toast(VISUAL_MODES_NEED_COLORS);
+        }
+    }
+
+    /// Switch visual mode; returns false (and stays put) on 16-color terminals
+    fn apply_visual_mode(&mut self, mode: VisualMode) -> bool {
+        // Only allow mode cycling in 256-color and truecolor terminals
+        let depth = self.color_palette.depth();
+        if !depth.has_visual_modes() && mode != VisualMode::Normal {
             // In 16-color mode, visual modes don't work well - stay in Normal
+            return false;
+        }
+
+        self.visual_mode = mode;
+        self.color_palette = create_palette(depth, self.visual_mode, self.custom_palette.as_ref());
+        true
+    }
+
+    /// Move the breeding screen cursor through the harvest history
+    pub fn move_breeding_cursor(&mut self, delta: isize) {
+        let last = self.harvest_history.len().saturating_sub(1);
+        self.breeding_cursor = self.breeding_cursor.saturating_add_signed(delta).min(last);
+    }
+
+    /// Move the strain library highlight, staying within the strains the filter shows
+    pub fn move_library_cursor(&mut self, delta: isize) {
+        let shown = strains::database()
+            .strains
+            .iter()
+            .filter(|s| matches_query(&s.name, &self.library_query))
+            .count();
+        self.library_cursor = self.library_cursor.saturating_add_signed(delta).min(shown.saturating_sub(1));
+    }
+
+    /// Start typing a strain library filter (keeps any filter already set)
+    pub fn start_library_search(&mut self) {
+        self.library_typing = true;
+    }
+
+    pub fn library_search_input(&mut self, c: char) {
+        self.library_query.push(c);
+        self.library_cursor = 0;
+    }
+
+    pub fn library_search_backspace(&mut self) {
+        self.library_query.pop();
+        self.move_library_cursor(0);
+    }
+
+    /// Stop typing; the filter stays applied
+    pub fn finish_library_search(&mut self) {
+        self.library_typing = false;
+    }
+
+    /// Stop typing and show every strain again
+    pub fn cancel_library_search(&mut self) {
+        self.library_typing = false;
+        self.library_query.clear();
+        self.library_cursor = 0;
+    }
+
+    /// Move the focused side of the strain comparison through the database
+    pub fn move_compare_cursor(&mut self, delta: isize) {
+        let last = strains::database().strains.len().saturating_sub(1);
+        let cursor = &mut self.compare_cursors[self.compare_focus];
+        *cursor = cursor.saturating_add_signed(delta).min(last);
+    }
+
+    /// Hand the cursor keys to the other side of the strain comparison
+    pub fn swap_compare_focus(&mut self) {
+        self.compare_focus = 1 - self.compare_focus;
+    }
+
+    /// Queue a seed of the strain of the day so it is planted next
+    pub fn plant_strain_of_the_day(&mut self) {
+        let db = strains::database();
+        let Some(strain) = strains::strain_of_the_day(self.session_date, &db.strains) else {
             return;
+        };
+        let seed = Seed {
+            id: random_uuid(&mut self.rng),
+            strain_name: strain.name.clone(),
+            genetics: Genetics::from_strain(&mut self.rng, strain),
+            lineage: None,
+        };
+        self.show_toast(format!("{} seed added - it will be planted next", seed.strain_name));
+        self.seed_inventory.insert(0, seed);
+    }
+
+    /// Move the goals screen highlight, clamped to the preset list
+    pub fn move_goal_cursor(&mut self, delta: isize) {
+        let last = Goal::PRESETS.len() - 1;
+        self.goal_cursor = self.goal_cursor.saturating_add_signed(delta).min(last);
+    }
+
+    /// Replace the active goal (progress counts everything harvested so far)
+    pub fn set_goal(&mut self, goal: Goal) {
+        self.goal = Some(goal);
+        self.goal_completed = false;
+        self.show_toast(format!("New goal: {}", goals::description(&goal, self.weight_unit)));
+        self.check_goal();
+    }
+
+    pub fn clear_goal(&mut self) {
+        self.goal = None;
+        self.goal_completed = false;
+    }
+
+    /// Celebrate the active goal the first time it is met
+    pub fn check_goal(&mut self) {
+        let Some(goal) = self.goal else {
+            return;
+        };
+        if !self.goal_completed && goal.is_met(&self.lifetime) {
+            self.goal_completed = true;
+            self.show_toast(format!(
+                "*** Goal complete: {}! ***",
+                goals::description(&goal, self.weight_unit)
+            ));
+        }
+    }
+
+    /// Step to the next strain library sort order (back to the top of the list)
+    pub fn cycle_library_sort(&mut self) {
+        self.library_sort = self.library_sort.next();
+        self.library_cursor = 0;
+    }
+
+    /// Select or deselect the highlighted harvest as a breeding parent
+    /// Harvests saved before genetics were recorded can't be bred
+    pub fn toggle_breeding_parent(&mut self) {
+        let index = self.breeding_cursor;
+        let Some(harvest) = self.harvest_history.get(index) else {
+            return;
+        };
+        if harvest.genetics.is_none() {
+            self.show_toast("No genetics recorded for this harvest");
+            return;
+        }
+
+        if let Some(pos) = self.breeding_parents.iter().position(|&i| i == index) {
+            self.breeding_parents.remove(pos);
+        } else {
+            if self.breeding_parents.len() == 2 {
+                self.breeding_parents.remove(0);
+            }
+            self.breeding_parents.push(index);
         }
+    }
+
+    /// Cross the two selected harvests into a seed for the inventory
+    pub fn breed_selected(&mut self) {
+        let [mother, father] = self.breeding_parents[..] else {
+            self.show_toast("Select two parents to breed");
+            return;
+        };
+        let (Some(mother), Some(father)) = (self.harvest_history.get(mother), self.harvest_history.get(father)) else {
+            return;
+        };
+        let (Some(mother_genetics), Some(father_genetics)) = (&mother.genetics, &father.genetics) else {
+            return;
+        };
+
+        let seed = breeding::breed(
+            Parent { name: &mother.strain_name, genetics: mother_genetics },
+            Parent { name: &father.strain_name, genetics: father_genetics },
+            self.seeds_bred + 1,
+            &mut self.rng,
+        );
+
+        self.seeds_bred += 1;
+        self.breeding_parents.clear();
+        self.show_toast(format!("Bred {} - added to seed inventory", seed.strain_name));
+        self.seed_inventory.push(seed);
+    }
 
-        self.visual_mode = self.visual_mode.next();
-        let supports_rgb = self.color_palette.supports_rgb();
-        self.color_palette = create_palette(supports_rgb, self.visual_mode);
+    /// Show a share code for the current plant's genetics
+    pub fn export_plant_code(&mut self) {
+        match self.current_plant {
+            Some(ref plant) => self.share_code = Some(SharedPlant::from_plant(plant).encode()),
+            None => self.show_toast("No plant to share"),
+        }
+    }
+
+    /// Ask the event loop to write the current plant out as art or a diary
+    pub fn request_export(&mut self, export: Export) {
+        if self.current_plant.is_some() {
+            self.export_requested = Some(export);
+        } else {
+            self.show_toast("No plant to export");
+        }
+    }
+
+    /// Decode the typed code and add its genetics to the seed inventory
+    pub fn import_plant_code(&mut self) {
+        let Some(code) = self.code_input.take() else {
+            return;
+        };
+        match SharedPlant::decode(&code) {
+            Ok(shared) => {
+                let seed = shared.into_seed(&mut self.rng);
+                self.show_toast(format!("Imported {} - added to seed inventory", seed.strain_name));
+                self.seed_inventory.push(seed);
+            }
+            Err(e) => self.show_toast(format!("Could not import: {}", e)),
+        }
     }
 
-    /// Update plant state based on elapsed time
+    /// Step to the next frame interval preset
+    pub fn cycle_frame_rate(&mut self) {
+        let next = FRAME_INTERVALS_MS
+            .iter()
+            .position(|&ms| ms == self.frame_interval_ms)
+            .map(|i| (i + 1) % FRAME_INTERVALS_MS.len())
+            .unwrap_or(0);
+        self.frame_interval_ms = FRAME_INTERVALS_MS[next];
+
+        let label = if next == 0 { "smooth" } else { "eco" };
+        self.show_toast(format!("Frame interval: {}ms ({})", self.frame_interval_ms, label));
+    }
+
+    /// Cycle the autosave interval through the presets
+    pub fn cycle_autosave(&mut self) {
+        let next = AUTOSAVE_INTERVALS_SECS
+            .iter()
+            .position(|&secs| secs == self.autosave_seconds)
+            .map(|i| (i + 1) % AUTOSAVE_INTERVALS_SECS.len())
+            .unwrap_or(0);
+        self.autosave_seconds = AUTOSAVE_INTERVALS_SECS[next];
+
+        if self.autosave_seconds == 0 {
+            self.show_toast("Autosave off - the game saves on exit or when you press save");
+        } else {
+            self.show_toast(format!("Autosave every {}s", self.autosave_seconds));
+        }
+    }
+
+    /// Quit, unless there are unsaved changes that only a save on exit would
+    /// keep: then ask first
+    pub fn request_quit(&mut self) {
+        if self.autosave_seconds == 0 && self.save_state.is_dirty() && self.save_dir.is_some() {
+            self.confirm_quit = true;
+            self.show_toast("Unsaved changes - [y] save and quit, [n] quit without saving, any other key cancels");
+        } else {
+            self.running = false;
+        }
+    }
+
+    /// Show "Saved" on the status line for a moment
+    pub fn flash_saved(&mut self) {
+        self.saved_flash_frames = frames_for(SAVED_FLASH_MS, self.frame_interval_ms) as u16;
+    }
+
+    /// How long the event loop should wait for input before the next tick
+    /// Simulation uses real elapsed time, so a longer wait never slows the grow
+    pub fn poll_interval_ms(&self) -> u64 {
+        if self.focused {
+            self.frame_interval_ms.max(FRAME_INTERVALS_MS[0])
+        } else {
+            UNFOCUSED_INTERVAL_MS
+        }
+    }
+
+    /// Show a toast message for a few seconds
+    pub fn show_toast(&mut self, text: impl Into<String>) {
+        self.toast = Some(Toast {
+            text: text.into(),
+            frames_left: frames_for(TOAST_MS, self.frame_interval_ms) as u16,
+            grade: None,
+        });
+    }
+
+    /// Show a harvest toast with its grade as a big colored letter
+    pub fn show_grade_toast(&mut self, text: impl Into<String>, grade: Grade) {
+        self.show_toast(text);
+        if let Some(ref mut toast) = self.toast {
+            toast.grade = Some(grade);
+        }
+    }
+
+    /// Simulate until the current plant is ready to harvest, without redrawing
+    /// Stops early if the plant is harvested (auto-harvest) or the step cap is hit
+    pub fn fast_forward(&mut self) {
+        let Some(plant) = self.current_plant.as_ref() else {
+            return;
+        };
+        if plant.stage == GrowthStage::ReadyToHarvest {
+            return;
+        }
+
+        let plant_id = plant.id;
+        let start_day = plant.days_alive;
+        let mut steps = 0;
+
+        while steps < FAST_FORWARD_MAX_STEPS {
+            match self.current_plant.as_ref() {
+                Some(plant) if plant.id == plant_id && plant.stage != GrowthStage::ReadyToHarvest => {}
+                _ => break,
+            }
+            self.simulate(FAST_FORWARD_STEP_SECONDS);
+            steps += 1;
+        }
+
+        let text = match self.current_plant.as_ref() {
+            Some(plant) if plant.id == plant_id && plant.stage == GrowthStage::ReadyToHarvest => format!(
+                "Fast-forwarded {} days - {} is ready to harvest!",
+                plant.days_alive.saturating_sub(start_day),
+                plant.strain_name
+            ),
+            Some(plant) if plant.id == plant_id => format!(
+                "Fast-forward stopped after {} days (still {})",
+                plant.days_alive.saturating_sub(start_day),
+                plant.stage.as_str()
+            ),
+            _ => "Fast-forward stopped - plant was harvested".to_string(),
+        };
+        self.show_toast(text);
+    }
+
+    /// Pick up a loaded game at `now`. The saved `last_tick` only measures how
+    /// long the game was closed: at most `offline_growth_days` of that is grown
+    /// (in fast-forward steps, not one jump) and the clock restarts from `now`,
+    /// so nothing else runs while closed. Returns the game days grown.
+    pub fn resume(&mut self, now: DateTime<Utc>) -> u32 {
+        let away_seconds = now.signed_duration_since(self.last_tick).num_milliseconds().max(0) as f32 / 1000.0;
+        let cap_seconds = self.offline_growth_days as f32 * 24.0 * 3600.0 / TIME_SCALE;
+        let mut remaining = away_seconds.min(cap_seconds);
+        let hours_grown = remaining * TIME_SCALE / 3600.0;
+
+        while remaining > 0.0 {
+            let step = remaining.min(FAST_FORWARD_STEP_SECONDS);
+            self.simulate(step);
+            remaining -= step;
+        }
+        self.last_tick = now;
+
+        let days = (hours_grown / 24.0) as u32;
+        if days > 0 {
+            self.show_toast(format!("Your plants grew {} days while you were away", days));
+        }
+        days
+    }
+
+    /// Cycle how much growth is caught up after the game was closed
+    pub fn cycle_offline_growth(&mut self) {
+        let next = OFFLINE_GROWTH_DAYS
+            .iter()
+            .position(|&days| days == self.offline_growth_days)
+            .map(|i| (i + 1) % OFFLINE_GROWTH_DAYS.len())
+            .unwrap_or(0);
+        self.offline_growth_days = OFFLINE_GROWTH_DAYS[next];
+
+        if self.offline_growth_days == 0 {
+            self.show_toast("Offline growth off - the grow waits while the game is closed");
+        } else {
+            self.show_toast(format!("Offline growth: up to {} days while closed", self.offline_growth_days));
+        }
+    }
+
+    /// Update plant state based on elapsed time, then step the animation
     pub fn update_time(&mut self, elapsed_seconds: f32) {
-        if let Some(ref mut plant) = self.current_plant {
-            // Calculate hours elapsed (50000x speed - ultra fast!)
-            // Full cycle (90 days) takes ~6.5 seconds real time
-            let hours_elapsed = (elapsed_seconds / 3600.0) * 130000.0;
+        self.simulate(elapsed_seconds);
+        self.advance_frame();
+    }
+
+    /// Grow the plant and the drying room by `elapsed_seconds` of real time,
+    /// without the per-frame effects of a drawn tick (fast-forward and offline
+    /// growth run many of these between two frames)
+    fn simulate(&mut self, elapsed_seconds: f32) {
+        // Calculate hours elapsed (50000x speed - ultra fast!)
+        // Full cycle (90 days) takes ~6.5 seconds real time
+        let hours_elapsed = (elapsed_seconds / 3600.0) * TIME_SCALE;
+
+        // Drying batches hang in the same room as the plant
+        let room_temperature = self.current_plant.as_ref().map_or(TEMP_OPTIMAL_MIN, |p| p.temperature);
+        for batch in &mut self.drying {
+            batch.advance(hours_elapsed, room_temperature);
+        }
+        if self.auto_harvest && self.drying.iter().any(DryingBatch::is_dry) {
+            self.finish_drying();
+        }
+        if let Some(ref mut jar) = self.curing {
+            jar.advance(hours_elapsed);
+            if self.auto_harvest && jar.is_cured() {
+                self.finish_cure();
+            }
+        }
+
+        let paused = self.paused_for_harvest();
+        let bands = &self.care_bands();
+        if let Some(ref mut plant) = self.current_plant.as_mut().filter(|_| !paused) {
 
             // Update total hours elapsed (accelerated time)
             plant.total_hours_elapsed += hours_elapsed;
+            self.lifetime.days_simulated += hours_elapsed / 24.0;
 
             // Update days alive based on game hours
             plant.days_alive = (plant.total_hours_elapsed / 24.0) as u32;
 
             // Update resource consumption based on growth stage (reduced for auto-viewing)
-            use crate::domain::GrowthStage;
             let water_drain = match plant.stage {
                 GrowthStage::Vegetative => 1.0,
                 GrowthStage::Flowering => 0.8,
                 _ => 0.5,
             };
+            let water_drain = water_drain * plant.medium.water_drain();
             plant.water_level = (plant.water_level - water_drain * hours_elapsed).max(0.0);
 
             let nutrient_drain = match plant.stage {
@@ -123,39 +1268,45 @@ impl App {
                 GrowthStage::Flowering => 1.0,
                 _ => 0.4,
             };
+            let nutrient_drain = nutrient_drain * plant.medium.nutrient_drain();
             plant.nutrient_level = (plant.nutrient_level - nutrient_drain * hours_elapsed).max(0.0);
 
             // Auto-care: keep resources topped up (like watching a bonsai grow)
-            if plant.water_level < 40.0 {
-                plant.water_level = (plant.water_level + 50.0).min(100.0);
-            }
-            if plant.nutrient_level < 50.0 {
-                plant.nutrient_level = (plant.nutrient_level + 40.0).min(100.0);
+            let (light_min, light_max) = plant.stage.optimal_light();
+            if self.auto_care {
+                if plant.water_level < bands.water_optimal.0 {
+                    plant.water_level = (plant.water_level + bands.auto_water_dose).min(100.0);
+                }
+                if plant.nutrient_level < bands.nutrient_optimal.0 {
+                    plant.nutrient_level = (plant.nutrient_level + bands.auto_feed_dose).min(100.0);
+                }
+                plant.light_intensity = (light_min + light_max) / 2.0;
             }
 
+            // Sample resource levels for the care history chart
+            plant.record_resource_sample();
+
             // Update environmental metrics
-            // CO2 absorption increases with canopy density
-            plant.co2_level = (80.0 + (plant.canopy_density * 0.2)).min(100.0);
-
-            // Light absorption increases with plant size and health
-            let light_base = match plant.stage {
-                GrowthStage::Seed | GrowthStage::Germination | GrowthStage::Seedling => 40.0,
-                GrowthStage::Vegetative => 60.0,
-                GrowthStage::PreFlower => 75.0,
-                GrowthStage::Flowering | GrowthStage::ReadyToHarvest => 85.0,
+            // Ambient CO2 rises with canopy density, enrichment pushes it toward 100
+            let co2_target = if self.co2_enrichment {
+                100.0
+            } else {
+                (80.0 + (plant.canopy_density * 0.2)).min(100.0)
             };
-            plant.light_absorption = (light_base + (plant.canopy_density * 0.1)).min(100.0);
+            plant.co2_level += (co2_target - plant.co2_level) * (hours_elapsed / 12.0).min(1.0);
 
-            // Temperature fluctuates slightly (simulate environment)
-            let temp_variation = (plant.days_alive as f32 * 0.7).sin() * 2.0;
-            plant.temperature = (24.0 + temp_variation).max(20.0).min(28.0);
+            // Light absorption = lamp intensity x how much canopy there is to catch it
+            let canopy_factor = 0.5 + (plant.canopy_density / 200.0); // 0.5-1.0
+            plant.light_absorption = (plant.light_intensity * canopy_factor).min(100.0);
 
-            // Humidity affected by watering
-            plant.humidity = (50.0 + (plant.water_level * 0.2)).min(80.0);
+            // Temperature and humidity drift on the plant's own climate curve
+            let seed = plant.climate_seed();
+            let days = plant.total_hours_elapsed / 24.0;
+            plant.temperature = environment::room_temperature(seed, days);
+            plant.humidity = environment::room_humidity(seed, days, plant.water_level);
 
-            // Root development grows over time
-            let root_progress = (plant.days_alive as f32 / 90.0 * 100.0).min(100.0);
-            plant.root_development = root_progress;
+            // Roots grow with good watering and die back in drought or when drowning
+            plant.update_roots(hours_elapsed, bands);
 
             // Canopy density increases with stage, genetics, and health
             let canopy_base = match plant.stage {
@@ -177,18 +1328,60 @@ impl App {
                     base * plant.genetics.growth_rate
                 }
             };
-            plant.canopy_density = canopy_base.min(100.0);
+            // Too little light slows canopy growth (down to half speed in the dark)
+            let light_multiplier = if plant.light_intensity < light_min {
+                (plant.light_intensity / light_min).max(0.5)
+            } else {
+                1.0
+            };
+            // CO2 enrichment speeds growth, but only under strong light in a warm tent
+            let co2_multiplier = plant.co2_growth_multiplier(self.co2_enrichment);
+            // Cutting the top colas thins the canopy for good
+            plant.canopy_density =
+                (canopy_base * light_multiplier * co2_multiplier * plant.remaining_share()).min(100.0);
+            plant.co2_bonus_hours += hours_elapsed * (co2_multiplier - 1.0);
 
             // Update growth stage
-            plant.stage = Plant::calculate_stage(plant.days_alive);
+            let previous_stage = plant.stage;
+            plant.stage = plant.calculate_stage(plant.days_alive);
+
+            // Notify once when the plant first becomes ready
+            if previous_stage != GrowthStage::ReadyToHarvest && plant.stage == GrowthStage::ReadyToHarvest {
+                self.harvest_flash_frames = frames_for(HARVEST_FLASH_MS, self.frame_interval_ms) as u16;
+                self.bell_pending = self.enable_bell;
+            }
+
+            if plant.stage != previous_stage {
+                plant.stage_days.push((plant.stage, plant.days_alive));
+                push_event(
+                    &mut self.events,
+                    plant.days_alive,
+                    format!("{} entered {}", plant.strain_name, plant.stage.as_str()),
+                );
+            }
+
+            plant.update_terpenes(hours_elapsed);
 
             // Auto-switch to flowering at day 45 if still in veg cycle
             if plant.days_alive >= 45 && plant.light_cycle == crate::domain::LightCycle::Veg18_6 {
                 plant.toggle_light_cycle();
+                push_event(&mut self.events, plant.days_alive, "Lights switched to 12/12 for flowering");
             }
 
             // Update health
-            plant.health = Plant::calculate_health(plant.water_level, plant.nutrient_level);
+            plant.health = Plant::calculate_health(plant.water_level, plant.nutrient_level, bands);
+
+            // Sustained overwatering drowns the roots: duller growth and worse health
+            if plant.water_level > OVERWATER_LEVEL {
+                plant.overwatered_hours += hours_elapsed;
+            } else {
+                plant.overwatered_hours = 0.0;
+            }
+            if plant.is_overwatered() {
+                plant.health = plant.health.worse();
+                plant.canopy_density *= OVERWATER_CANOPY_PENALTY;
+            }
+            plant.settle_wilt(hours_elapsed);
 
             // Resilience mitiga impacto de health ruim no crescimento
             let health_multiplier = match plant.health {
@@ -203,8 +1396,8 @@ impl App {
             plant.canopy_density *= health_multiplier;
 
             // Update care history tracking (cumulative)
-            let water_optimal = (40.0..=80.0).contains(&plant.water_level);
-            let nutrient_optimal = (50.0..=80.0).contains(&plant.nutrient_level);
+            let water_optimal = bands.water_is_optimal(plant.water_level);
+            let nutrient_optimal = bands.nutrients_are_optimal(plant.nutrient_level);
 
             if water_optimal {
                 plant.care_history.total_optimal_water_hours += hours_elapsed;
@@ -212,12 +1405,19 @@ impl App {
             if nutrient_optimal {
                 plant.care_history.total_optimal_nutrient_hours += hours_elapsed;
             }
+            if (TEMP_OPTIMAL_MIN..=TEMP_OPTIMAL_MAX).contains(&plant.temperature) {
+                plant.care_history.total_optimal_temp_hours += hours_elapsed;
+            }
+            if (HUMIDITY_OPTIMAL_MIN..=HUMIDITY_OPTIMAL_MAX).contains(&plant.humidity) {
+                plant.care_history.total_optimal_humidity_hours += hours_elapsed;
+            }
+            plant.care_history.environment_hours += hours_elapsed;
             plant.care_history.total_hours += hours_elapsed;
 
             // Detect and record stress events
             use crate::domain::{StressEvent, StressSeverity, StressCause};
 
-            if plant.water_level < 20.0 && !plant.care_history.has_recent_stress(StressCause::LowWater, plant.days_alive) {
+            if plant.water_level < bands.water_critical && !plant.care_history.has_recent_stress(StressCause::LowWater, plant.days_alive) {
                 plant.care_history.stress_events.push(StressEvent {
                     day: plant.days_alive,
                     severity: StressSeverity::Moderate,
@@ -225,7 +1425,7 @@ impl App {
                 });
             }
 
-            if plant.water_level > 90.0 && !plant.care_history.has_recent_stress(StressCause::HighWater, plant.days_alive) {
+            if plant.water_level > OVERWATER_LEVEL && !plant.care_history.has_recent_stress(StressCause::HighWater, plant.days_alive) {
                 plant.care_history.stress_events.push(StressEvent {
                     day: plant.days_alive,
                     severity: StressSeverity::Moderate,
@@ -233,7 +1433,7 @@ impl App {
                 });
             }
 
-            if plant.nutrient_level < 30.0 && !plant.care_history.has_recent_stress(StressCause::LowNutrients, plant.days_alive) {
+            if plant.nutrient_level < bands.nutrient_critical && !plant.care_history.has_recent_stress(StressCause::LowNutrients, plant.days_alive) {
                 plant.care_history.stress_events.push(StressEvent {
                     day: plant.days_alive,
                     severity: StressSeverity::Moderate,
@@ -241,7 +1441,7 @@ impl App {
                 });
             }
 
-            if plant.nutrient_level > 90.0 && !plant.care_history.has_recent_stress(StressCause::NutrientBurn, plant.days_alive) {
+            if plant.nutrient_level > bands.nutrient_burn && !plant.care_history.has_recent_stress(StressCause::NutrientBurn, plant.days_alive) {
                 plant.care_history.stress_events.push(StressEvent {
                     day: plant.days_alive,
                     severity: StressSeverity::Severe,
@@ -249,44 +1449,143 @@ impl App {
                 });
             }
 
-            // Auto-harvest mode: harvest 10 days after ReadyToHarvest (day 96)
-            if self.auto_harvest
-                && plant.stage == crate::domain::GrowthStage::ReadyToHarvest
-                && plant.days_alive >= 96 {
-                // Trigger auto-harvest
+            // Strong light in dry air scorches (bleaches) the buds
+            if plant.light_intensity > light_max
+                && plant.humidity < LIGHT_BURN_HUMIDITY
+                && !plant.care_history.has_recent_stress(StressCause::LightBurn, plant.days_alive) {
+                plant.care_history.stress_events.push(StressEvent {
+                    day: plant.days_alive,
+                    severity: StressSeverity::Moderate,
+                    cause: StressCause::LightBurn,
+                });
+            }
+
+            // Lower buds left after a partial harvest come down once they've ripened
+            if plant.lower_buds_done() {
+                let day = plant.days_alive;
+                let text = format!("Finished harvesting {}", plant.strain_name);
+                self.harvest_and_replant();
+                push_event(&mut self.events, day, text);
+            } else if self.auto_harvest
+                && plant.stage == GrowthStage::ReadyToHarvest
+                && plant.days_alive
+                    >= plant.stage_start_day(GrowthStage::ReadyToHarvest) + self.auto_harvest_delay_days {
+                // Auto-harvest mode: harvest once the plant has been ready for the delay
+                let day = plant.days_alive;
+                let text = format!("Auto-harvested {}", plant.strain_name);
                 self.harvest_and_replant();
+                push_event(&mut self.events, day, text);
             }
         }
 
         self.last_tick = Utc::now();
+    }
+
+    /// Step everything that counts drawn frames: animation, gauges, flashes and toasts
+    fn advance_frame(&mut self) {
         self.animation_frame = self.animation_frame.wrapping_add(1);
+        if let Some(ref plant) = self.current_plant {
+            self.gauges.ease_toward(plant);
+        }
+        self.harvest_flash_frames = self.harvest_flash_frames.saturating_sub(1);
+        if let Some(undo) = self.last_harvest_undo.as_mut() {
+            undo.frames_left = undo.frames_left.saturating_sub(1);
+            if undo.frames_left == 0 {
+                self.last_harvest_undo = None;
+            }
+        }
+        self.saved_flash_frames = self.saved_flash_frames.saturating_sub(1);
+        if let Some(toast) = self.toast.as_mut() {
+            toast.frames_left = toast.frames_left.saturating_sub(1);
+            if toast.frames_left == 0 {
+                self.toast = None;
+                // The quit prompt lives in the toast: once it's gone, q asks again
+                self.confirm_quit = false;
+            }
+        }
     }
 }
 
 impl Default for App {
     fn default() -> Self {
-        Self::new(false) // Default to Basic16 palette
+        Self::new(ColorDepth::Basic16) // Default to Basic16 palette
     }
 }
 
 impl Clone for App {
     fn clone(&self) -> Self {
         Self {
+            version: self.version,
             current_plant: self.current_plant.clone(),
             harvest_history: self.harvest_history.clone(),
+            lifetime: self.lifetime,
             last_tick: self.last_tick,
             total_harvests: self.total_harvests,
             auto_harvest: self.auto_harvest,
+            auto_harvest_delay_days: self.auto_harvest_delay_days,
             visual_mode: self.visual_mode,
+            theme: self.theme.clone(),
+            auto_care: self.auto_care,
+            co2_enrichment: self.co2_enrichment,
+            enable_bell: self.enable_bell,
+            compress_saves: self.compress_saves,
+            save_format: self.save_format,
+            pause_when_ready: self.pause_when_ready,
+            weight_unit: self.weight_unit,
+            seed_inventory: self.seed_inventory.clone(),
+            seeds_bred: self.seeds_bred,
+            frame_interval_ms: self.frame_interval_ms,
+            autosave_seconds: self.autosave_seconds,
+            offline_growth_days: self.offline_growth_days,
+            difficulty: self.difficulty,
+            medium: self.medium,
+            render_style: self.render_style,
+            reduced_motion: self.reduced_motion,
+            drying: self.drying.clone(),
+            curing: self.curing.clone(),
+            rng: self.rng.clone(),
+            events: self.events.clone(),
+            goal: self.goal,
+            goal_completed: self.goal_completed,
             current_screen: self.current_screen,
             running: self.running,
             animation_frame: self.animation_frame,
             // Create new palette instance with same visual mode
-            color_palette: if self.color_palette.supports_rgb() {
-                create_palette(true, self.visual_mode)
-            } else {
-                create_palette(false, self.visual_mode)
-            },
+            color_palette: create_palette(self.color_palette.depth(), self.visual_mode, self.custom_palette.as_ref()),
+            custom_palette: self.custom_palette.clone(),
+            palettes_dir: self.palettes_dir.clone(),
+            show_help: self.show_help,
+            keys: self.keys.clone(),
+            harvest_flash_frames: self.harvest_flash_frames,
+            last_harvest_undo: self.last_harvest_undo.clone(),
+            bell_pending: self.bell_pending,
+            toast: self.toast.clone(),
+            status_error: self.status_error.clone(),
+            focused: self.focused,
+            unicode: self.unicode,
+            breeding_cursor: self.breeding_cursor,
+            breeding_parents: self.breeding_parents.clone(),
+            library_cursor: self.library_cursor,
+            library_sort: self.library_sort,
+            library_query: self.library_query.clone(),
+            library_typing: self.library_typing,
+            compare_cursors: self.compare_cursors,
+            compare_focus: self.compare_focus,
+            goal_cursor: self.goal_cursor,
+            gauges: self.gauges.clone(),
+            session_date: self.session_date,
+            pending_harvest: self.pending_harvest.clone(),
+            profile: self.profile.clone(),
+            save_dir: self.save_dir.clone(),
+            confirm_new_game: self.confirm_new_game,
+            confirm_quit: self.confirm_quit,
+            save_state: self.save_state.clone(),
+            save_requested: self.save_requested,
+            export_requested: self.export_requested,
+            saved_flash_frames: self.saved_flash_frames,
+            share_code: self.share_code.clone(),
+            code_input: self.code_input.clone(),
+            notice: self.notice.clone(),
         }
     }
 }
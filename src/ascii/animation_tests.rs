@@ -0,0 +1,76 @@
+//! Frame-by-frame contracts for the animation cycles
+//!
+//! Each animation indexes its glyphs by `frame % N`, with an N of its own.
+//! These tests step through whole periods so a refactor can't quietly change
+//! the timing or pick the wrong cycle for a stage.
+
+use super::art::{
+    get_border_decoration, get_nutrient_sparkles, get_plant_ascii, get_water_drops, trunk_glyph, Appearance, Canvas,
+    PlantTraits, Stretch,
+};
+use crate::domain::GrowthStage;
+
+/// A still plant on the classic canvas, so the trunk stands on the center column
+const STILL: Appearance = Appearance {
+    flower_variant: 1,
+    canopy_density: 100.0,
+    canvas: Canvas::CLASSIC,
+    sway: 0.0,
+    wilt: 0.0,
+    frost: 0.0,
+    roots: 0.0,
+};
+
+const STRETCH: Stretch = Stretch {
+    flowering_start_day: 49,
+    factor: 1.0,
+};
+
+/// The trunk cycle each growing stage promises, on a day it is drawn
+const TRUNK_CYCLES: [(GrowthStage, u32, &[char]); 5] = [
+    (GrowthStage::Seedling, 5, &['|', '!']),
+    (GrowthStage::Vegetative, 25, &['|', '!', 'I']),
+    (GrowthStage::PreFlower, 45, &['|', '!', 'I', '║']),
+    (GrowthStage::Flowering, 65, &['|', '!', 'I', '║']),
+    (GrowthStage::ReadyToHarvest, 90, &['I', '║']),
+];
+
+/// The glyph `frame` draws at (`rows_up` above the soil, center column)
+fn center_glyph(stage: GrowthStage, day: u32, frame: usize, rows_up: usize) -> char {
+    let lines = get_plant_ascii(stage, day, 42, PlantTraits::default(), STILL, frame, STRETCH);
+    let row = Canvas::CLASSIC.soil_row() - rows_up;
+    lines[row].chars().nth(Canvas::CLASSIC.width / 2).unwrap()
+}
+
+/// `glyph(frame)` over two full periods, as a string
+fn cycle<T: std::fmt::Display>(period: usize, glyph: impl Fn(usize) -> T) -> String {
+    (0..2 * period).map(|frame| glyph(frame).to_string()).collect()
+}
+
+#[test]
+fn trunk_glyphs_cycle_per_stage() {
+    for (stage, day, chars) in TRUNK_CYCLES {
+        let expected: String = chars.iter().chain(chars).collect();
+        assert_eq!(cycle(chars.len(), |frame| trunk_glyph(stage, frame)), expected, "{:?}", stage);
+        assert_eq!(cycle(chars.len(), |frame| center_glyph(stage, day, frame, 1)), expected, "{:?} drawn", stage);
+    }
+}
+
+#[test]
+fn seed_and_sprout_keep_their_own_beat() {
+    // The seed swells for the last 4 of every 16 frames
+    let swell = ".".repeat(12) + "oooo";
+    assert_eq!(cycle(16, |frame| center_glyph(GrowthStage::Seed, 0, frame, 0)), swell.repeat(2));
+
+    // Day 0 the shoot hooks over every 8 frames; day 1 the stem flickers like a seedling's
+    let hook = ",,,,''''";
+    assert_eq!(cycle(8, |frame| center_glyph(GrowthStage::Germination, 0, frame, 1)), hook.repeat(2));
+    assert_eq!(cycle(2, |frame| center_glyph(GrowthStage::Germination, 1, frame, 1)), "|!|!");
+}
+
+#[test]
+fn gauge_and_border_decorations_cycle_with_their_periods() {
+    assert_eq!(cycle(8, get_water_drops), ".o.O.o. ".repeat(2));
+    assert_eq!(cycle(12, get_nutrient_sparkles), "*+*x*+*X*x* ".repeat(2));
+    assert_eq!(cycle(4, get_border_decoration), "~~--".repeat(2));
+}
@@ -1,24 +1,130 @@
-use crate::domain::GrowthStage;
-use std::collections::HashMap;
+use crate::domain::{Grade, GrowthStage};
+use crate::domain::plant::STRETCH_DAYS;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
 use std::sync::Mutex;
 
+/// Structures kept in the cache; only the plant on screen (and the odd
+/// preview) is drawn at a time, so a handful is plenty
+pub(super) const PLANT_CACHE_CAPACITY: usize = 8;
+
 lazy_static::lazy_static! {
-    static ref PLANT_CACHE: Mutex<HashMap<u64, PlantStructure>> = Mutex::new(HashMap::new());
+    // The cache `get_plant_ascii` draws from
+    static ref PLANT_CACHE: Mutex<StructureCache> = Mutex::new(StructureCache::default());
+}
+
+/// Recently generated plant structures, most recently used last
+///
+/// Keyed by seed and strain traits so different strains never share a structure.
+/// A structure is a pure function of its key, so two plants whose ids truncate
+/// to the same seed would draw alike anyway; evicting never changes the art.
+#[derive(Debug, Default)]
+pub struct StructureCache {
+    entries: Vec<((u64, PlantTraits), PlantStructure)>,
+}
+
+impl StructureCache {
+    /// Cached structure for a plant; a miss generates it and drops the least
+    /// recently used one if the cache is full
+    pub fn get_or_generate(&mut self, seed: u64, traits: PlantTraits) -> PlantStructure {
+        if let Some(index) = self.entries.iter().position(|(key, _)| *key == (seed, traits)) {
+            let entry = self.entries.remove(index);
+            let structure = entry.1.clone();
+            self.entries.push(entry);
+            return structure;
+        }
+
+        let structure = PlantStructure::generate(seed, traits);
+        if self.entries.len() == PLANT_CACHE_CAPACITY {
+            self.entries.remove(0);
+        }
+        self.entries.push(((seed, traits), structure.clone()));
+        structure
+    }
+
+    /// Number of structures held
+    #[cfg(test)]
+    pub(super) fn len(&self) -> usize {
+        self.entries.len()
+    }
 }
 
 /// Phenotype determines growth pattern
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Phenotype {
     Tall,       // Sativa-like: tall, thin branches, spaced out
     Bushy,      // Indica-like: short, dense, many branches
     Balanced,   // Hybrid: balanced growth
 }
 
+impl Phenotype {
+    /// Map a strain's phenotype label ("Indica-dominant", "Sativa", "Bushy"...)
+    /// Anything unrecognized grows balanced
+    pub fn from_strain(label: &str) -> Self {
+        let label = label.to_ascii_lowercase();
+        if label.contains("indica") || label.contains("bushy") {
+            Phenotype::Bushy
+        } else if label.contains("sativa") || label.contains("tall") {
+            Phenotype::Tall
+        } else {
+            Phenotype::Balanced
+        }
+    }
+}
+
+/// Strain height category ("Short"/"Medium"/"Tall")
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HeightClass {
+    Short,
+    Medium,
+    Tall,
+}
+
+impl HeightClass {
+    /// Map a strain's height label; unknown labels leave height to the phenotype
+    pub fn from_strain(label: &str) -> Option<Self> {
+        match label.trim().to_ascii_lowercase().as_str() {
+            "short" => Some(HeightClass::Short),
+            "medium" => Some(HeightClass::Medium),
+            "tall" => Some(HeightClass::Tall),
+            _ => None,
+        }
+    }
+
+    /// Inclusive max_height range (canvas rows) for this class
+    pub fn max_height_range(&self) -> (usize, usize) {
+        match self {
+            HeightClass::Short => (10, 14),
+            HeightClass::Medium => (14, 19),
+            HeightClass::Tall => (19, 24),
+        }
+    }
+}
+
+/// Strain-driven inputs to plant generation (part of the cache key)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PlantTraits {
+    pub phenotype: Option<Phenotype>,       // From the strain; None rolls one from the seed
+    pub height_class: Option<HeightClass>,  // From the strain; None uses the phenotype's range
+    pub height_percent: u16,                // Max height scaling (100 = normal)
+    pub topped: bool,                       // Top colas taken by a partial harvest
+}
+
+impl Default for PlantTraits {
+    fn default() -> Self {
+        Self {
+            phenotype: None,
+            height_class: None,
+            height_percent: 100,
+            topped: false,
+        }
+    }
+}
+
 /// Plant structure - procedurally generated for each plant
 #[derive(Clone, Debug)]
 pub struct PlantStructure {
     pub branches: Vec<Branch>,
-    #[allow(dead_code)]
     pub seed: u64,
     #[allow(dead_code)]
     pub phenotype: Phenotype,
@@ -27,9 +133,99 @@ pub struct PlantStructure {
     pub foliage_density: f32,
     pub trunk_splits: Vec<TrunkSplit>, // Main trunk bifurcations
     pub max_height: usize,              // Maximum height this plant can reach
+    pub stretch_ceiling: usize,         // Highest the flowering stretch can push the trunk
     pub growth_rate: f32,               // How fast trunk grows (per day)
 }
 
+/// Flowering stretch parameters (from the plant's genetics and schedule)
+#[derive(Clone, Copy, Debug)]
+pub struct Stretch {
+    pub flowering_start_day: u32, // Day flowering begins (stretch window starts)
+    pub factor: f32,              // Extra height gain during stretch (1.0 = double)
+}
+
+/// Live plant state that changes how the art is drawn (not part of the cache key)
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    pub flower_variant: u8,  // Flower color variant (picks the bud glyphs)
+    pub canopy_density: f32, // 0-100%, thins out secondary branches when low
+    pub canvas: Canvas,      // Size of the grid the plant is drawn into
+    pub sway: f32,           // 0-1 wind sway strength (0 = still, for reduced motion)
+    pub wilt: f32,           // 0-1 how far a thirsty or sick plant droops
+    pub frost: f32,          // 0-1 trichome frost score from potency (see `frost_score`)
+    pub roots: f32,          // 0-100% root development, spreads the roots below the soil
+}
+
+/// Character grid the plant art fills, sized to the plant panel
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Canvas {
+    pub width: usize,
+    pub height: usize,
+    pub roots: usize, // Bottom rows kept below the soil line for the roots
+}
+
+impl Canvas {
+    /// The 70x28 canvas the art was designed on; other sizes scale from it
+    pub const CLASSIC: Canvas = Canvas { width: 70, height: 28, roots: 0 };
+
+    /// Fit the canvas to a panel, never shrinking below a drawable plant
+    pub fn fit(width: u16, height: u16) -> Self {
+        Self {
+            width: (width as usize).max(MIN_CANVAS_WIDTH),
+            height: (height as usize).max(MIN_CANVAS_HEIGHT),
+            roots: 0,
+        }
+    }
+
+    /// Give up to `rows` of the bottom rows to the roots, keeping enough
+    /// above the soil for a drawable plant
+    pub fn with_roots(self, rows: usize) -> Self {
+        Self {
+            roots: rows.min(self.height.saturating_sub(MIN_CANVAS_HEIGHT)),
+            ..self
+        }
+    }
+
+    /// Row the soil line sits on, with the roots (if any) below it
+    pub fn soil_row(self) -> usize {
+        self.height - 1 - self.roots
+    }
+
+    /// Column the trunk grows from
+    fn center(self) -> usize {
+        self.width / 2
+    }
+
+    /// Rows per classic row: the trunk's full height always fits above the soil
+    fn y_scale(self) -> f32 {
+        (self.soil_row() - 1) as f32 / MAX_TRUNK_HEIGHT as f32
+    }
+
+    /// Columns per classic column: narrow panels squeeze the branches, wide
+    /// ones spread them no further than the height grew
+    fn x_scale(self) -> f32 {
+        (self.width as f32 / Self::CLASSIC.width as f32).min(self.y_scale().max(1.0))
+    }
+
+    /// A height in classic rows, scaled to this canvas
+    fn rows(self, classic_rows: usize) -> usize {
+        (classic_rows as f32 * self.y_scale()).round() as usize
+    }
+}
+
+/// Smallest canvas that still holds a recognisable plant
+const MIN_CANVAS_WIDTH: usize = 24;
+const MIN_CANVAS_HEIGHT: usize = 8;
+
+/// Canopy density (%) at which every generated branch is shown
+const FULL_CANOPY: f32 = 60.0;
+
+/// Highest trunk the classic 28-line canvas can hold above the soil line
+const MAX_TRUNK_HEIGHT: usize = 26;
+
+/// Shortest trunk a Dwarf mutation can shrink a plant to
+pub(super) const MIN_TRUNK_HEIGHT: usize = 6;
+
 #[derive(Clone, Debug)]
 pub struct TrunkSplit {
     pub split_day: u32,     // Day when trunk splits
@@ -44,7 +240,6 @@ pub struct Branch {
     pub growth_start_day: u32,  // Day this branch starts growing
     pub max_length: u8,         // Maximum length this branch can reach
     pub thickness: u8,          // Branch thickness (1-3)
-    #[allow(dead_code)]
     pub is_secondary: bool,     // Secondary branch (grows from another branch)
     #[allow(dead_code)]
     pub parent_index: Option<usize>, // Index of parent branch if secondary
@@ -54,41 +249,52 @@ pub struct Branch {
 }
 
 impl PlantStructure {
-    /// Get or generate a cached plant structure
-    pub fn get_or_generate(seed: u64) -> Self {
-        let mut cache = PLANT_CACHE.lock().unwrap();
-
-        if let Some(structure) = cache.get(&seed) {
-            return structure.clone();
-        }
-
-        let structure = Self::generate(seed);
-        cache.insert(seed, structure.clone());
-        structure
+    /// Get or generate a structure from the shared cache
+    pub fn get_or_generate(seed: u64, traits: PlantTraits) -> Self {
+        PLANT_CACHE.lock().unwrap().get_or_generate(seed, traits)
     }
 
     /// Generate a unique plant structure based on seed
-    fn generate(seed: u64) -> Self {
-        let mut rng = SimpleRng::new(seed);
+    fn generate(seed: u64, traits: PlantTraits) -> Self {
+        let mut rng = StructureRng::new(seed);
 
-        // Determine phenotype
-        let phenotype = match rng.next() % 3 {
+        // Determine phenotype (always roll so the rest of the structure stays seed-stable)
+        let rolled = match rng.below(3) {
             0 => Phenotype::Tall,
             1 => Phenotype::Bushy,
             _ => Phenotype::Balanced,
         };
+        let phenotype = traits.phenotype.unwrap_or(rolled);
 
+        // One roll places the height within whichever range applies
+        let height_roll = rng.unit();
+        let jitter = |range: usize| ((height_roll * range as f32) as usize).min(range.saturating_sub(1));
         let (branch_density, foliage_density, max_height, growth_rate) = match phenotype {
-            Phenotype::Tall => (0.6, 0.4, 20 + (rng.next() % 5) as usize, 0.25),      // 20-24 height, reaches max ~96 days
-            Phenotype::Bushy => (1.0, 0.9, 12 + (rng.next() % 5) as usize, 0.22),     // 12-16 height, reaches max ~64 days
-            Phenotype::Balanced => (0.8, 0.7, 16 + (rng.next() % 5) as usize, 0.23),  // 16-20 height, reaches max ~80 days
+            Phenotype::Tall => (0.6, 0.4, 20 + jitter(5), 0.25),      // 20-24 height, reaches max ~96 days
+            Phenotype::Bushy => (1.0, 0.9, 12 + jitter(5), 0.22),     // 12-16 height, reaches max ~64 days
+            Phenotype::Balanced => (0.8, 0.7, 16 + jitter(5), 0.23),  // 16-20 height, reaches max ~80 days
+        };
+
+        // The strain's height class replaces the phenotype range, keeping the seed's jitter
+        let max_height = match traits.height_class.map(|c| c.max_height_range()) {
+            Some((lo, hi)) => lo + jitter(hi.saturating_sub(lo) + 1),
+            None => max_height,
+        };
+
+        // Dwarf/Giant mutations rescale the final height
+        let max_height = (max_height * traits.height_percent as usize / 100).clamp(MIN_TRUNK_HEIGHT, MAX_TRUNK_HEIGHT);
+
+        // Classed strains stretch at most a quarter above their max height
+        let stretch_ceiling = match traits.height_class {
+            Some(_) => (max_height * 5 / 4).min(MAX_TRUNK_HEIGHT),
+            None => MAX_TRUNK_HEIGHT,
         };
 
         // MANY more primary branches - they appear early and frequently
         let num_primary = match phenotype {
-            Phenotype::Tall => 15 + (rng.next() % 10) as usize,      // 15-25 primary
-            Phenotype::Bushy => 25 + (rng.next() % 15) as usize,     // 25-40 primary
-            Phenotype::Balanced => 20 + (rng.next() % 12) as usize,  // 20-32 primary
+            Phenotype::Tall => 15 + rng.below(10),      // 15-25 primary
+            Phenotype::Bushy => 25 + rng.below(15),     // 25-40 primary
+            Phenotype::Balanced => 20 + rng.below(12),  // 20-32 primary
         };
 
         let mut branches = Vec::new();
@@ -98,9 +304,9 @@ impl PlantStructure {
         for _i in 0..num_primary {
             // Level distribution - spread across the plant height
             let level = match phenotype {
-                Phenotype::Tall => 1 + (rng.next() % (max_height - 1) as u64) as usize,      // 1 to max_height
-                Phenotype::Bushy => 2 + (rng.next() % (max_height - 2).max(1) as u64) as usize, // Lower/middle
-                Phenotype::Balanced => 2 + (rng.next() % (max_height - 2).max(1) as u64) as usize,
+                Phenotype::Tall => 1 + rng.below(max_height.saturating_sub(1)),      // 1 to max_height
+                Phenotype::Bushy => 2 + rng.below(max_height.saturating_sub(2)), // Lower/middle
+                Phenotype::Balanced => 2 + rng.below(max_height.saturating_sub(2)),
             };
 
             // Branches appear MUCH earlier - starting when trunk is only 4 levels tall
@@ -113,35 +319,35 @@ impl PlantStructure {
 
             // Branches can start appearing from day 4 onwards (when trunk_height = 4)
             let base_day = 4;
-            let level_day = base_day + ((max_height - level) as f32 * days_per_level) as u32;
-            let growth_start_day = level_day + (rng.next() % 3) as u32; // Small variation
+            let level_day = base_day + (max_height.saturating_sub(level) as f32 * days_per_level) as u32;
+            let growth_start_day = level_day + rng.below(3) as u32; // Small variation
 
-            let direction = if rng.next() % 2 == 0 { -1 } else { 1 };
+            let direction = if rng.one_in(2) { -1 } else { 1 };
 
             // Longer branches - doubled size for 2x bigger plants
             let max_length = match phenotype {
-                Phenotype::Tall => 6 + (rng.next() % 8) as u8,         // 6-13 chars
-                Phenotype::Bushy => 8 + (rng.next() % 6) as u8,        // 8-13 chars
-                Phenotype::Balanced => 6 + (rng.next() % 8) as u8,     // 6-13 chars
+                Phenotype::Tall => 6 + rng.below(8) as u8,         // 6-13 chars
+                Phenotype::Bushy => 8 + rng.below(6) as u8,        // 8-13 chars
+                Phenotype::Balanced => 6 + rng.below(8) as u8,     // 6-13 chars
             };
 
             let thickness = match phenotype {
                 Phenotype::Tall => 1,
-                Phenotype::Bushy => if rng.next() % 2 == 0 { 2 } else { 1 },
-                Phenotype::Balanced => if rng.next() % 3 == 0 { 2 } else { 1 },
+                Phenotype::Bushy => if rng.one_in(2) { 2 } else { 1 },
+                Phenotype::Balanced => if rng.one_in(3) { 2 } else { 1 },
             };
 
             // Add some curvature
-            let curve = if rng.next() % 3 == 0 {
-                if rng.next() % 2 == 0 { -1 } else { 1 }
+            let curve = if rng.one_in(3) {
+                if rng.one_in(2) { -1 } else { 1 }
             } else {
                 0
             };
 
             // More branches can bifurcate (split into 2)
-            let can_bifurcate = rng.next() % 3 == 0; // 33% chance (increased from 20%)
+            let can_bifurcate = rng.one_in(3); // 33% chance (increased from 20%)
             let bifurcation_day = if can_bifurcate {
-                growth_start_day + 8 + (rng.next() % 8) as u32 // Bifurcate sooner
+                growth_start_day + 8 + rng.below(8) as u32 // Bifurcate sooner
             } else {
                 999 // Never
             };
@@ -169,38 +375,38 @@ impl PlantStructure {
 
         let primary_count = branches.len();
         for _ in 0..num_secondary {
-            let parent_idx = (rng.next() as usize) % primary_count;
+            let parent_idx = rng.below(primary_count);
             let parent = &branches[parent_idx];
 
             // Secondary branches appear sooner
-            let growth_start_day = parent.growth_start_day + 5 + (rng.next() % 5) as u32;
+            let growth_start_day = parent.growth_start_day + 5 + rng.below(5) as u32;
 
             // Same level or one level up/down from parent
-            let level_offset = ((rng.next() % 3) as i32) - 1; // -1, 0, or 1
-            let level = ((parent.level as i32 + level_offset).max(1).min((max_height - 1) as i32)) as usize;
+            let level_offset = rng.below(3) as i32 - 1; // -1, 0, or 1
+            let level = ((parent.level as i32 + level_offset).clamp(1, max_height.saturating_sub(1).max(1) as i32)) as usize;
 
             // Often grows opposite direction for visual variety
-            let direction = if rng.next() % 3 == 0 {
+            let direction = if rng.one_in(3) {
                 parent.direction
             } else {
                 -parent.direction
             };
 
             // Longer secondary branches for 2x size
-            let max_length = 4 + (rng.next() % 6) as u8; // 4-9 chars
+            let max_length = 4 + rng.below(6) as u8; // 4-9 chars
 
             let thickness = 1; // Thinner
 
-            let curve = if rng.next() % 2 == 0 {
-                if rng.next() % 2 == 0 { -1 } else { 1 }
+            let curve = if rng.one_in(2) {
+                if rng.one_in(2) { -1 } else { 1 }
             } else {
                 0
             };
 
             // Secondary branches can also bifurcate more often
-            let can_bifurcate = rng.next() % 5 == 0; // 20% chance
+            let can_bifurcate = rng.one_in(5); // 20% chance
             let bifurcation_day = if can_bifurcate {
-                growth_start_day + 10 + (rng.next() % 8) as u32
+                growth_start_day + 10 + rng.below(8) as u32
             } else {
                 999
             };
@@ -222,15 +428,15 @@ impl PlantStructure {
         // Generate trunk splits (bifurcations)
         let mut trunk_splits = Vec::new();
         let num_splits = match phenotype {
-            Phenotype::Tall => if rng.next() % 3 == 0 { 1 } else { 0 },      // 33% chance
-            Phenotype::Bushy => if rng.next() % 2 == 0 { 1 } else { 2 },     // Often splits
-            Phenotype::Balanced => if rng.next() % 4 == 0 { 1 } else { 0 },  // 25% chance
+            Phenotype::Tall => if rng.one_in(3) { 1 } else { 0 },      // 33% chance
+            Phenotype::Bushy => if rng.one_in(2) { 1 } else { 2 },     // Often splits
+            Phenotype::Balanced => if rng.one_in(4) { 1 } else { 0 },  // 25% chance
         };
 
         for _ in 0..num_splits {
-            let split_day = 20 + (rng.next() % 30) as u32; // Day 20-50
-            let split_level = 4 + (rng.next() % 4) as usize; // Level 4-8
-            let angle = ((rng.next() % 5) as i8) - 2; // -2 to 2
+            let split_day = 20 + rng.below(30) as u32; // Day 20-50
+            let split_level = 4 + rng.below(4); // Level 4-8
+            let angle = rng.below(5) as i8 - 2; // -2 to 2
 
             trunk_splits.push(TrunkSplit {
                 split_day,
@@ -247,16 +453,27 @@ impl PlantStructure {
             foliage_density,
             trunk_splits,
             max_height,
+            stretch_ceiling,
             growth_rate,
         }
     }
 
-    /// Calculate current trunk height based on day
-    pub fn trunk_height(&self, day: u32) -> usize {
+    /// Calculate current trunk height based on day and stage
+    pub fn trunk_height(&self, day: u32, stage: GrowthStage, stretch: Stretch) -> usize {
         // Trunk grows progressively based on growth_rate
         // Formula: height = min(day * growth_rate, max_height)
-        let calculated_height = (day as f32 * self.growth_rate) as usize;
-        calculated_height.min(self.max_height)
+        let linear_height = |d: u32| ((d as f32 * self.growth_rate) as usize).min(self.max_height);
+
+        match stage {
+            GrowthStage::Flowering | GrowthStage::ReadyToHarvest if day >= stretch.flowering_start_day => {
+                // Stretch: height gain multiplied for the first weeks of flower, then stops
+                let pre_flower_height = linear_height(stretch.flowering_start_day) as f32;
+                let stretch_days = (day - stretch.flowering_start_day).min(STRETCH_DAYS) as f32;
+                let gain = stretch_days * self.growth_rate * (1.0 + stretch.factor);
+                ((pre_flower_height + gain) as usize).min(self.stretch_ceiling)
+            }
+            _ => linear_height(day),
+        }
     }
 
     /// Calculate current length using sigmoid growth curve
@@ -276,12 +493,23 @@ impl PlantStructure {
     }
 
     /// Get branches that are currently visible (started growing)
-    pub fn visible_branches(&self, day: u32) -> Vec<&Branch> {
+    /// Primary branches always show; secondaries fill in as the live canopy
+    /// density climbs to FULL_CANOPY, so a neglected plant looks sparse
+    pub fn visible_branches(&self, day: u32, canopy_density: f32) -> Vec<&Branch> {
+        let fullness = (canopy_density / FULL_CANOPY).clamp(0.0, 1.0);
         self.branches.iter()
-            .filter(|b| b.growth_start_day <= day)
+            .enumerate()
+            .filter(|(_, b)| b.growth_start_day <= day)
+            .filter(|(i, b)| !b.is_secondary || self.branch_rank(*i) < fullness)
+            .map(|(_, b)| b)
             .collect()
     }
 
+    /// Fixed 0..1 rank of a branch; lower ranks appear first as the canopy fills
+    fn branch_rank(&self, index: usize) -> f32 {
+        StructureRng::new(self.seed ^ (index as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)).unit()
+    }
+
     /// Calculate foliage density for a specific day
     pub fn current_foliage_density(&self, day: u32) -> f32 {
         // Foliage increases over time
@@ -291,84 +519,196 @@ impl PlantStructure {
     }
 }
 
-/// Simple pseudo-random number generator for deterministic plant generation
-struct SimpleRng {
-    state: u64,
-}
+/// Seeded generator for plant structures. ChaCha8 draws the same numbers on
+/// every platform, and the helpers sample ranges evenly instead of taking a
+/// modulo, so no branch length or level is favoured
+pub(super) struct StructureRng(ChaCha8Rng);
 
-impl SimpleRng {
-    fn new(seed: u64) -> Self {
-        Self { state: seed.wrapping_add(1) }
+impl StructureRng {
+    pub(super) fn new(seed: u64) -> Self {
+        Self(ChaCha8Rng::seed_from_u64(seed))
     }
 
-    fn next(&mut self) -> u64 {
-        self.state = self.state.wrapping_mul(1103515245).wrapping_add(12345);
-        (self.state / 65536) % 32768
+    /// Uniform in `0..n`; an empty range gives 0 rather than panicking
+    pub(super) fn below(&mut self, n: usize) -> usize {
+        if n == 0 {
+            0
+        } else {
+            self.0.gen_range(0..n)
+        }
     }
-}
 
-/// Get plant ASCII art - procedurally generated and animated
-pub fn get_plant_ascii(stage: GrowthStage, day: u32, seed: u64, frame: usize) -> Vec<String> {
-    let structure = PlantStructure::get_or_generate(seed);
+    /// True one time in `n`
+    fn one_in(&mut self, n: u32) -> bool {
+        self.0.gen_ratio(1, n)
+    }
 
-    match stage {
-        // No more Seed or Germination - start directly as Seedling
-        GrowthStage::Seed | GrowthStage::Germination => render_seedling(day, &structure, frame, stage),
-        GrowthStage::Seedling => render_seedling(day, &structure, frame, stage),
-        GrowthStage::Vegetative => render_vegetative(day, &structure, frame, stage),
-        GrowthStage::PreFlower => render_preflower(day, &structure, frame, stage),
-        GrowthStage::Flowering => render_flowering(day, &structure, frame, stage),
-        GrowthStage::ReadyToHarvest => render_harvest(day, &structure, frame, stage),
+    /// Uniform in `0..1`
+    fn unit(&mut self) -> f32 {
+        self.0.gen()
     }
 }
 
-// Removed render_seed() and render_germination() - plants start directly as seedlings
+/// What a cell of the plant canvas is part of; the growing view colors by it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CellKind {
+    #[default]
+    Empty,
+    Soil,
+    Root,
+    /// The seed and the sprout's first leaves
+    Seed,
+    Trunk,
+    Branch,
+    /// Leafy fill along the branches
+    Foliage,
+    FanLeaf,
+    /// A bud at a branch tip (and the buds of a cola above it)
+    Bud,
+    /// The smaller glyphs packing out a bud cluster
+    BudCluster,
+    Trichome,
+}
 
-fn render_seedling(day: u32, structure: &PlantStructure, frame: usize, stage: GrowthStage) -> Vec<String> {
-    render_plant_structure(day, structure, frame, false, "", stage)
+/// Plant art with the kind of every cell alongside the glyphs
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlantArt {
+    pub lines: Vec<String>,
+    pub kinds: Vec<Vec<CellKind>>,
 }
 
-fn render_vegetative(day: u32, structure: &PlantStructure, frame: usize, stage: GrowthStage) -> Vec<String> {
-    render_plant_structure(day, structure, frame, false, "", stage)
+/// One cell of the canvas while it is drawn
+#[derive(Clone, Copy)]
+struct Pixel {
+    glyph: char,
+    kind: CellKind,
 }
 
-fn render_preflower(day: u32, structure: &PlantStructure, frame: usize, stage: GrowthStage) -> Vec<String> {
-    // 8-frame gentle appearance of small flowers
-    let flowers = ['.', '*', '.', ' ', '.', '*', '.', ' '];
-    let flower = &flowers[frame % 8].to_string();
-    render_plant_structure(day, structure, frame, true, flower, stage)
+impl Pixel {
+    const BLANK: Pixel = Pixel { glyph: ' ', kind: CellKind::Empty };
+
+    /// A blank glyph (a bud between pulses) stays empty canvas
+    fn new(glyph: char, kind: CellKind) -> Self {
+        if glyph == ' ' {
+            Self::BLANK
+        } else {
+            Pixel { glyph, kind }
+        }
+    }
+
+    fn is_blank(self) -> bool {
+        self.glyph == ' '
+    }
 }
 
-fn render_flowering(day: u32, structure: &PlantStructure, frame: usize, stage: GrowthStage) -> Vec<String> {
-    // 12-frame pulsing/breathing buds
-    let buds = ['o', 'o', 'O', 'O', '@', '@', 'O', 'O', 'o', 'o', '.', '.'];
-    let bud = &buds[frame % 12].to_string();
-    render_plant_structure(day, structure, frame, true, bud, stage)
+/// Get plant ASCII art - procedurally generated and animated
+#[cfg(test)]
+pub fn get_plant_ascii(
+    stage: GrowthStage,
+    day: u32,
+    seed: u64,
+    traits: PlantTraits,
+    look: Appearance,
+    frame: usize,
+    stretch: Stretch,
+) -> Vec<String> {
+    get_plant_art(stage, day, seed, traits, look, frame, stretch).lines
 }
 
-fn render_harvest(day: u32, structure: &PlantStructure, frame: usize, stage: GrowthStage) -> Vec<String> {
-    // 8-frame trichome sparkle effect
-    let harvest = ['@', '#', '@', '*', '#', '@', '*', '#'];
-    let bud = &harvest[frame % 8].to_string();
-    render_plant_structure(day, structure, frame, true, bud, stage)
+/// `get_plant_ascii` with the kind of every cell
+pub fn get_plant_art(
+    stage: GrowthStage,
+    day: u32,
+    seed: u64,
+    traits: PlantTraits,
+    look: Appearance,
+    frame: usize,
+    stretch: Stretch,
+) -> PlantArt {
+    let structure = PlantStructure::get_or_generate(seed, traits);
+    draw_plant_art(&structure, stage, day, traits.topped, look, frame, stretch)
 }
 
-/// Render the plant structure into ASCII art
-/// ALWAYS returns exactly 70 chars wide × 28 lines tall
-fn render_plant_structure(
+/// Draw a generated structure. The art depends on nothing but the arguments,
+/// so a structure from any `StructureCache` draws exactly what
+/// `get_plant_ascii` would
+#[cfg(test)]
+pub fn draw_plant(
+    structure: &PlantStructure,
+    stage: GrowthStage,
     day: u32,
+    topped: bool,
+    look: Appearance,
+    frame: usize,
+    stretch: Stretch,
+) -> Vec<String> {
+    draw_plant_art(structure, stage, day, topped, look, frame, stretch).lines
+}
+
+/// `draw_plant` with the kind of every cell
+pub fn draw_plant_art(
     structure: &PlantStructure,
+    stage: GrowthStage,
+    day: u32,
+    topped: bool,
+    look: Appearance,
     frame: usize,
-    show_flowers: bool,
-    flower_char: &str,
+    stretch: Stretch,
+) -> PlantArt {
+    match stage {
+        GrowthStage::Seed => render_seed(frame, look.canvas),
+        GrowthStage::Germination => render_germination(day, frame, look.canvas),
+        GrowthStage::Seedling => render_seedling(day, structure, frame, look, stretch),
+        GrowthStage::Vegetative => render_vegetative(day, structure, frame, look, stretch),
+        GrowthStage::PreFlower => render_preflower(day, structure, frame, look, stretch),
+        GrowthStage::Flowering => render_flowering(day, structure, frame, look, stretch),
+        GrowthStage::ReadyToHarvest => render_harvest(day, structure, frame, look, topped, stretch),
+    }
+}
+
+/// Every frame in `frames` of one day's animation, in order
+#[cfg(test)]
+pub fn render_animation(
+    structure: &PlantStructure,
     stage: GrowthStage,
-) -> Vec<String> {
-    // Create 28 lines buffer (70 chars wide) - DOUBLE SIZE
-    let mut lines: Vec<Vec<char>> = vec![vec![' '; 70]; 28];
+    day: u32,
+    topped: bool,
+    look: Appearance,
+    stretch: Stretch,
+    frames: std::ops::Range<usize>,
+) -> Vec<Vec<String>> {
+    frames.map(|frame| draw_plant(structure, stage, day, topped, look, frame, stretch)).collect()
+}
 
-    // Draw main trunk with progressive growth
-    // Trunk animation varies by stage
-    let trunk_char = match stage {
+/// A freshly planted seed resting in the soil
+fn render_seed(frame: usize, canvas: Canvas) -> PlantArt {
+    let mut lines = empty_canvas(canvas);
+    // Every so often the seed swells as it soaks up water
+    let glyph = if frame % 16 < 12 { '.' } else { 'o' };
+    lines[canvas.soil_row()][canvas.center()] = Pixel::new(glyph, CellKind::Seed);
+    into_art(lines)
+}
+
+/// Day 0: a shoot hooks out of the cracked seed; day 1: cotyledons open
+fn render_germination(day: u32, frame: usize, canvas: Canvas) -> PlantArt {
+    let mut lines = empty_canvas(canvas);
+    let (soil, x) = (canvas.soil_row(), canvas.center());
+    lines[soil][x] = Pixel::new('o', CellKind::Seed);
+    if day == 0 {
+        lines[soil - 1][x] = Pixel::new(if frame % 8 < 4 { ',' } else { '\'' }, CellKind::Seed);
+    } else {
+        let stem = Pixel::new(trunk_glyph(GrowthStage::Germination, frame), CellKind::Trunk);
+        lines[soil - 1][x] = stem;
+        lines[soil - 2][x] = stem;
+        lines[soil - 3][x - 1] = Pixel::new('(', CellKind::Seed);
+        lines[soil - 3][x + 1] = Pixel::new(')', CellKind::Seed);
+    }
+    into_art(lines)
+}
+
+/// Trunk glyph for `frame`; the animation varies by stage
+pub fn trunk_glyph(stage: GrowthStage, frame: usize) -> char {
+    match stage {
         GrowthStage::Seed | GrowthStage::Germination | GrowthStage::Seedling => {
             // Seedling: 2-frame fast, energetic
             let chars = ['|', '!'];
@@ -389,113 +729,370 @@ fn render_plant_structure(
             let chars = ['I', '║'];
             chars[frame % 2]
         }
+    }
+}
+
+/// Blank canvas with the soil line drawn
+fn empty_canvas(canvas: Canvas) -> Vec<Vec<Pixel>> {
+    let mut lines = vec![vec![Pixel::BLANK; canvas.width]; canvas.height];
+    draw_soil(&mut lines, canvas);
+    lines
+}
+
+/// Soil line centred under the trunk, 38 columns wide on the classic canvas
+fn draw_soil(lines: &mut [Vec<Pixel>], canvas: Canvas) {
+    let soil_width = 38 * canvas.width / Canvas::CLASSIC.width;
+    let start = canvas.center().saturating_sub(soil_width / 2);
+    for cell in lines[canvas.soil_row()].iter_mut().skip(start).take(soil_width) {
+        *cell = Pixel::new('~', CellKind::Soil);
+    }
+}
+
+/// Split into strings and kinds - GUARANTEE exactly canvas-width chars per line
+fn into_art(lines: Vec<Vec<Pixel>>) -> PlantArt {
+    PlantArt {
+        lines: lines.iter().map(|line| line.iter().map(|p| p.glyph).collect()).collect(),
+        kinds: lines.iter().map(|line| line.iter().map(|p| p.kind).collect()).collect(),
+    }
+}
+
+fn render_seedling(day: u32, structure: &PlantStructure, frame: usize, look: Appearance, stretch: Stretch) -> PlantArt {
+    render_plant_structure(day, structure, frame, None, look, GrowthStage::Seedling, stretch)
+}
+
+fn render_vegetative(day: u32, structure: &PlantStructure, frame: usize, look: Appearance, stretch: Stretch) -> PlantArt {
+    render_plant_structure(day, structure, frame, None, look, GrowthStage::Vegetative, stretch)
+}
+
+fn render_preflower(day: u32, structure: &PlantStructure, frame: usize, look: Appearance, stretch: Stretch) -> PlantArt {
+    // 8-frame gentle appearance of small flowers
+    let flowers = ['.', '*', '.', ' ', '.', '*', '.', ' '];
+    let buds = Buds { glyph: flowers[frame % 8], companion: '.', size: 1, topped: false, frost: 0.0 };
+    render_plant_structure(day, structure, frame, Some(buds), look, GrowthStage::PreFlower, stretch)
+}
+
+fn render_flowering(day: u32, structure: &PlantStructure, frame: usize, look: Appearance, stretch: Stretch) -> PlantArt {
+    // 12-frame pulsing/breathing buds that fill out week by week
+    let glyphs = bud_glyphs(look.flower_variant);
+    let weeks_in = day.saturating_sub(stretch.flowering_start_day) / 7;
+    let size = match weeks_in {
+        0..=1 => 1,
+        2..=3 => 2,
+        _ => 3,
     };
+    // Full colas start to frost over in the last weeks
+    let frost = if size == 3 { trichome_density(look.frost) * LATE_FLOWER_FROST } else { 0.0 };
+    let buds = Buds { glyph: glyphs.flowering[frame % 12], companion: glyphs.companion, size, topped: false, frost };
+    render_plant_structure(day, structure, frame, Some(buds), look, GrowthStage::Flowering, stretch)
+}
 
-    let center = 35; // Center position (middle of 70)
+fn render_harvest(day: u32, structure: &PlantStructure, frame: usize, look: Appearance, topped: bool, stretch: Stretch) -> PlantArt {
+    // 8-frame trichome sparkle effect, plus a frost overlay on potent strains
+    let glyphs = bud_glyphs(look.flower_variant);
+    let buds = Buds {
+        glyph: glyphs.harvest[frame % 8],
+        companion: glyphs.companion,
+        size: 3,
+        topped,
+        frost: trichome_density(look.frost),
+    };
+    render_plant_structure(day, structure, frame, Some(buds), look, GrowthStage::ReadyToHarvest, stretch)
+}
 
-    // Calculate current trunk height (grows progressively)
-    let current_trunk_height = structure.trunk_height(day);
+/// Bud animation cycles for one look of flower
+struct BudGlyphs {
+    flowering: [char; 12],
+    harvest: [char; 8],
+    companion: char, // Packs out the clusters around a growing bud
+}
 
-    // Trunk grows from bottom (27) upward
-    // Only draw trunk up to current height
-    let trunk_start_level = (27 - current_trunk_height).max(0);
+const CLASSIC_BUDS: BudGlyphs = BudGlyphs {
+    flowering: ['o', 'o', 'O', 'O', '@', '@', 'O', 'O', 'o', 'o', '.', '.'],
+    harvest: ['@', '#', '@', '*', '#', '@', '*', '#'],
+    companion: '*',
+};
+
+/// Tight, heavy indica-style colas
+const DENSE_BUDS: BudGlyphs = BudGlyphs {
+    flowering: ['O', 'O', '@', '@', '#', '#', '@', '@', 'O', 'O', 'o', 'o'],
+    harvest: ['#', '@', '#', '#', '@', '#', '@', '#'],
+    companion: '#',
+};
+
+/// Frost-covered buds that glitter
+const FROSTY_BUDS: BudGlyphs = BudGlyphs {
+    flowering: ['o', 'o', '*', '*', '❄', '❄', '*', '*', 'o', 'o', '.', '.'],
+    harvest: ['❄', '*', '❄', '@', '*', '❄', '*', '@'],
+    companion: '❄',
+};
+
+/// Glyphs for a flower color variant (0 purple, 1 orange, 2 golden, 3 pink,
+/// 4 blue, 5 frosty - see `ui::growing`)
+fn bud_glyphs(variant: u8) -> &'static BudGlyphs {
+    match variant {
+        0 => &DENSE_BUDS,
+        5 => &FROSTY_BUDS,
+        _ => &CLASSIC_BUDS,
+    }
+}
 
-    // Check for active splits
-    let active_splits: Vec<&TrunkSplit> = structure.trunk_splits.iter()
-        .filter(|s| s.split_day <= day)
-        .collect();
+/// One stem of the trunk above its forks, relative to the trunk's centre
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Stem {
+    x: i32,
+    lean: i32,    // -1 or 1 while leaning away from a fork
+    steps: usize, // Rows left to lean
+}
+
+/// Buds drawn at branch tips
+#[derive(Clone, Copy)]
+struct Buds {
+    glyph: char,
+    companion: char, // Fills out the clusters of bigger buds
+    size: u8,        // 1 single bud, 2 a two-wide cluster, 3 a cola stacked above the tip
+    topped: bool, // Leave the upper third of the plant bare (top colas already cut)
+    frost: f32,   // 0-1 chance a cell next to a bud gets a trichome speckle
+}
+
+/// Grow a tip bud at (x, y) into a cluster: a companion glyph further out along
+/// its branch, and for full colas a three-wide row stacked above (not above
+/// `bud_top`, where a topped plant was cut). Only empty cells on the canvas
+/// are filled
+fn draw_bud_cluster(lines: &mut [Vec<Pixel>], x: usize, y: usize, direction: i32, buds: Buds, bud_top: usize) {
+    let mut put = |dx: i32, dy: i32, pixel: Pixel| {
+        let (Some(x), Some(y)) = (x.checked_add_signed(dx as isize), y.checked_add_signed(dy as isize)) else {
+            return;
+        };
+        if let Some(cell) = lines.get_mut(y).and_then(|row| row.get_mut(x)).filter(|c| c.is_blank()) {
+            *cell = pixel;
+        }
+    };
+    let (bud, companion) = (Pixel::new(buds.glyph, CellKind::Bud), Pixel::new(buds.companion, CellKind::BudCluster));
+    put(direction.signum(), 0, companion);
+    if buds.size >= 3 && y > bud_top {
+        put(-1, -1, bud);
+        put(0, -1, companion);
+        put(1, -1, bud);
+    }
+}
+
+/// THC % where trichome frost starts to show, and where it is thickest
+const FROST_MIN_THC: f32 = 12.0;
+const FROST_FULL_THC: f32 = 28.0;
+/// Extra frost score for the Extra Resinous mutation
+const RESINOUS_FROST_BONUS: f32 = 0.3;
+/// Share of the cells around a bud speckled at full frost
+const MAX_TRICHOME_DENSITY: f32 = 0.6;
+/// Late-flowering colas carry this share of their harvest frost
+const LATE_FLOWER_FROST: f32 = 0.5;
+/// Frames between a speckle's glints
+const TRICHOME_GLINT_FRAMES: usize = 4;
+
+/// Trichome speckle glyphs (fine dust and bright crystals); each speckle glints
+/// between them
+pub const TRICHOMES: [char; 2] = ['·', '*'];
+
+/// How frosty a plant of this potency gets (0 = none, 1 = fully frosted)
+pub fn frost_score(thc_percent: f32, extra_resinous: bool) -> f32 {
+    let potency = ((thc_percent - FROST_MIN_THC) / (FROST_FULL_THC - FROST_MIN_THC)).clamp(0.0, 1.0);
+    let bonus = if extra_resinous { RESINOUS_FROST_BONUS } else { 0.0 };
+    (potency + bonus).min(1.0)
+}
 
-    let mut split_found = false;
-    let mut split_level_found = 0;
+/// Share of the cells around a bud that get a speckle; mild strains get almost none
+fn trichome_density(frost: f32) -> f32 {
+    frost.clamp(0.0, 1.0).powi(2) * MAX_TRICHOME_DENSITY
+}
 
-    for level in trunk_start_level..=27 {
-        let trunk = trunk_char;
+/// Scatter trichomes into the empty cells on and around each cola. Which cells
+/// get one comes from the plant seed and the bud's index, so the speckles hold
+/// still; the frame only makes them glint, each on its own beat
+fn draw_trichomes(lines: &mut [Vec<Pixel>], buds: &[(usize, usize)], frost: f32, seed: u64, frame: usize) {
+    // The tip's neighbours plus a halo around the cola stacked above it
+    const AROUND: [(i32, i32); 13] = [
+        (-1, -2), (0, -2), (1, -2),
+        (-2, -1), (-1, -1), (0, -1), (1, -1), (2, -1),
+        (-1, 0), (1, 0),
+        (-1, 1), (0, 1), (1, 1),
+    ];
+    for (index, &(x, y)) in buds.iter().enumerate() {
+        for (n, (dx, dy)) in AROUND.iter().enumerate() {
+            let roll = mix(seed, (index * AROUND.len() + n) as u64);
+            if (roll % 1000) as f32 >= frost * 1000.0 {
+                continue;
+            }
+            let (Some(x), Some(y)) = (x.checked_add_signed(*dx as isize), y.checked_add_signed(*dy as isize)) else {
+                continue;
+            };
+            if let Some(cell) = lines.get_mut(y).and_then(|row| row.get_mut(x)).filter(|c| c.is_blank()) {
+                let glint = (roll / 1000) as usize + frame / TRICHOME_GLINT_FRAMES;
+                *cell = Pixel::new(TRICHOMES[glint % TRICHOMES.len()], CellKind::Trichome);
+            }
+        }
+    }
+}
 
-        // Check if there's a split at this level
-        let split_here = active_splits.iter().find(|s| s.split_level == (27 - level));
+/// Render the plant structure into ASCII art
+/// ALWAYS returns exactly `look.canvas` in size; heights and branch lengths
+/// are designed on the classic 70x28 canvas and scaled to fit
+fn render_plant_structure(
+    day: u32,
+    structure: &PlantStructure,
+    frame: usize,
+    buds: Option<Buds>,
+    look: Appearance,
+    stage: GrowthStage,
+    stretch: Stretch,
+) -> PlantArt {
+    let canvas = look.canvas;
+    let (width, height) = (canvas.width as i32, canvas.height as i32);
+    let soil = canvas.soil_row();
+    let mut lines = vec![vec![Pixel::BLANK; canvas.width]; canvas.height];
 
-        if let Some(split) = split_here {
-            if !split_found {
-                // Draw bifurcation
-                lines[level][center] = trunk;
+    // Draw main trunk with progressive growth
+    let trunk_char = trunk_glyph(stage, frame);
 
-                // Draw the split branches going outward
-                let split_pos_left = (center as i8 - split.angle.abs()) as usize;
-                let split_pos_right = (center as i8 + split.angle.abs()) as usize;
+    let center = canvas.center();
 
-                if split_pos_left < 70 && level > 0 {
-                    lines[level - 1][split_pos_left] = if split.angle < 0 { '\\' } else { '/' };
-                }
-                if split_pos_right < 70 && level > 0 {
-                    lines[level - 1][split_pos_right] = if split.angle > 0 { '/' } else { '\\' };
-                }
+    // Calculate current trunk height (grows progressively)
+    let current_trunk_height = canvas.rows(structure.trunk_height(day, stage, stretch));
+
+    // Wind sway: columns to shift a point `rows_up` above the soil; the tip of
+    // a branch (`along` = 1) runs ahead of its base
+    let sway_amplitude = MAX_SWAY_COLUMNS * stage_sway(stage) * look.sway.clamp(0.0, 1.0);
+    let sway = |rows_up: usize, along: f32| -> i32 {
+        let share = (rows_up as f32 / (soil - 1) as f32).min(1.0);
+        let phase = frame as f32 * SWAY_SPEED - rows_up as f32 / canvas.y_scale() * SWAY_LAG + along * SWAY_TIP_LEAD;
+        (sway_amplitude * share * phase.sin()).round() as i32
+    };
+    // Trunk column on a row, bending further from the centre the higher it is
+    let trunk_x = |level: usize| (center as i32 + sway(soil - level, 0.0)).clamp(0, width - 1);
 
-                // Continue both branches upward from split point
-                if level >= 2 {
-                    for up_level in (trunk_start_level..level-1).rev() {
-                        if split_pos_left < 70 {
-                            lines[up_level][split_pos_left] = trunk;
-                        }
-                        if split_pos_right < 70 {
-                            lines[up_level][split_pos_right] = trunk;
-                        }
-                    }
-                }
+    // Trunk grows from the soil upward
+    // Only draw trunk up to current height
+    let trunk_start_level = soil - current_trunk_height;
+
+    // Rows above this carry no buds
+    let bud_top = match buds {
+        Some(Buds { topped: true, .. }) => trunk_start_level + current_trunk_height / 3,
+        _ => 0,
+    };
+    let bud_at = |y: usize| buds.filter(|_| y >= bud_top).map(|b| b.glyph);
+
+    // Forks the trunk has grown by today, lowest first: (rows up, spread)
+    let mut forks: Vec<(usize, usize)> = structure.trunk_splits.iter()
+        .filter(|s| s.split_day <= day)
+        .map(|s| (canvas.rows(s.split_level), 1 + s.angle.unsigned_abs() as usize))
+        .collect();
+    forks.sort_unstable();
+    // A fork never starts inside the one below it, so both stay readable
+    for i in 1..forks.len() {
+        let (below, spread) = forks[i - 1];
+        forks[i].0 = forks[i].0.max(below + spread + 1);
+    }
 
-                split_found = true;
-                split_level_found = level;
+    // Walk the trunk up from the soil; at each fork every stem splits into a
+    // symmetric pair that leans apart for `spread` rows, then grows straight
+    let mut stems = vec![Stem { x: 0, lean: 0, steps: 0 }];
+    for rows_up in 0..=current_trunk_height {
+        let level = soil - rows_up;
+        for stem in stems.iter_mut() {
+            let glyph = if stem.steps > 0 {
+                stem.x += stem.lean;
+                stem.steps -= 1;
+                if stem.lean < 0 { '\\' } else { '/' }
+            } else {
+                trunk_char
+            };
+            if stem.steps == 0 {
+                stem.lean = 0;
+            }
+            let x = trunk_x(level) + stem.x;
+            if (0..width).contains(&x) {
+                lines[level][x as usize] = Pixel::new(glyph, CellKind::Trunk);
             }
-        } else if !split_found || level > split_level_found {
-            // Draw normal trunk (either no split yet, or below the split point)
-            lines[level][center] = trunk;
         }
+
+        if let Some(&(_, spread)) = forks.iter().find(|(row, _)| *row == rows_up) {
+            stems = stems.iter()
+                .flat_map(|s| [-1, 1].map(|lean| Stem { x: s.x, lean, steps: spread }))
+                .collect();
+        }
+        // Stems that meet grow on as one
+        stems.sort_unstable_by_key(|s| (s.x, s.lean, s.steps));
+        stems.dedup();
     }
 
     // Get visible branches for this day
-    let visible = structure.visible_branches(day);
+    let visible = structure.visible_branches(day, look.canopy_density);
+
+    // Get foliage density; a wilting plant's leaves hang limp and thin out
+    let wilt = look.wilt.clamp(0.0, 1.0);
+    let foliage_density = structure.current_foliage_density(day) * (1.0 - WILT_THINNING * wilt);
+    let droop_rows = (wilt * MAX_DROOP_ROWS).round() as i32;
+    // Limp buds replace the tips once the plant is badly wilted
+    let tip_bud = |y: usize| bud_at(y).map(|glyph| if wilt >= LIMP_BUDS_WILT { ',' } else { glyph });
 
-    // Get foliage density
-    let foliage_density = structure.current_foliage_density(day);
+    // Late in flower the lower fan leaves yellow and drop: branches at or
+    // below this level stay bare
+    let bare_below = (current_trunk_height as f32 * LEAF_DROP_SHARE * senescence(day, stage, stretch)) as usize;
+    let mut leaf_spots: Vec<(i32, i32)> = Vec::new();
+    let mut bud_spots: Vec<(usize, usize, i32)> = Vec::new(); // Tip buds and the way their branch points
 
     // Draw branches growing from trunk outward
     for branch in visible.iter() {
-        let level = 27 - branch.level; // Invert level (0 is top, 27 is bottom)
-        if level >= 27 { continue; }
+        let branch_level = canvas.rows(branch.level);
+        let level = soil.saturating_sub(branch_level); // Invert level (0 is top, soil is bottom)
+        if level >= soil { continue; }
 
         // Only draw branch if trunk has reached its level
-        if branch.level > current_trunk_height {
+        if branch_level > current_trunk_height {
             continue; // Trunk hasn't grown to this branch yet
         }
 
-        let current_length = structure.branch_length(branch, day);
+        let current_length = structure.branch_length(branch, day) * canvas.x_scale();
         if current_length < 0.5 { continue; }
 
         let length_int = current_length.ceil() as u8;
+        // Branch column `i` out from the trunk, swayed
+        let branch_x = |i: u8| center as i32 + i as i32 * branch.direction as i32 + sway(branch_level, i as f32 / length_int as f32);
+        // Rows the outer half of a wilting branch hangs below its line
+        let sag = |i: u8| {
+            let half = length_int as f32 / 2.0;
+            (droop_rows as f32 * ((i as f32 - half) / half).max(0.0)).round() as i32
+        };
+        let drooping = |i: u8| sag(i) > 0;
 
         // Check if branch is bifurcating
         let is_bifurcating = branch.can_bifurcate && day >= branch.bifurcation_day;
 
         // Draw the branch with curvature
         for i in 1..=length_int {
-            let x_pos = center as i8 + (i as i8 * branch.direction);
-            let mut y_pos = level as i8;
+            let x_pos = branch_x(i);
+            let mut y_pos = level as i32;
 
             // Apply curvature - branch bends up or down
             if branch.curve != 0 && i > 2 {
-                let curve_amount = ((i - 2) as i8 / 2) * branch.curve;
-                y_pos = (y_pos - curve_amount).max(0).min(27);
+                let curve_amount = ((i - 2) as i32 / 2) * branch.curve as i32;
+                y_pos = (y_pos - curve_amount).clamp(0, soil as i32);
             }
+            // Wilting tips hang down toward the soil
+            y_pos = (y_pos + sag(i)).clamp(0, soil as i32);
 
             // Skip if out of bounds
-            if x_pos < 0 || x_pos >= 70 || y_pos < 0 || y_pos >= 28 { break; }
+            if !(0..width).contains(&x_pos) || !(0..height).contains(&y_pos) { break; }
 
             let x = x_pos as usize;
             let y = y_pos as usize;
 
             // Choose character based on position, curve, and density
-            let ch = if i == length_int && show_flowers {
+            let tip = tip_bud(y).filter(|_| i == length_int);
+            let ch = if let Some(glyph) = tip {
                 // Flower/bud at the tip
-                flower_char.chars().next().unwrap_or('*')
+                glyph
+            } else if drooping(i) {
+                // Hanging down and away from the trunk
+                if branch.direction < 0 { '/' } else { '\\' }
             } else if i == 1 {
                 // Near trunk - use connection character
                 if branch.direction < 0 { '\\' } else { '/' }
@@ -522,24 +1119,40 @@ fn render_plant_structure(
             };
 
             // Only draw if space is empty (don't overwrite)
-            if lines[y][x] == ' ' {
-                lines[y][x] = ch;
+            if lines[y][x].is_blank() {
+                lines[y][x] = Pixel::new(ch, if tip.is_some() { CellKind::Bud } else { CellKind::Branch });
+                if tip.is_some() {
+                    bud_spots.push((x, y, branch.direction as i32));
+                }
+            }
+        }
+
+        // Fan leaves every few columns along the branch, more as the foliage fills in
+        if branch_level > bare_below {
+            for i in (LEAF_SPACING..length_int).step_by(LEAF_SPACING as usize) {
+                let roll = (branch.growth_start_day as usize * 31 + branch.level * 17 + i as usize * 7) % 100;
+                if (roll as f32) < foliage_density * 100.0 {
+                    let curve_amount = if branch.curve != 0 && i > 2 { ((i - 2) as i32 / 2) * branch.curve as i32 } else { 0 };
+                    let x = branch_x(i);
+                    let y = (level as i32 - curve_amount + sag(i)).clamp(0, soil as i32) - 1;
+                    leaf_spots.push((x, y));
+                }
             }
         }
 
         // Add foliage density effect
         if foliage_density > 0.5 && length_int >= 3 && level > 0 {
             for offset in 1..=2 {
-                let foliage_x_pos = center as i8 + ((length_int - offset) as i8 * branch.direction);
+                let foliage_x_pos = branch_x(length_int - offset);
                 let foliage_y = level - 1;
 
-                if foliage_x_pos > 0 && foliage_x_pos < 34 && foliage_y < 14 {
+                if (0..width).contains(&foliage_x_pos) {
                     let fx = foliage_x_pos as usize;
-                    if lines[foliage_y][fx] == ' ' && foliage_density > 0.6 {
-                        lines[foliage_y][fx] = if show_flowers {
-                            if offset == 1 { '*' } else { '.' }
+                    if lines[foliage_y][fx].is_blank() && foliage_density > 0.6 {
+                        lines[foliage_y][fx] = if bud_at(foliage_y).is_some() {
+                            Pixel::new(if offset == 1 { '*' } else { '.' }, CellKind::BudCluster)
                         } else {
-                            ':'
+                            Pixel::new(':', CellKind::Foliage)
                         };
                     }
                 }
@@ -554,20 +1167,24 @@ fn render_plant_structure(
             // Two sub-branches grow from split point
             for sub_dir in [-1, 1].iter() {
                 for i in 1..=2 {
-                    let base_x = center as i8 + (split_point as i8 * branch.direction);
+                    let base_x = branch_x(split_point);
                     let x_pos = base_x + (i * sub_dir);
-                    let y_pos = level as i8 - (i / 2); // Slightly upward
+                    let y_pos = level as i32 - (i / 2); // Slightly upward
 
-                    if x_pos >= 0 && x_pos < 70 && y_pos >= 0 && y_pos < 28 {
+                    if (0..width).contains(&x_pos) && (0..height).contains(&y_pos) {
                         let x = x_pos as usize;
                         let y = y_pos as usize;
 
-                        let ch = if i == 2 && show_flowers {
-                            flower_char.chars().next().unwrap_or('*')
+                        let tip = tip_bud(y).filter(|_| i == 2);
+                        let ch = if let Some(glyph) = tip {
+                            glyph
                         } else if *sub_dir < 0 { '\\' } else { '/' };
 
-                        if lines[y][x] == ' ' {
-                            lines[y][x] = ch;
+                        if lines[y][x].is_blank() {
+                            lines[y][x] = Pixel::new(ch, if tip.is_some() { CellKind::Bud } else { CellKind::Branch });
+                            if tip.is_some() {
+                                bud_spots.push((x, y, *sub_dir));
+                            }
                         }
                     }
                 }
@@ -575,33 +1192,237 @@ fn render_plant_structure(
         }
     }
 
+    // Buds fill out into clusters, then frost hugs them, before the leaves fill in around them
+    if let Some(buds) = buds.filter(|b| b.size > 1 && wilt < LIMP_BUDS_WILT) {
+        for &(x, y, direction) in &bud_spots {
+            draw_bud_cluster(&mut lines, x, y, direction, buds, bud_top);
+        }
+    }
+    if let Some(frost) = buds.map(|b| b.frost).filter(|f| *f > 0.0) {
+        let tips: Vec<(usize, usize)> = bud_spots.iter().map(|&(x, y, _)| (x, y)).collect();
+        draw_trichomes(&mut lines, &tips, frost, structure.seed, frame);
+    }
+
+    // Leaves go in last so they only fill empty space, never trunk or buds
+    for (x, y) in leaf_spots {
+        draw_fan_leaf(&mut lines, x, y);
+    }
+
     // Draw soil line (wider, doubled size)
-    let soil = "~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~";
-    for (i, c) in soil.chars().enumerate() {
-        let x = 16 + i;
-        if x < 70 {
-            lines[27][x] = c;
+    draw_soil(&mut lines, canvas);
+    draw_roots(&mut lines, canvas, look.roots, structure.seed);
+
+    into_art(lines)
+}
+
+/// Lateral root pairs a fully developed root system sends out
+const MAX_ROOT_PAIRS: usize = 4;
+/// One root cell in this many is left out so the roots stay sparser than the canopy
+const ROOT_GAP_ODDS: u64 = 5;
+
+/// Roots in the rows below the soil: a taproot with mirrored laterals that
+/// reach further and deeper as `development` (0-100%) climbs, never past
+/// the soil line's edges
+fn draw_roots(lines: &mut [Vec<Pixel>], canvas: Canvas, development: f32, seed: u64) {
+    let reach = (development / 100.0).clamp(0.0, 1.0);
+    if canvas.roots == 0 || reach == 0.0 {
+        return;
+    }
+    let (soil, center) = (canvas.soil_row(), canvas.center());
+    let depth = ((canvas.roots as f32 * (0.4 + 0.6 * reach)).round() as usize).clamp(1, canvas.roots);
+    let spread = (19 * canvas.width / Canvas::CLASSIC.width) as f32 * reach;
+
+    for line in &mut lines[soil + 1..=soil + depth] {
+        line[center] = Pixel::new('|', CellKind::Root);
+    }
+
+    let pairs = 1 + (reach * (MAX_ROOT_PAIRS - 1) as f32).round() as usize;
+    for pair in 0..pairs {
+        let start = soil + 1 + pair * depth / pairs;
+        // Upper laterals run furthest; each pair is trimmed a little by the seed
+        let trim = 0.7 + (mix(seed, pair as u64) % 30) as f32 / 100.0;
+        let length = (spread * trim * (1.0 - pair as f32 / (pairs + 1) as f32)).round() as usize;
+        let slope = (length / (soil + depth + 1 - start).max(1)).max(1);
+
+        let mut y = start;
+        for step in 1..=length {
+            let drops = step % slope == 0 && y < soil + depth;
+            if mix(seed ^ pair as u64, step as u64).is_multiple_of(ROOT_GAP_ODDS) {
+                y += drops as usize;
+                continue;
+            }
+            for (x, glyph) in [(center + step, if drops { '\\' } else { '-' }), (center - step, if drops { '/' } else { '-' })] {
+                if x < canvas.width {
+                    lines[y][x] = Pixel::new(glyph, CellKind::Root);
+                }
+            }
+            y += drops as usize;
+        }
+    }
+}
+
+/// Rows a fully wilted branch tip hangs down
+const MAX_DROOP_ROWS: f32 = 2.0;
+/// Share of the foliage a fully wilted plant loses
+const WILT_THINNING: f32 = 0.5;
+/// Wilt at which the buds at the tips go limp
+const LIMP_BUDS_WILT: f32 = 0.5;
+
+/// Widest sway at the top of the canvas, in columns
+const MAX_SWAY_COLUMNS: f32 = 2.0;
+/// Radians per animation frame, and per classic row up the plant
+const SWAY_SPEED: f32 = 0.15;
+const SWAY_LAG: f32 = 0.12;
+/// How far a branch tip runs ahead of its base (radians)
+const SWAY_TIP_LEAD: f32 = 0.6;
+
+/// Sway strength by stage: a seedling barely moves, a heavy flowering plant rocks
+fn stage_sway(stage: GrowthStage) -> f32 {
+    match stage {
+        GrowthStage::Seed | GrowthStage::Germination => 0.0,
+        GrowthStage::Seedling => 0.25,
+        GrowthStage::Vegetative => 0.5,
+        GrowthStage::PreFlower => 0.75,
+        GrowthStage::Flowering | GrowthStage::ReadyToHarvest => 1.0,
+    }
+}
+
+/// Columns between fan leaves along a branch
+const LEAF_SPACING: u8 = 3;
+/// Share of the trunk (from the bottom) that is bare once flowering is over
+const LEAF_DROP_SHARE: f32 = 0.4;
+/// Flowering days before the lower leaves start to drop, and days until they are gone
+const LEAF_DROP_START_DAYS: u32 = 18;
+const LEAF_DROP_DAYS: u32 = 18;
+
+/// Fan leaf glyphs: fingers either side of the leaf's centre
+pub const FAN_LEAF: [char; 3] = ['`', 'Y', '\''];
+
+/// How far senescence has gone (0 = lush, 1 = lower leaves all dropped)
+fn senescence(day: u32, stage: GrowthStage, stretch: Stretch) -> f32 {
+    match stage {
+        GrowthStage::ReadyToHarvest => 1.0,
+        GrowthStage::Flowering => {
+            let flowering_days = day.saturating_sub(stretch.flowering_start_day + LEAF_DROP_START_DAYS);
+            (flowering_days as f32 / LEAF_DROP_DAYS as f32).min(1.0)
         }
+        _ => 0.0,
     }
+}
 
-    // Convert to strings - GUARANTEE 70 chars per line
-    lines.into_iter()
-        .map(|line| {
-            let s: String = line.into_iter().collect();
-            // Ensure exactly 70 chars
-            format!("{:70}", s.get(..70).unwrap_or(&s))
-        })
-        .collect()
+/// A three-glyph fan leaf centred on (x, y), drawn only where the canvas is empty
+fn draw_fan_leaf(lines: &mut [Vec<Pixel>], x: i32, y: i32) {
+    let Some(row) = usize::try_from(y).ok().and_then(|y| lines.get_mut(y)) else {
+        return;
+    };
+    let free = |row: &[Pixel], x: i32| usize::try_from(x).ok().and_then(|x| row.get(x)).is_some_and(|p| p.is_blank());
+    if !free(row, x) {
+        return;
+    }
+    for (dx, glyph) in (-1..=1).zip(FAN_LEAF) {
+        if free(row, x + dx) {
+            row[(x + dx) as usize] = Pixel::new(glyph, CellKind::FanLeaf);
+        }
+    }
 }
 
 // Removed get_drying_ascii() - no longer have drying room feature
 
+/// Three-row block letter for a harvest grade
+pub fn get_grade_ascii(grade: Grade) -> [&'static str; 3] {
+    match grade {
+        Grade::S => ["█▀▀", "▀▀█", "▀▀▀"],
+        Grade::A => ["█▀█", "█▀█", "▀ ▀"],
+        Grade::B => ["█▀▄", "█▀▄", "▀▀ "],
+        Grade::C => ["█▀▀", "█  ", "▀▀▀"],
+        Grade::D => ["█▀▄", "█ █", "▀▀ "],
+    }
+}
+
 /// Get animated border decoration
 pub fn get_border_decoration(frame: usize) -> &'static str {
     let decorations = ["~", "~", "-", "-"];
     decorations[frame % decorations.len()]
 }
 
+/// A stable pseudo-random roll for the `n`th thing drawn from `seed`
+fn mix(seed: u64, n: u64) -> u64 {
+    seed.wrapping_mul(6364136223846793005)
+        .wrapping_add(n)
+        .wrapping_mul(1442695040888963407)
+        >> 33
+}
+
+/// Leaves knocked off by one stress event
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LeafDrop {
+    pub day: u32,      // Day the stress was recorded
+    pub leaves: usize, // How many leaves it knocks off
+}
+
+/// One dropped leaf, in canvas cells
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FallingLeaf {
+    pub x: usize,
+    pub y: usize,
+    pub glyph: char,
+    pub landed: bool, // Lying on the soil line as debris
+}
+
+/// Leaves drop during the first half of the stress day and take this long to fall
+const LEAF_FALL_START_DAYS: f32 = 0.5;
+const LEAF_FALL_DAYS: f32 = 0.4;
+/// Days fallen leaves lie on the soil
+const LEAF_DEBRIS_DAYS: f32 = 2.0;
+
+/// Tumbling and resting leaf glyphs
+const FALLING_LEAF: [char; 3] = ['`', '\'', ','];
+const LEAF_DEBRIS: [char; 2] = [',', '.'];
+
+/// Where the leaves knocked off by recent stress are at `age` (days since
+/// planting, with the hour as a fraction). Everything comes from the drop
+/// day, plant seed and frame, so the fall replays identically after a load
+pub fn falling_leaves(drops: &[LeafDrop], age: f32, seed: u64, frame: usize, canvas: Canvas) -> Vec<FallingLeaf> {
+    let soil = canvas.soil_row();
+    let mut leaves = Vec::new();
+    for drop in drops {
+        let since = age - drop.day as f32;
+        if !(0.0..LEAF_FALL_START_DAYS + LEAF_FALL_DAYS + LEAF_DEBRIS_DAYS).contains(&since) {
+            continue;
+        }
+        for k in 0..drop.leaves {
+            let roll = mix(seed ^ drop.day as u64, k as u64);
+            let start = (roll % 100) as f32 / 100.0 * LEAF_FALL_START_DAYS;
+            let fallen = (since - start) / LEAF_FALL_DAYS;
+            if fallen < 0.0 {
+                continue; // Still on the plant
+            }
+
+            // Off a branch somewhere in the middle of the plant
+            let spread = canvas.width as i32 / 4;
+            let x0 = canvas.center() as i32 + (roll / 100 % (2 * spread as u64 + 1)) as i32 - spread;
+            let y0 = soil - (soil - 1) * (30 + (roll / 10_000 % 40) as usize) / 100;
+
+            let landed = fallen >= 1.0;
+            let (x, y, glyph) = if landed {
+                (x0, soil, LEAF_DEBRIS[k % LEAF_DEBRIS.len()])
+            } else {
+                // Flutter side to side on the way down
+                let flutter = [0, 1, 0, -1][(frame / 2 + k) % 4];
+                let y = y0 + ((soil - y0) as f32 * fallen) as usize;
+                (x0 + flutter, y, FALLING_LEAF[(frame / 3 + k) % FALLING_LEAF.len()])
+            };
+            leaves.push(FallingLeaf {
+                x: x.clamp(0, canvas.width as i32 - 1) as usize,
+                y: y.min(soil),
+                glyph,
+                landed,
+            });
+        }
+    }
+    leaves
+}
+
 /// Get animated water drops
 pub fn get_water_drops(frame: usize) -> &'static str {
     let drops = [".", "o", ".", "O", ".", "o", ".", " "];
@@ -614,4 +1435,36 @@ pub fn get_nutrient_sparkles(frame: usize) -> &'static str {
     sparkles[frame % sparkles.len()]
 }
 
-// Removed get_jar_ascii() and get_fill() - no longer have jar/curing feature
+/// Interior rows of the curing jar
+pub const JAR_ROWS: usize = 4;
+
+/// Get the curing jar with buds filled to `fill` (0.0-1.0)
+/// Condensation drips down the glass while the jar needs burping
+pub fn get_jar_ascii(fill: f32, humid: bool, frame: usize) -> Vec<String> {
+    let filled = (fill.clamp(0.0, 1.0) * JAR_ROWS as f32).round() as usize;
+    let drip_row = frame / 2 % JAR_ROWS;
+
+    let mut lines = vec![
+        "  .------.  ".to_string(),
+        "  |______|  ".to_string(),
+        " /        \\ ".to_string(),
+    ];
+    for row in 0..JAR_ROWS {
+        let inside = if JAR_ROWS - row <= filled {
+            get_fill(row, frame)
+        } else {
+            "        ".to_string()
+        };
+        let wall = if humid && row == drip_row { ':' } else { '|' };
+        lines.push(format!(" {}{}{} ", wall, inside, wall));
+    }
+    lines.push(" \\________/ ".to_string());
+    lines
+}
+
+/// One row of buds inside the jar, shifted per row so it doesn't look tiled
+pub fn get_fill(row: usize, frame: usize) -> String {
+    let buds = "@%&@*%@&%@";
+    let offset = (row * 3 + frame / 8) % 2;
+    buds.chars().skip(offset + row % 2).take(8).collect()
+}
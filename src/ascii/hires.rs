@@ -0,0 +1,218 @@
+use serde::{Deserialize, Serialize};
+
+use super::art::{Canvas, CellKind, PlantArt};
+
+/// How the plant canvas is turned into terminal cells
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum RenderStyle {
+    /// One ASCII glyph per cell (works everywhere)
+    #[default]
+    Ascii,
+    /// Two rows per cell with ▀ ▄ █
+    HalfBlock,
+    /// Two columns by four rows per cell with braille dots
+    Braille,
+}
+
+impl RenderStyle {
+    pub fn next(self) -> Self {
+        match self {
+            RenderStyle::Ascii => RenderStyle::HalfBlock,
+            RenderStyle::HalfBlock => RenderStyle::Braille,
+            RenderStyle::Braille => RenderStyle::Ascii,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            RenderStyle::Ascii => "ASCII",
+            RenderStyle::HalfBlock => "half blocks",
+            RenderStyle::Braille => "braille",
+        }
+    }
+
+    /// Fine-canvas pixels per terminal cell (columns, rows)
+    pub fn subpixels(self) -> (usize, usize) {
+        match self {
+            RenderStyle::Ascii => (1, 1),
+            RenderStyle::HalfBlock => (1, 2),
+            RenderStyle::Braille => (2, 4),
+        }
+    }
+
+    /// Style actually drawn: the Unicode styles need a UTF-8 terminal
+    pub fn effective(self, unicode: bool) -> Self {
+        if unicode {
+            self
+        } else {
+            RenderStyle::Ascii
+        }
+    }
+
+    /// Canvas to draw the plant on so it downsamples to `display`
+    pub fn fine_canvas(self, display: Canvas) -> Canvas {
+        let (cols, rows) = self.subpixels();
+        Canvas {
+            width: display.width * cols,
+            height: display.height * rows,
+            roots: display.roots * rows,
+        }
+    }
+}
+
+/// One terminal cell of the plant: the glyph to draw and what part of the
+/// plant it shows, which decides its color
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlantCell {
+    pub glyph: char,
+    pub kind: CellKind,
+}
+
+/// Which fine-canvas pixel colors a cell when several share it; buds win so
+/// flowers never vanish into the leaves
+fn priority(kind: CellKind) -> u8 {
+    match kind {
+        CellKind::Bud | CellKind::BudCluster => 5,
+        CellKind::Trunk => 4,
+        CellKind::Foliage | CellKind::FanLeaf | CellKind::Trichome => 3,
+        CellKind::Branch | CellKind::Seed | CellKind::Root => 2,
+        CellKind::Soil => 1,
+        CellKind::Empty => 0,
+    }
+}
+
+/// Braille dot bit for a pixel inside a 2x4 cell
+const BRAILLE_DOTS: [[u32; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+/// Collapse a canvas drawn on `style.fine_canvas` into terminal cells. A cell
+/// holding a bud is filled solid, so flowers show as clusters
+pub fn downsample(art: &PlantArt, style: RenderStyle) -> Vec<Vec<PlantCell>> {
+    let (cols, rows) = style.subpixels();
+    let pixels: Vec<Vec<char>> = art.lines.iter().map(|l| l.chars().collect()).collect();
+    let width = pixels.first().map_or(0, Vec::len) / cols;
+
+    pixels
+        .chunks(rows)
+        .zip(art.kinds.chunks(rows))
+        .map(|(band, kinds)| {
+            (0..width)
+                .map(|x| {
+                    let (mut source, mut kind) = (' ', CellKind::Empty);
+                    let mut dots = 0;
+                    for (dy, (line, line_kinds)) in band.iter().zip(kinds).enumerate() {
+                        for dx in 0..cols {
+                            let (ch, ch_kind) = (line[x * cols + dx], line_kinds[x * cols + dx]);
+                            if ch != ' ' {
+                                dots |= BRAILLE_DOTS[dy][dx];
+                            }
+                            if priority(ch_kind) > priority(kind) {
+                                (source, kind) = (ch, ch_kind);
+                            }
+                        }
+                    }
+                    PlantCell {
+                        glyph: cell_glyph(style, source, kind, dots),
+                        kind,
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn cell_glyph(style: RenderStyle, source: char, kind: CellKind, dots: u32) -> char {
+    let bud = priority(kind) == priority(CellKind::Bud);
+    match style {
+        RenderStyle::Ascii => source,
+        RenderStyle::HalfBlock => match (bud, dots & 0x01 != 0, dots & 0x02 != 0) {
+            (true, _, _) | (false, true, true) => '█',
+            (false, true, false) => '▀',
+            (false, false, true) => '▄',
+            (false, false, false) => ' ',
+        },
+        RenderStyle::Braille => {
+            let dots = if bud { 0xFF } else { dots };
+            if dots == 0 {
+                ' '
+            } else {
+                char::from_u32(0x2800 + dots).unwrap_or(' ')
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A canvas from glyph rows and matching rows of kinds, one letter per
+    /// cell: b(ranch) t(runk) u (bud) c (bud cluster) s(oil) r(oot)
+    fn canvas(rows: &[&str], kinds: &[&str]) -> PlantArt {
+        let kind = |k: char| match k {
+            'b' => CellKind::Branch,
+            't' => CellKind::Trunk,
+            'u' => CellKind::Bud,
+            'c' => CellKind::BudCluster,
+            's' => CellKind::Soil,
+            'r' => CellKind::Root,
+            _ => CellKind::Empty,
+        };
+        PlantArt {
+            lines: rows.iter().map(|r| r.to_string()).collect(),
+            kinds: kinds.iter().map(|r| r.chars().map(kind).collect()).collect(),
+        }
+    }
+
+    #[test]
+    fn braille_sets_one_dot_per_pixel() {
+        let fine = canvas(&["/   ", " |  ", "    ", "~~~~"], &["b   ", " t  ", "    ", "ssss"]);
+        let cells = downsample(&fine, RenderStyle::Braille);
+
+        assert_eq!(cells.len(), 1);
+        assert_eq!(cells[0].len(), 2);
+        // Left cell: (0,0), (1,1) and the soil row
+        assert_eq!(cells[0][0].glyph, char::from_u32(0x2800 + 0x01 + 0x10 + 0x40 + 0x80).unwrap());
+        assert_eq!(cells[0][0].kind, CellKind::Trunk, "the trunk outranks the branch");
+        assert_eq!(cells[0][1], PlantCell { glyph: '⣀', kind: CellKind::Soil });
+    }
+
+    #[test]
+    fn cells_keep_the_kind_not_the_glyph() {
+        // A thick '#' branch is no bud, however much it looks like one
+        let fine = canvas(&["#*", "  "], &["bc", "  "]);
+        let cells = downsample(&fine, RenderStyle::HalfBlock);
+        assert_eq!(cells[0][0], PlantCell { glyph: '▀', kind: CellKind::Branch });
+        assert_eq!(cells[0][1], PlantCell { glyph: '█', kind: CellKind::BudCluster });
+
+        // Root pixels share the trunk's '|' but stay roots
+        let fine = canvas(&["|", "|"], &["r", "r"]);
+        assert_eq!(downsample(&fine, RenderStyle::HalfBlock)[0][0].kind, CellKind::Root);
+    }
+
+    #[test]
+    fn half_blocks_follow_the_pixel_rows() {
+        let fine = canvas(&["/ |", "  |", " @ "], &["b t", "  t", " u "]);
+        let glyphs: Vec<String> = downsample(&fine, RenderStyle::HalfBlock)
+            .iter()
+            .map(|row| row.iter().map(|c| c.glyph).collect())
+            .collect();
+
+        assert_eq!(glyphs[0], "▀ █");
+        assert_eq!(glyphs[1], " █ ", "buds fill the whole cell");
+    }
+
+    #[test]
+    fn ascii_is_passed_through() {
+        let fine = canvas(&[" o/", "~~~"], &[" ub", "sss"]);
+        let cells = downsample(&fine, RenderStyle::Ascii);
+        let glyphs: String = cells[0].iter().map(|c| c.glyph).collect();
+        assert_eq!(glyphs, " o/");
+        assert_eq!(cells[1][0].kind, CellKind::Soil);
+    }
+
+    #[test]
+    fn unicode_styles_fall_back_without_utf8() {
+        assert_eq!(RenderStyle::Braille.effective(false), RenderStyle::Ascii);
+        assert_eq!(RenderStyle::HalfBlock.effective(true), RenderStyle::HalfBlock);
+    }
+}
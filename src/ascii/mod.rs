@@ -1,3 +1,9 @@
 pub mod art;
+pub mod hires;
 
 pub use art::*;
+
+#[cfg(test)]
+mod animation_tests;
+#[cfg(test)]
+mod snapshot_tests;
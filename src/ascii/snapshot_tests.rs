@@ -0,0 +1,684 @@
+//! Golden snapshots of procedural plants
+//!
+//! Each seed renders a few growth days and is compared against
+//! `src/ascii/snapshots/seed_<n>.txt`; the `_animated` goldens hold several
+//! frames of each day. When a visual change is deliberate, regenerate the
+//! goldens with:
+//!
+//! ```text
+//! UPDATE_SNAPSHOTS=1 cargo test snapshot
+//! ```
+
+use std::fs;
+use std::path::PathBuf;
+
+use super::art::{
+    draw_plant, falling_leaves, frost_score, get_plant_art, get_plant_ascii, render_animation, Appearance, Canvas, CellKind, HeightClass, LeafDrop, Phenotype, PlantStructure, PlantTraits,
+    Stretch, StructureCache, StructureRng, MIN_TRUNK_HEIGHT, PLANT_CACHE_CAPACITY,
+};
+use super::hires::{downsample, RenderStyle};
+use crate::domain::plant::STRETCH_DAYS;
+use crate::domain::GrowthStage;
+
+/// Canvas size the renderer promises
+const WIDTH: usize = 70;
+const HEIGHT: usize = 28;
+
+const SEEDS: [u64; 3] = [1, 42, 0xDEAD_BEEF];
+
+const DAYS: [(GrowthStage, u32); 5] = [
+    (GrowthStage::Seedling, 5),
+    (GrowthStage::Vegetative, 25),
+    (GrowthStage::PreFlower, 45),
+    (GrowthStage::Flowering, 65),
+    (GrowthStage::ReadyToHarvest, 90),
+];
+
+/// Classic bud glyphs on a full, still canopy (the goldens use it)
+const CLASSIC: Appearance = Appearance {
+    flower_variant: 1,
+    canopy_density: 100.0,
+    canvas: Canvas::CLASSIC,
+    sway: 0.0,
+    wilt: 0.0,
+    frost: 0.0,
+    roots: 0.0,
+};
+
+const STRETCH: Stretch = Stretch {
+    flowering_start_day: 49,
+    factor: 1.0,
+};
+
+/// Frames drawn per day in the animated goldens; enough to cycle the trunk,
+/// bud and glint glyphs
+const FRAMES: usize = 4;
+
+/// The classic look with some frost, so the animated goldens catch the glint
+const FROSTY: Appearance = Appearance { frost: 0.8, ..CLASSIC };
+
+fn snapshot_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src/ascii/snapshots")
+        .join(format!("{}.txt", name))
+}
+
+/// Render every sample day for a seed, checking the canvas invariants on the way
+fn render_seed(seed: u64, phenotype: Option<Phenotype>) -> String {
+    render_seed_on(seed, phenotype, Canvas::CLASSIC)
+}
+
+fn render_seed_on(seed: u64, phenotype: Option<Phenotype>, canvas: Canvas) -> String {
+    let look = Appearance { canvas, ..CLASSIC };
+    let mut out = String::new();
+    for (stage, day) in DAYS {
+        let traits = PlantTraits {
+            phenotype,
+            ..PlantTraits::default()
+        };
+        let lines = get_plant_ascii(stage, day, seed, traits, look, 0, STRETCH);
+
+        assert_eq!(lines.len(), canvas.height, "seed {} {:?} day {}: line count", seed, stage, day);
+        for (i, line) in lines.iter().enumerate() {
+            assert_eq!(
+                line.chars().count(),
+                canvas.width,
+                "seed {} {:?} day {}: line {} width",
+                seed,
+                stage,
+                day,
+                i
+            );
+        }
+
+        out.push_str(&format!("== {:?} day {} ==\n", stage, day));
+        for line in lines {
+            out.push_str(&line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+fn assert_snapshot(name: &str, actual: &str) {
+    let path = snapshot_path(name);
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::write(&path, actual).expect("write snapshot");
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!("missing snapshot {} (run with UPDATE_SNAPSHOTS=1 to create it)", path.display())
+    });
+    assert!(
+        expected == actual,
+        "snapshot {} changed (run with UPDATE_SNAPSHOTS=1 if this is intentional)",
+        name
+    );
+}
+
+#[test]
+fn snapshot_rolled_phenotypes() {
+    for seed in SEEDS {
+        assert_snapshot(&format!("seed_{}", seed), &render_seed(seed, None));
+    }
+}
+
+#[test]
+fn snapshot_strain_phenotypes() {
+    for (phenotype, name) in [
+        (Phenotype::Bushy, "bushy"),
+        (Phenotype::Tall, "tall"),
+        (Phenotype::Balanced, "balanced"),
+    ] {
+        assert_snapshot(&format!("seed_42_{}", name), &render_seed(42, Some(phenotype)));
+    }
+}
+
+#[test]
+fn snapshot_canvas_sizes() {
+    for (width, height) in [(60, 20), (70, 28), (120, 40)] {
+        let canvas = Canvas { width, height, roots: 0 };
+        assert_snapshot(&format!("seed_42_{}x{}", width, height), &render_seed_on(42, None, canvas));
+    }
+}
+
+#[test]
+fn snapshot_unicode_styles() {
+    let display = Canvas { width: 60, height: 20, roots: 0 };
+    for style in [RenderStyle::HalfBlock, RenderStyle::Braille] {
+        let look = Appearance { canvas: style.fine_canvas(display), ..CLASSIC };
+        let mut out = String::new();
+        for (stage, day) in [(GrowthStage::Vegetative, 25), (GrowthStage::ReadyToHarvest, 90)] {
+            let fine = get_plant_art(stage, day, 42, PlantTraits::default(), look, 0, STRETCH);
+            let cells = downsample(&fine, style);
+            assert_eq!(cells.len(), display.height);
+            assert!(cells.iter().all(|row| row.len() == display.width));
+
+            out.push_str(&format!("== {:?} day {} ==\n", stage, day));
+            for row in cells {
+                out.extend(row.iter().map(|cell| cell.glyph));
+                out.push('\n');
+            }
+        }
+        assert_snapshot(&format!("seed_42_{}", style.name().replace(' ', "_")), &out);
+    }
+}
+
+/// Which cells are drawn, ignoring the trunk and bud glyphs that cycle every frame
+fn shape(lines: &[String]) -> Vec<String> {
+    lines.iter().map(|l| l.chars().map(|c| if c == ' ' { ' ' } else { '#' }).collect()).collect()
+}
+
+/// First and last drawn column of a row
+fn span(line: &str) -> Option<(usize, usize)> {
+    let cols: Vec<usize> = line.chars().enumerate().filter(|(_, c)| *c != ' ').map(|(x, _)| x).collect();
+    Some((*cols.first()?, *cols.last()?))
+}
+
+#[test]
+fn snapshot_wind_sway() {
+    let windy = Appearance { sway: 1.0, ..CLASSIC };
+    let mut out = String::new();
+    for frame in [0, 4, 8] {
+        let still = get_plant_ascii(GrowthStage::Flowering, 65, 42, PlantTraits::default(), CLASSIC, frame, STRETCH);
+        let swayed = get_plant_ascii(GrowthStage::Flowering, 65, 42, PlantTraits::default(), windy, frame, STRETCH);
+        assert_eq!(swayed.len(), HEIGHT);
+        assert!(swayed.iter().all(|l| l.chars().count() == WIDTH), "frame {}: sway stays on the canvas", frame);
+        assert_eq!(
+            swayed,
+            get_plant_ascii(GrowthStage::Flowering, 65, 42, PlantTraits::default(), windy, frame, STRETCH),
+            "frame {}: sway is deterministic",
+            frame
+        );
+
+        // Nothing moves further than the widest sway (the soil never moves)
+        for (y, (a, b)) in still.iter().zip(&swayed).enumerate() {
+            if let (Some(a), Some(b)) = (span(a), span(b)) {
+                assert!(a.0.abs_diff(b.0) <= 2 && a.1.abs_diff(b.1) <= 2, "frame {} row {}: {:?} -> {:?}", frame, y, a, b);
+            }
+        }
+        assert_eq!(still[HEIGHT - 1], swayed[HEIGHT - 1]);
+
+        out.push_str(&format!("== frame {} ==\n", frame));
+        for line in swayed {
+            out.push_str(&line);
+            out.push('\n');
+        }
+    }
+    assert_snapshot("seed_42_sway", &out);
+}
+
+/// Every sample day for a seed, `FRAMES` frames each, from `cache`
+fn render_frames(cache: &mut StructureCache, seed: u64) -> String {
+    let structure = cache.get_or_generate(seed, PlantTraits::default());
+    let mut out = String::new();
+    for (stage, day) in DAYS {
+        let frames = render_animation(&structure, stage, day, false, FROSTY, STRETCH, 0..FRAMES);
+        for (frame, lines) in frames.into_iter().enumerate() {
+            out.push_str(&format!("== {:?} day {} frame {} ==\n", stage, day, frame));
+            for line in lines {
+                out.push_str(&line);
+                out.push('\n');
+            }
+        }
+    }
+    out
+}
+
+#[test]
+fn snapshot_animation_frames() {
+    let mut cache = StructureCache::default();
+    for seed in SEEDS {
+        assert_snapshot(&format!("seed_{}_animated", seed), &render_frames(&mut cache, seed));
+    }
+}
+
+#[test]
+fn every_frame_replays_exactly() {
+    for seed in SEEDS {
+        let first = render_frames(&mut StructureCache::default(), seed);
+        let again = render_frames(&mut StructureCache::default(), seed);
+        assert_eq!(first, again, "seed {}", seed);
+    }
+}
+
+#[test]
+fn a_private_cache_draws_what_the_shared_one_does() {
+    let mut cache = StructureCache::default();
+    for seed in SEEDS {
+        let structure = cache.get_or_generate(seed, PlantTraits::default());
+        for (stage, day) in DAYS {
+            for frame in 0..FRAMES {
+                assert_eq!(
+                    draw_plant(&structure, stage, day, false, FROSTY, frame, STRETCH),
+                    get_plant_ascii(stage, day, seed, PlantTraits::default(), FROSTY, frame, STRETCH),
+                    "seed {} {:?} frame {}",
+                    seed,
+                    stage,
+                    frame
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn reduced_motion_holds_the_plant_still() {
+    let render = |look: Appearance, frame| {
+        shape(&get_plant_ascii(GrowthStage::ReadyToHarvest, 90, 42, PlantTraits::default(), look, frame, STRETCH))
+    };
+    let still = render(CLASSIC, 0);
+    assert!((0..40).all(|frame| render(CLASSIC, frame) == still));
+
+    let windy = Appearance { sway: 1.0, ..CLASSIC };
+    assert!((0..40).any(|frame| render(windy, frame) != render(windy, 0)), "the breeze moves the plant");
+}
+
+#[test]
+fn wilted_plants_droop_and_go_limp() {
+    let render = |wilt| {
+        let look = Appearance { wilt, ..CLASSIC };
+        get_plant_ascii(GrowthStage::Flowering, 65, 42, PlantTraits::default(), look, 0, STRETCH)
+    };
+    let drawn = |lines: &[String]| lines.iter().flat_map(|l| l.chars()).filter(|c| *c != ' ').count();
+    let healthy = render(0.0);
+    let wilted = render(1.0);
+
+    assert!(wilted.iter().all(|l| l.chars().count() == WIDTH));
+    assert!(wilted.iter().any(|l| l.contains(',')), "tips go limp");
+    assert!(!wilted.iter().any(|l| l.contains('o')), "no upright buds left");
+    assert!(drawn(&wilted) < drawn(&healthy), "the foliage thins out");
+
+    // A structure the wilted render never touched draws the recovered plant
+    let fresh = StructureCache::default().get_or_generate(42, PlantTraits::default());
+    let baseline = draw_plant(&fresh, GrowthStage::Flowering, 65, false, CLASSIC, 0, STRETCH);
+    assert_eq!(render(0.0), baseline, "recovery restores the normal shape");
+}
+
+#[test]
+fn potent_strains_frost_over_at_harvest() {
+    let render = |frost, frame| {
+        let look = Appearance { frost, ..CLASSIC };
+        get_plant_ascii(GrowthStage::ReadyToHarvest, 90, 42, PlantTraits::default(), look, frame, STRETCH)
+    };
+    let specks = |lines: &[String]| -> Vec<(usize, usize)> {
+        lines
+            .iter()
+            .enumerate()
+            .flat_map(|(y, l)| l.chars().enumerate().filter(|(_, c)| *c == '·').map(move |(x, _)| (x, y)))
+            .collect()
+    };
+
+    assert_eq!(frost_score(10.0, false), 0.0);
+    assert!(frost_score(18.0, true) > frost_score(18.0, false), "Extra Resinous frosts harder");
+    let high = frost_score(28.0, false);
+    let frosted = render(high, 0);
+    assert!(specks(&render(frost_score(14.0, false), 0)).len() * 10 < specks(&frosted).len(), "mild strains get almost none");
+    assert!(frosted.iter().all(|l| l.chars().count() == WIDTH) && frosted.len() == HEIGHT);
+    assert!(specks(&render(frost_score(20.0, false), 0)).len() < specks(&frosted).len(), "more THC, more frost");
+
+    // Speckles glint in place: a dust speck at frame 0 is dust or a crystal later,
+    // and the whole plant repeats after the 8-frame sparkle cycle
+    for frame in 1..8 {
+        let later = render(high, frame);
+        assert!(specks(&frosted).iter().all(|&(x, y)| matches!(later[y].chars().nth(x), Some('·' | '*'))), "frame {}", frame);
+    }
+    assert_eq!(render(high, 8), frosted);
+
+    let flowering = |day| {
+        let look = Appearance { frost: high, ..CLASSIC };
+        specks(&get_plant_ascii(GrowthStage::Flowering, day, 42, PlantTraits::default(), look, 0, STRETCH)).len()
+    };
+    assert_eq!(flowering(60), 0, "young buds carry no frost");
+    assert!(flowering(80) > 0 && flowering(80) < specks(&frosted).len(), "late colas start to frost");
+
+    // That frost is marked as trichomes, apart from the bud glyphs drawn with the same '*'
+    let art = get_plant_art(GrowthStage::Flowering, 80, 42, PlantTraits::default(), Appearance { frost: high, ..CLASSIC }, 0, STRETCH);
+    let cells: Vec<(char, CellKind)> = art.lines.iter().zip(&art.kinds).flat_map(|(l, k)| l.chars().zip(k.iter().copied())).collect();
+    assert!(cells.iter().filter(|(c, _)| *c == '·').all(|(_, k)| *k == CellKind::Trichome));
+    assert!(cells.contains(&('*', CellKind::BudCluster)), "bud companions are not frost");
+}
+
+#[test]
+fn stress_drops_leaves_that_settle_on_the_soil() {
+    let canvas = Canvas::CLASSIC;
+    let drops = [LeafDrop { day: 30, leaves: 4 }];
+    let at = |age: f32, frame| falling_leaves(&drops, age, 42, frame, canvas);
+
+    assert!(at(29.9, 0).is_empty(), "nothing falls before the stress");
+    assert_eq!(at(30.45, 3), at(30.45, 3), "the fall replays identically");
+
+    // Once all have let go, each leaf only moves down, then rests on the soil line
+    let mut previous = [0; 4];
+    for step in 5..=10 {
+        let leaves = at(30.0 + step as f32 * 0.1, step);
+        assert_eq!(leaves.len(), 4);
+        for (k, leaf) in leaves.iter().enumerate() {
+            assert!(leaf.x < canvas.width && leaf.y < canvas.height);
+            assert!(leaf.y >= previous[k], "leaf {} drifted back up", k);
+            previous[k] = leaf.y;
+        }
+    }
+    let settled = at(31.5, 0);
+    assert_eq!(settled.len(), 4);
+    assert!(settled.iter().all(|l| l.landed && l.y == canvas.height - 1));
+    assert!(at(33.0, 0).is_empty(), "the debris clears after a couple of days");
+}
+
+#[test]
+fn buds_fill_out_through_flowering() {
+    // Frame 4 shows '@' tips on the classic variant
+    let bud_cells = |stage, day| {
+        get_plant_ascii(stage, day, 42, PlantTraits::default(), CLASSIC, 4, STRETCH)
+            .iter()
+            .flat_map(|l| l.chars().collect::<Vec<_>>())
+            .filter(|c| matches!(c, 'o' | 'O' | '@' | '#' | '*'))
+            .count()
+    };
+    let early = bud_cells(GrowthStage::Flowering, 55);
+    let mid = bud_cells(GrowthStage::Flowering, 66);
+    let late = bud_cells(GrowthStage::Flowering, 80);
+
+    assert!(early > 0);
+    assert!(early < mid && mid < late, "buds grow: {} -> {} -> {}", early, mid, late);
+    assert!(bud_cells(GrowthStage::ReadyToHarvest, 90) >= late, "harvest keeps the full colas");
+}
+
+#[test]
+fn small_canvases_keep_the_whole_plant() {
+    let canvas = Canvas::fit(40, 12);
+    let look = Appearance { canvas, ..CLASSIC };
+    for seed in SEEDS {
+        let lines = get_plant_ascii(GrowthStage::ReadyToHarvest, 90, seed, PlantTraits::default(), look, 0, STRETCH);
+        assert!(lines[canvas.height - 1].contains('~'), "seed {}: soil stays on the bottom row", seed);
+        assert!(lines[0].trim().is_empty(), "seed {}: the top of the plant fits", seed);
+        assert!(lines.iter().any(|l| l.contains('@')), "seed {}: buds still show", seed);
+    }
+}
+
+#[test]
+fn short_strains_never_fill_the_canvas() {
+    let traits = PlantTraits {
+        height_class: Some(HeightClass::Short),
+        ..PlantTraits::default()
+    };
+    let stretch = Stretch {
+        flowering_start_day: 49,
+        factor: 1.5,
+    };
+
+    for seed in 0..50 {
+        for (stage, day) in DAYS.iter().copied().chain([(GrowthStage::ReadyToHarvest, 120)]) {
+            let lines = get_plant_ascii(stage, day, seed, traits, CLASSIC, 0, stretch);
+            let top = lines.iter().position(|l| !l.trim().is_empty()).unwrap_or(HEIGHT);
+            assert!(top >= 4, "seed {} day {}: short plant reaches row {}", seed, day, top);
+        }
+    }
+}
+
+#[test]
+fn stretch_height_is_gained_only_in_the_window() {
+    let traits = PlantTraits {
+        height_class: Some(HeightClass::Tall),
+        ..PlantTraits::default()
+    };
+    let stretch = Stretch {
+        flowering_start_day: 49,
+        factor: 1.0,
+    };
+    let structure = PlantStructure::get_or_generate(42, traits);
+    let height = |stage, day| structure.trunk_height(day, stage, stretch);
+
+    let start = height(GrowthStage::Flowering, 49);
+    let end = height(GrowthStage::Flowering, 49 + STRETCH_DAYS);
+    assert_eq!(start, height(GrowthStage::PreFlower, 49), "flowering starts from the veg height");
+    assert!(end > start, "the stretch adds height: {} -> {}", start, end);
+    for day in 49 + STRETCH_DAYS..=120 {
+        assert_eq!(height(GrowthStage::Flowering, day), end, "day {} grew after the stretch", day);
+    }
+    assert_eq!(height(GrowthStage::ReadyToHarvest, 120), end);
+}
+
+#[test]
+fn seed_and_germination_sprout_from_the_soil() {
+    let seed = get_plant_ascii(GrowthStage::Seed, 0, 42, PlantTraits::default(), CLASSIC, 0, STRETCH);
+    let sprout = get_plant_ascii(GrowthStage::Germination, 1, 42, PlantTraits::default(), CLASSIC, 0, STRETCH);
+
+    for lines in [&seed, &sprout] {
+        assert_eq!(lines.len(), HEIGHT);
+        assert!(lines.iter().all(|l| l.chars().count() == WIDTH));
+    }
+    assert!(seed[..HEIGHT - 1].iter().all(|l| l.trim().is_empty()), "a seed stays in the soil");
+    assert!(sprout[HEIGHT - 4].contains("( )"), "cotyledons open above the soil");
+}
+
+#[test]
+fn topped_plants_lose_their_upper_buds() {
+    // Frame 0 of the harvest animation draws buds as '@'
+    let topped = PlantTraits {
+        topped: true,
+        ..PlantTraits::default()
+    };
+    for seed in SEEDS {
+        let full = get_plant_ascii(GrowthStage::ReadyToHarvest, 90, seed, PlantTraits::default(), CLASSIC, 0, STRETCH);
+        let cut = get_plant_ascii(GrowthStage::ReadyToHarvest, 90, seed, topped, CLASSIC, 0, STRETCH);
+        let buds = |lines: &[String]| lines.iter().map(|l| l.matches('@').count()).sum::<usize>();
+
+        let top = cut.iter().position(|l| !l.trim().is_empty()).unwrap_or(HEIGHT);
+        let upper_third = top + (HEIGHT - 1 - top) / 3;
+        assert_eq!(buds(&cut[..upper_third]), 0, "seed {}: buds left on the top colas", seed);
+        assert!(buds(&cut) < buds(&full), "seed {}: partial harvest should remove buds", seed);
+        assert!(buds(&cut) > 0, "seed {}: lower buds stay on the plant", seed);
+    }
+}
+
+#[test]
+fn every_drawn_cell_carries_its_kind() {
+    let canvas = Canvas { width: 70, height: 32, roots: 4 };
+    let look = Appearance { canvas, roots: 100.0, frost: 1.0, ..CLASSIC };
+    for (stage, day) in DAYS {
+        let art = get_plant_art(stage, day, 42, PlantTraits::default(), look, 0, STRETCH);
+        assert_eq!(art.kinds.len(), art.lines.len());
+        for (y, (line, kinds)) in art.lines.iter().zip(&art.kinds).enumerate() {
+            assert_eq!(kinds.len(), line.chars().count());
+            for (ch, kind) in line.chars().zip(kinds) {
+                assert_eq!(ch == ' ', *kind == CellKind::Empty, "{:?} day {}: '{}' is {:?}", stage, day, ch, kind);
+                if y > canvas.soil_row() {
+                    assert!(matches!(kind, CellKind::Root | CellKind::Empty), "below the soil is root");
+                }
+            }
+        }
+    }
+
+    // A frosted harvest has trichomes beside its buds, whatever glyphs they share
+    let art = get_plant_art(GrowthStage::ReadyToHarvest, 90, 42, PlantTraits::default(), look, 0, STRETCH);
+    let kinds: Vec<CellKind> = art.kinds.concat();
+    assert!(kinds.contains(&CellKind::Trichome) && kinds.contains(&CellKind::Bud));
+}
+
+#[test]
+fn lower_fan_leaves_drop_late_in_flower() {
+    let leaves = |lines: &[String]| lines.iter().map(|l| l.matches('Y').count()).sum::<usize>();
+    let mut dropped = 0;
+    for seed in SEEDS {
+        let lush = get_plant_ascii(GrowthStage::Flowering, 65, seed, PlantTraits::default(), CLASSIC, 0, STRETCH);
+        let ripe = get_plant_ascii(GrowthStage::ReadyToHarvest, 65, seed, PlantTraits::default(), CLASSIC, 0, STRETCH);
+
+        let lower = HEIGHT * 2 / 3;
+        assert!(leaves(&ripe[lower..]) <= leaves(&lush[lower..]), "seed {}", seed);
+        assert!(leaves(&ripe) > 0, "seed {}: the upper fan leaves stay", seed);
+        dropped += leaves(&lush[lower..]) - leaves(&ripe[lower..]);
+    }
+    assert!(dropped > 0, "some lower leaves should have dropped");
+}
+
+#[test]
+fn flower_variants_pick_their_own_glyphs() {
+    let glyphs = |flower_variant: u8, stage: GrowthStage, day: u32, frame: usize| {
+        let look = Appearance { flower_variant, ..CLASSIC };
+        let lines = get_plant_ascii(stage, day, 7, PlantTraits::default(), look, frame, STRETCH);
+        lines.concat()
+    };
+
+    // Frosty buds glitter with snowflakes at some point in each cycle
+    assert!((0..12).any(|f| glyphs(5, GrowthStage::Flowering, 65, f).contains('❄')));
+    assert!((0..8).any(|f| glyphs(5, GrowthStage::ReadyToHarvest, 90, f).contains('❄')));
+    assert!((0..12).all(|f| !glyphs(CLASSIC.flower_variant, GrowthStage::Flowering, 65, f).contains('❄')));
+
+    // Dense colas stay heavy where the classic buds flash small sparkles
+    let sparkles = |variant: u8| glyphs(variant, GrowthStage::ReadyToHarvest, 90, 3).matches('*').count();
+    assert!(sparkles(0) < sparkles(CLASSIC.flower_variant));
+
+    // The animation still cycles with the same period
+    for variant in 0..6 {
+        assert_eq!(
+            glyphs(variant, GrowthStage::Flowering, 65, 3),
+            glyphs(variant, GrowthStage::Flowering, 65, 15),
+            "variant {}",
+            variant
+        );
+        assert_ne!(
+            glyphs(variant, GrowthStage::Flowering, 65, 0),
+            glyphs(variant, GrowthStage::Flowering, 65, 4),
+            "variant {}",
+            variant
+        );
+    }
+}
+
+#[test]
+fn sparse_canopies_show_fewer_branches() {
+    let branches = |lines: &[String]| lines.iter().map(|l| l.matches(['/', '\\']).count()).sum::<usize>();
+    for seed in SEEDS {
+        let at = |canopy_density: f32| {
+            let look = Appearance { canopy_density, ..CLASSIC };
+            get_plant_ascii(GrowthStage::Flowering, 65, seed, PlantTraits::default(), look, 0, STRETCH)
+        };
+        let (sparse, thin, full) = (at(20.0), at(45.0), at(90.0));
+        assert!(branches(&sparse) <= branches(&thin), "seed {}", seed);
+        assert!(branches(&thin) <= branches(&full), "seed {}", seed);
+        assert!(branches(&sparse) < branches(&full), "seed {}: 20% canopy should look thinner", seed);
+        assert!(branches(&sparse) > 0, "seed {}: the main branches stay", seed);
+    }
+}
+
+#[test]
+fn evicted_plants_render_the_same_when_they_return() {
+    let first = render_seed(7, None);
+    // Grow a long line of other plants through the bounded cache
+    let mut cache = StructureCache::default();
+    let kept = format!("{:?}", cache.get_or_generate(7, PlantTraits::default()));
+    for seed in 100..100 + 3 * PLANT_CACHE_CAPACITY as u64 {
+        render_seed(seed, None);
+        cache.get_or_generate(seed, PlantTraits::default());
+        assert!(cache.len() <= PLANT_CACHE_CAPACITY, "the cache holds {} plants", cache.len());
+    }
+    assert_eq!(cache.len(), PLANT_CACHE_CAPACITY);
+    assert_eq!(render_seed(7, None), first);
+    assert_eq!(format!("{:?}", cache.get_or_generate(7, PlantTraits::default())), kept);
+}
+
+#[test]
+fn every_trunk_split_forks_symmetrically() {
+    // Seed 2 rolls a bushy plant with two splits
+    let traits = PlantTraits {
+        phenotype: Some(Phenotype::Bushy),
+        ..PlantTraits::default()
+    };
+    assert_eq!(PlantStructure::get_or_generate(2, traits).trunk_splits.len(), 2);
+
+    let lines = get_plant_ascii(GrowthStage::Flowering, 80, 2, traits, CLASSIC, 0, STRETCH);
+    let top = lines.iter().position(|l| l.contains('|')).expect("a trunk");
+    let center = WIDTH as i32 / 2;
+    let stems: Vec<i32> = lines[top + 1]
+        .chars()
+        .enumerate()
+        .filter(|(_, c)| *c == '|')
+        .map(|(x, _)| x as i32 - center)
+        .collect();
+
+    assert!(stems.len() >= 3, "both forks show: stems at {:?}", stems);
+    let mut mirrored: Vec<i32> = stems.iter().map(|x| -x).collect();
+    mirrored.reverse();
+    assert_eq!(stems, mirrored, "the forks are symmetric");
+}
+
+#[test]
+fn roots_spread_below_the_soil_with_development() {
+    let canvas = Canvas { width: 70, height: 32, roots: 4 };
+    let roots = |development: f32| {
+        let look = Appearance { canvas, roots: development, ..CLASSIC };
+        let lines = get_plant_ascii(GrowthStage::Vegetative, 40, 42, PlantTraits::default(), look, 0, STRETCH);
+        assert_eq!(lines.len(), canvas.height);
+        assert!(lines[canvas.soil_row()].contains('~'), "the soil sits above the roots");
+        lines[canvas.soil_row() + 1..].to_vec()
+    };
+    let drawn = |rows: &[String]| rows.iter().flat_map(|r| r.chars()).filter(|c| *c != ' ').count();
+    let width = |rows: &[String]| {
+        let columns: Vec<usize> = rows.iter().flat_map(|r| r.chars().enumerate().filter(|(_, c)| *c != ' ').map(|(x, _)| x)).collect();
+        columns.iter().max().unwrap() - columns.iter().min().unwrap()
+    };
+
+    assert_eq!(drawn(&roots(0.0)), 0, "no roots before they develop");
+    let (young, established) = (roots(20.0), roots(100.0));
+    assert!(width(&young) < width(&established), "roots spread as they develop");
+    assert!(drawn(&young) < drawn(&established));
+
+    let soil_width = 38;
+    assert!(width(&established) <= soil_width, "roots stay under the soil line");
+    for row in &established {
+        let left = row.len() - row.trim_start().len();
+        let right = row.trim_end().len() - 1;
+        assert_eq!(canvas.width / 2 - left, right - canvas.width / 2, "roots mirror around the taproot");
+    }
+}
+
+#[test]
+fn empty_structure_ranges_roll_zero() {
+    let mut rng = StructureRng::new(1346);
+    for _ in 0..100 {
+        assert_eq!(rng.below(0), 0);
+        assert_eq!(rng.below(1), 0);
+        assert!(rng.below(3) < 3);
+    }
+}
+
+#[test]
+fn the_smallest_plants_generate_without_underflow() {
+    for seed in 0..200 {
+        for height_class in [None, Some(HeightClass::Short), Some(HeightClass::Medium), Some(HeightClass::Tall)] {
+            let traits = PlantTraits {
+                height_class,
+                height_percent: 1,
+                ..PlantTraits::default()
+            };
+            let structure = PlantStructure::get_or_generate(seed, traits);
+            assert_eq!(structure.max_height, MIN_TRUNK_HEIGHT, "a 1% plant sits on the height floor");
+            assert!(structure.branches.iter().all(|b| b.level >= 1 && b.level < structure.max_height));
+        }
+    }
+}
+
+#[test]
+fn foliage_fills_the_whole_canopy() {
+    let traits = PlantTraits {
+        phenotype: Some(Phenotype::Bushy),
+        ..PlantTraits::default()
+    };
+    let center = WIDTH / 2;
+    for seed in SEEDS {
+        // Late vegetative growth: dense foliage and no buds, so ':' only comes from the foliage pass
+        let lines = get_plant_ascii(GrowthStage::Vegetative, 90, seed, traits, CLASSIC, 0, STRETCH);
+        let foliage: Vec<(usize, usize)> = lines
+            .iter()
+            .enumerate()
+            .flat_map(|(y, l)| l.chars().enumerate().filter(|&(_, c)| c == ':').map(move |(x, _)| (x, y)))
+            .collect();
+
+        assert!(foliage.iter().any(|&(x, _)| x < center), "seed {}: foliage left of the trunk", seed);
+        assert!(foliage.iter().any(|&(x, _)| x > center), "seed {}: foliage right of the trunk", seed);
+        assert!(foliage.iter().any(|&(_, y)| y >= HEIGHT / 2), "seed {}: foliage in the lower half", seed);
+    }
+}
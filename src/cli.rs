@@ -0,0 +1,147 @@
+use std::path::PathBuf;
+
+use ganjatui::storage::sanitize_profile_name;
+use ganjatui::ui::CUSTOM_PREFIX;
+
+/// Command-line options
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CliArgs {
+    /// Custom strain database (`--strains <path>`)
+    pub strains: Option<PathBuf>,
+    /// Fixed RNG seed for a reproducible game (`--seed <n>`)
+    pub seed: Option<u64>,
+    /// Directory for the save file (`--save-dir <path>`)
+    pub save_dir: Option<PathBuf>,
+    /// Rotating save backups to keep (`--backups <n>`)
+    pub backups: Option<usize>,
+    /// Roll the save back to backup n before starting (`--restore-backup <n>`)
+    pub restore_backup: Option<usize>,
+    /// Save profile to play, already sanitized (`--profile <name>`)
+    pub profile: Option<String>,
+    /// Palette to draw with (`--theme custom:<name>`); `Some(None)` is
+    /// `--theme default`, back to the built-in colors
+    pub theme: Option<Option<String>>,
+    /// Check the strain files and exit (`--validate-strains`)
+    pub validate_strains: bool,
+}
+
+/// Usage text printed for `--help` and argument errors
+pub const USAGE: &str = "Usage: ganjatui [--strains <path>] [--seed <n>] [--save-dir <path>]
+               [--backups <n>] [--restore-backup <n>] [--profile <name>]
+               [--theme custom:<name>] [--validate-strains]
+
+Options:
+  --strains <path>        Load the strain database from a JSON file
+  --seed <n>              Start a fresh, reproducible game from seed n (not saved)
+  --save-dir <path>       Keep the save file in this directory
+                          (default: $GANJATUI_SAVE_DIR, then the data directory:
+                          $GANJATUI_DATA_DIR, $XDG_DATA_HOME/ganjatui or the
+                          platform default)
+  --backups <n>           Hourly save backups to keep (default 3, 0 turns them off)
+  --restore-backup <n>    Roll the save back to backup n (1 = newest) and start
+  --profile <name>        Play (or create) this save profile; without it a
+                          picker lists the profiles and can add a new one
+  --theme custom:<name>   Draw with palettes/<name>.toml from the config
+                          directory (e.g. ~/.config/ganjatui/palettes);
+                          remembered in the save, `--theme default` goes back
+                          (`T` cycles the palettes in game)
+  --validate-strains      Check the strain files the game would load, report
+                          any problems and exit (non-zero if there are any)
+  -h, --help              Show this help";
+
+impl CliArgs {
+    /// Parse arguments (without the program name)
+    /// Returns `Ok(None)` when help was requested
+    pub fn parse<I>(args: I) -> Result<Option<Self>, String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut cli = Self::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => return Ok(None),
+                "--validate-strains" => cli.validate_strains = true,
+                "--strains" => {
+                    let path = args.next().ok_or("--strains needs a path")?;
+                    cli.strains = Some(PathBuf::from(path));
+                }
+                "--seed" => {
+                    let seed = args.next().ok_or("--seed needs a number")?;
+                    cli.seed = Some(parse_seed(&seed)?);
+                }
+                "--save-dir" => {
+                    let path = args.next().ok_or("--save-dir needs a path")?;
+                    cli.save_dir = Some(PathBuf::from(path));
+                }
+                "--backups" => {
+                    let count = args.next().ok_or("--backups needs a number")?;
+                    cli.backups = Some(parse_count("--backups", &count)?);
+                }
+                "--profile" => {
+                    let name = args.next().ok_or("--profile needs a name")?;
+                    cli.profile = Some(parse_profile(&name)?);
+                }
+                "--theme" => {
+                    let theme = args.next().ok_or("--theme needs custom:<name> or default")?;
+                    cli.theme = Some(parse_theme(&theme)?);
+                }
+                "--restore-backup" => {
+                    let n = args.next().ok_or("--restore-backup needs a backup number")?;
+                    cli.restore_backup = Some(parse_count("--restore-backup", &n)?);
+                }
+                other => {
+                    if let Some(path) = other.strip_prefix("--strains=") {
+                        cli.strains = Some(PathBuf::from(path));
+                    } else if let Some(seed) = other.strip_prefix("--seed=") {
+                        cli.seed = Some(parse_seed(seed)?);
+                    } else if let Some(path) = other.strip_prefix("--save-dir=") {
+                        cli.save_dir = Some(PathBuf::from(path));
+                    } else if let Some(count) = other.strip_prefix("--backups=") {
+                        cli.backups = Some(parse_count("--backups", count)?);
+                    } else if let Some(name) = other.strip_prefix("--profile=") {
+                        cli.profile = Some(parse_profile(name)?);
+                    } else if let Some(theme) = other.strip_prefix("--theme=") {
+                        cli.theme = Some(parse_theme(theme)?);
+                    } else if let Some(n) = other.strip_prefix("--restore-backup=") {
+                        cli.restore_backup = Some(parse_count("--restore-backup", n)?);
+                    } else {
+                        return Err(format!("unknown argument '{}'", other));
+                    }
+                }
+            }
+        }
+
+        Ok(Some(cli))
+    }
+}
+
+fn parse_seed(value: &str) -> Result<u64, String> {
+    value
+        .parse()
+        .map_err(|_| format!("--seed expects a whole number, got '{}'", value))
+}
+
+fn parse_count(flag: &str, value: &str) -> Result<usize, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{} expects a whole number, got '{}'", flag, value))
+}
+
+fn parse_profile(name: &str) -> Result<String, String> {
+    sanitize_profile_name(name).ok_or_else(|| format!("'{}' is not a usable profile name", name))
+}
+
+fn parse_theme(value: &str) -> Result<Option<String>, String> {
+    if value == "default" {
+        return Ok(None);
+    }
+    let name = value
+        .strip_prefix(CUSTOM_PREFIX)
+        .ok_or_else(|| format!("--theme expects custom:<name> or default, got '{}'", value))?;
+    // Palette names become file names, so they follow the profile name rules
+    sanitize_profile_name(name)
+        .map(Some)
+        .ok_or_else(|| format!("'{}' is not a usable palette name", name))
+}
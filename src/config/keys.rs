@@ -0,0 +1,600 @@
+use std::collections::BTreeMap;
+use std::fs;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Key config file name (inside the data directory)
+const KEYS_FILE: &str = "keys.json";
+
+/// Actions that can be bound to keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Action {
+    Quit,
+    SaveNow,
+    Harvest,
+    PartialHarvest,
+    UndoHarvest,
+    FinishDrying,
+    BurpJar,
+    FinishCure,
+    ToggleAuto,
+    AutoHarvestSooner,
+    AutoHarvestLater,
+    ToggleCare,
+    Water,
+    Feed,
+    LightUp,
+    LightDown,
+    ToggleCo2,
+    ToggleBell,
+    TogglePauseWhenReady,
+    ToggleCompression,
+    CycleSaveFormat,
+    ToggleUnits,
+    FastForward,
+    CycleFrameRate,
+    CycleAutosave,
+    CycleOfflineGrowth,
+    CycleDifficulty,
+    CycleMedium,
+    ReloadStrains,
+    CycleMode,
+    CycleModePrev,
+    ModeZen,
+    ModeRainbow,
+    ModeMatrix,
+    ModeNormal,
+    CycleRenderStyle,
+    CycleTheme,
+    ToggleReducedMotion,
+    GrowingRoom,
+    SwitchStats,
+    CareLog,
+    Breeding,
+    StrainLibrary,
+    StrainCompare,
+    Goals,
+    CursorUp,
+    CursorDown,
+    PageUp,
+    PageDown,
+    Confirm,
+    PickParent,
+    Search,
+    CycleSort,
+    ClearGoal,
+    SwitchSide,
+    PlantFeatured,
+    ExportCode,
+    ExportArt,
+    ExportDiary,
+    ImportCode,
+    NewGame,
+    Help,
+}
+
+impl Action {
+    /// All actions, in display order
+    pub const ALL: [Action; 62] = [
+        Action::Quit,
+        Action::SaveNow,
+        Action::Harvest,
+        Action::PartialHarvest,
+        Action::UndoHarvest,
+        Action::FinishDrying,
+        Action::BurpJar,
+        Action::FinishCure,
+        Action::ToggleAuto,
+        Action::AutoHarvestSooner,
+        Action::AutoHarvestLater,
+        Action::ToggleCare,
+        Action::Water,
+        Action::Feed,
+        Action::LightUp,
+        Action::LightDown,
+        Action::ToggleCo2,
+        Action::ToggleBell,
+        Action::TogglePauseWhenReady,
+        Action::ToggleCompression,
+        Action::CycleSaveFormat,
+        Action::ToggleUnits,
+        Action::FastForward,
+        Action::CycleFrameRate,
+        Action::CycleAutosave,
+        Action::CycleOfflineGrowth,
+        Action::CycleDifficulty,
+        Action::CycleMedium,
+        Action::ReloadStrains,
+        Action::CycleMode,
+        Action::CycleModePrev,
+        Action::ModeZen,
+        Action::ModeRainbow,
+        Action::ModeMatrix,
+        Action::ModeNormal,
+        Action::CycleRenderStyle,
+        Action::CycleTheme,
+        Action::ToggleReducedMotion,
+        Action::GrowingRoom,
+        Action::SwitchStats,
+        Action::CareLog,
+        Action::Breeding,
+        Action::StrainLibrary,
+        Action::StrainCompare,
+        Action::Goals,
+        Action::CursorUp,
+        Action::CursorDown,
+        Action::PageUp,
+        Action::PageDown,
+        Action::Confirm,
+        Action::PickParent,
+        Action::Search,
+        Action::CycleSort,
+        Action::ClearGoal,
+        Action::SwitchSide,
+        Action::PlantFeatured,
+        Action::ExportCode,
+        Action::ExportArt,
+        Action::ExportDiary,
+        Action::ImportCode,
+        Action::NewGame,
+        Action::Help,
+    ];
+
+    /// Name used in the config file
+    pub fn name(&self) -> &'static str {
+        match self {
+            Action::Quit => "quit",
+            Action::SaveNow => "save",
+            Action::Harvest => "harvest",
+            Action::PartialHarvest => "partial_harvest",
+            Action::UndoHarvest => "undo_harvest",
+            Action::FinishDrying => "finish_drying",
+            Action::BurpJar => "burp_jar",
+            Action::FinishCure => "finish_cure",
+            Action::ToggleAuto => "toggle_auto",
+            Action::AutoHarvestSooner => "auto_harvest_sooner",
+            Action::AutoHarvestLater => "auto_harvest_later",
+            Action::ToggleCare => "toggle_care",
+            Action::Water => "water",
+            Action::Feed => "feed",
+            Action::LightUp => "light_up",
+            Action::LightDown => "light_down",
+            Action::ToggleCo2 => "toggle_co2",
+            Action::ToggleBell => "toggle_bell",
+            Action::TogglePauseWhenReady => "toggle_pause_when_ready",
+            Action::ToggleCompression => "toggle_compression",
+            Action::CycleSaveFormat => "cycle_save_format",
+            Action::ToggleUnits => "toggle_units",
+            Action::FastForward => "fast_forward",
+            Action::CycleFrameRate => "cycle_frame_rate",
+            Action::CycleAutosave => "cycle_autosave",
+            Action::CycleOfflineGrowth => "cycle_offline_growth",
+            Action::CycleDifficulty => "cycle_difficulty",
+            Action::CycleMedium => "cycle_medium",
+            Action::ReloadStrains => "reload_strains",
+            Action::CycleMode => "cycle_mode",
+            Action::CycleModePrev => "cycle_mode_prev",
+            Action::ModeZen => "mode_zen",
+            Action::ModeRainbow => "mode_rainbow",
+            Action::ModeMatrix => "mode_matrix",
+            Action::ModeNormal => "mode_normal",
+            Action::CycleRenderStyle => "cycle_render_style",
+            Action::CycleTheme => "cycle_theme",
+            Action::ToggleReducedMotion => "toggle_reduced_motion",
+            Action::GrowingRoom => "growing_room",
+            Action::SwitchStats => "switch_stats",
+            Action::CareLog => "care_log",
+            Action::Breeding => "breeding",
+            Action::StrainLibrary => "strain_library",
+            Action::StrainCompare => "strain_compare",
+            Action::Goals => "goals",
+            Action::CursorUp => "cursor_up",
+            Action::CursorDown => "cursor_down",
+            Action::PageUp => "page_up",
+            Action::PageDown => "page_down",
+            Action::Confirm => "confirm",
+            Action::PickParent => "pick_parent",
+            Action::Search => "search",
+            Action::CycleSort => "cycle_sort",
+            Action::ClearGoal => "clear_goal",
+            Action::SwitchSide => "switch_side",
+            Action::PlantFeatured => "plant_featured",
+            Action::ExportCode => "export_code",
+            Action::ExportArt => "export_art",
+            Action::ExportDiary => "export_diary",
+            Action::ImportCode => "import_code",
+            Action::NewGame => "new_game",
+            Action::Help => "help",
+        }
+    }
+
+    /// Whether the action only means something on the list screens (breeding,
+    /// library and the like); there its keys are looked up before the global ones
+    pub fn is_screen_local(&self) -> bool {
+        matches!(
+            self,
+            Action::CursorUp
+                | Action::CursorDown
+                | Action::PageUp
+                | Action::PageDown
+                | Action::Confirm
+                | Action::PickParent
+                | Action::Search
+                | Action::CycleSort
+                | Action::ClearGoal
+                | Action::SwitchSide
+        )
+    }
+
+    /// Look up an action by its config file name
+    pub fn from_name(name: &str) -> Option<Action> {
+        Action::ALL.iter().copied().find(|a| a.name() == name)
+    }
+
+    /// Default key names for this action (today's hardcoded bindings)
+    fn default_keys(&self) -> &'static [&'static str] {
+        match self {
+            Action::Quit => &["q"],
+            Action::SaveNow => &["Ctrl+s"],
+            Action::Harvest => &["h"],
+            Action::PartialHarvest => &["H"],
+            Action::UndoHarvest => &["U"],
+            Action::FinishDrying => &["d"],
+            Action::BurpJar => &["j"],
+            Action::FinishCure => &["J"],
+            Action::ToggleAuto => &["a"],
+            Action::AutoHarvestSooner => &["["],
+            Action::AutoHarvestLater => &["]"],
+            Action::ToggleCare => &["m"],
+            Action::Water => &["w"],
+            Action::Feed => &["f"],
+            Action::LightUp => &["+", "="],
+            Action::LightDown => &["-"],
+            Action::ToggleCo2 => &["e"],
+            Action::ToggleBell => &["b"],
+            Action::TogglePauseWhenReady => &["p"],
+            Action::ToggleCompression => &["z"],
+            Action::CycleSaveFormat => &["R"],
+            Action::ToggleUnits => &["u"],
+            Action::FastForward => &[">"],
+            Action::CycleFrameRate => &["r"],
+            Action::CycleAutosave => &["S"],
+            Action::CycleOfflineGrowth => &["O"],
+            Action::CycleDifficulty => &["D"],
+            Action::CycleMedium => &["k"],
+            Action::ReloadStrains => &["F5"],
+            Action::CycleMode => &["v"],
+            Action::CycleModePrev => &["V"],
+            Action::ModeZen => &["F1"],
+            Action::ModeRainbow => &["F2"],
+            Action::ModeMatrix => &["F3"],
+            Action::ModeNormal => &["F4"],
+            Action::CycleRenderStyle => &["G"],
+            Action::CycleTheme => &["T"],
+            Action::ToggleReducedMotion => &["M"],
+            Action::GrowingRoom => &["1"],
+            Action::SwitchStats => &["s", "2"],
+            Action::CareLog => &["c"],
+            Action::Breeding => &["x"],
+            Action::StrainLibrary => &["3"],
+            Action::StrainCompare => &["5"],
+            Action::Goals => &["g", "4"],
+            Action::CursorUp => &["Up"],
+            Action::CursorDown => &["Down"],
+            Action::PageUp => &["PageUp"],
+            Action::PageDown => &["PageDown"],
+            Action::Confirm => &["Enter"],
+            Action::PickParent => &["Space"],
+            Action::Search => &["/"],
+            Action::CycleSort => &["o"],
+            Action::ClearGoal => &["Delete", "Backspace"],
+            Action::SwitchSide => &["Tab", "Left", "Right"],
+            Action::PlantFeatured => &["t"],
+            Action::ExportCode => &["C"],
+            Action::ExportArt => &["P"],
+            Action::ExportDiary => &["L"],
+            Action::ImportCode => &["I"],
+            Action::NewGame => &["N"],
+            Action::Help => &["?"],
+        }
+    }
+}
+
+/// A single key binding (key code plus whether Ctrl must be held)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyBinding {
+    pub code: KeyCode,
+    pub ctrl: bool,
+}
+
+impl KeyBinding {
+    /// Parse a key name like "q", "F1", "Esc", "Space" or "Ctrl+s"
+    pub fn parse(name: &str) -> Option<Self> {
+        let (ctrl, key) = match name.strip_prefix("Ctrl+").or_else(|| name.strip_prefix("ctrl+")) {
+            Some(rest) => (true, rest),
+            None => (false, name),
+        };
+
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) => KeyCode::Char(c),
+            _ => match key.to_ascii_lowercase().as_str() {
+                "esc" => KeyCode::Esc,
+                "enter" => KeyCode::Enter,
+                "tab" => KeyCode::Tab,
+                "space" => KeyCode::Char(' '),
+                "backspace" => KeyCode::Backspace,
+                "delete" => KeyCode::Delete,
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "pageup" => KeyCode::PageUp,
+                "pagedown" => KeyCode::PageDown,
+                other => {
+                    let n = other.strip_prefix('f')?.parse::<u8>().ok()?;
+                    if !(1..=12).contains(&n) {
+                        return None;
+                    }
+                    KeyCode::F(n)
+                }
+            },
+        };
+
+        Some(Self { code, ctrl })
+    }
+
+    /// Check if a key event matches this binding
+    pub fn matches(&self, key: &KeyEvent) -> bool {
+        self.code == key.code && self.ctrl == key.modifiers.contains(KeyModifiers::CONTROL)
+    }
+
+    /// Human-readable label for help screens
+    pub fn label(&self) -> String {
+        let key = match self.code {
+            KeyCode::Char(' ') => "Space".to_string(),
+            KeyCode::Char(c) => c.to_string(),
+            KeyCode::F(n) => format!("F{}", n),
+            KeyCode::Esc => "Esc".to_string(),
+            KeyCode::Enter => "Enter".to_string(),
+            KeyCode::Tab => "Tab".to_string(),
+            KeyCode::Backspace => "Backspace".to_string(),
+            KeyCode::Delete => "Delete".to_string(),
+            KeyCode::Up => "Up".to_string(),
+            KeyCode::Down => "Down".to_string(),
+            KeyCode::Left => "Left".to_string(),
+            KeyCode::Right => "Right".to_string(),
+            KeyCode::PageUp => "PageUp".to_string(),
+            KeyCode::PageDown => "PageDown".to_string(),
+            other => format!("{:?}", other),
+        };
+
+        if self.ctrl {
+            format!("Ctrl+{}", key)
+        } else {
+            key
+        }
+    }
+}
+
+/// Key bindings for every action
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyConfig {
+    bindings: BTreeMap<Action, Vec<KeyBinding>>,
+}
+
+impl Default for KeyConfig {
+    fn default() -> Self {
+        let bindings = Action::ALL
+            .iter()
+            .map(|action| {
+                let keys = action
+                    .default_keys()
+                    .iter()
+                    .filter_map(|k| KeyBinding::parse(k))
+                    .collect();
+                (*action, keys)
+            })
+            .collect();
+
+        Self { bindings }
+    }
+}
+
+impl KeyConfig {
+    /// Load key bindings from `keys.json` in the data directory
+    ///
+    /// The file maps action names to lists of key names, e.g.
+    /// `{ "quit": ["x"], "switch_stats": ["s", "2"] }`. Missing actions keep
+    /// their default keys. Returns the config plus any warnings found.
+    pub fn load() -> (Self, Vec<String>) {
+        let path = match crate::storage::get_data_dir() {
+            Ok(dir) => dir.join(KEYS_FILE),
+            Err(_) => return (Self::default(), Vec::new()),
+        };
+
+        match fs::read_to_string(&path) {
+            Ok(content) => Self::from_json(&content),
+            Err(_) => (Self::default(), Vec::new()),
+        }
+    }
+
+    /// Parse key bindings from JSON, falling back to defaults per action
+    pub fn from_json(content: &str) -> (Self, Vec<String>) {
+        let mut config = Self::default();
+        let mut warnings = Vec::new();
+
+        let overrides: BTreeMap<String, Vec<String>> = match serde_json::from_str(content) {
+            Ok(map) => map,
+            Err(e) => {
+                warnings.push(format!("{}: {} (using default keys)", KEYS_FILE, e));
+                return (config, warnings);
+            }
+        };
+
+        for (name, keys) in overrides {
+            let Some(action) = Action::from_name(&name) else {
+                warnings.push(format!("{}: unknown action '{}'", KEYS_FILE, name));
+                continue;
+            };
+
+            let mut parsed = Vec::new();
+            for key in keys {
+                match KeyBinding::parse(&key) {
+                    Some(binding) => parsed.push(binding),
+                    None => warnings.push(format!("{}: unknown key '{}' for '{}'", KEYS_FILE, key, name)),
+                }
+            }
+
+            if !parsed.is_empty() {
+                config.bindings.insert(action, parsed);
+            }
+        }
+
+        warnings.extend(config.conflicts());
+        (config, warnings)
+    }
+
+    /// Find keys bound to more than one action, and list-screen keys that
+    /// hide a global key on those screens
+    /// The first action in display order wins at lookup time
+    pub fn conflicts(&self) -> Vec<String> {
+        let mut seen: Vec<(KeyBinding, Action)> = Vec::new();
+        let mut warnings = Vec::new();
+
+        for action in Action::ALL {
+            for binding in self.keys(action) {
+                let owners: Vec<Action> = seen.iter().filter(|(b, _)| b == binding).map(|(_, owner)| *owner).collect();
+                for owner in &owners {
+                    warnings.push(if owner.is_screen_local() == action.is_screen_local() {
+                        format!(
+                            "{}: key '{}' is bound to both '{}' and '{}'",
+                            KEYS_FILE,
+                            binding.label(),
+                            owner.name(),
+                            action.name()
+                        )
+                    } else {
+                        let (local, global) = if action.is_screen_local() { (action, *owner) } else { (*owner, action) };
+                        format!(
+                            "{}: key '{}' for '{}' hides '{}' on the list screens",
+                            KEYS_FILE,
+                            binding.label(),
+                            local.name(),
+                            global.name()
+                        )
+                    });
+                }
+                if owners.iter().all(|owner| owner.is_screen_local() != action.is_screen_local()) {
+                    seen.push((*binding, action));
+                }
+            }
+        }
+
+        warnings
+    }
+
+    /// Keys bound to an action
+    pub fn keys(&self, action: Action) -> &[KeyBinding] {
+        self.bindings.get(&action).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Find the global action bound to a key event
+    pub fn action_for(&self, key: &KeyEvent) -> Option<Action> {
+        self.find(key, false)
+    }
+
+    /// Find the list-screen action bound to a key event
+    pub fn screen_action_for(&self, key: &KeyEvent) -> Option<Action> {
+        self.find(key, true)
+    }
+
+    fn find(&self, key: &KeyEvent, screen_local: bool) -> Option<Action> {
+        Action::ALL
+            .iter()
+            .copied()
+            .filter(|action| action.is_screen_local() == screen_local)
+            .find(|action| self.keys(*action).iter().any(|b| b.matches(key)))
+    }
+
+    /// Label listing all keys for an action, e.g. "s / 2"
+    pub fn label(&self, action: Action) -> String {
+        self.keys(action)
+            .iter()
+            .map(KeyBinding::label)
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn defaults_have_no_conflicts() {
+        assert!(KeyConfig::default().conflicts().is_empty());
+    }
+
+    #[test]
+    fn the_medium_key_is_not_hidden_on_the_list_screens() {
+        let keys = KeyConfig::default();
+        for binding in keys.keys(Action::CycleMedium) {
+            let key = press(binding.code, KeyModifiers::NONE);
+            assert_eq!(keys.screen_action_for(&key), None, "'{}' moves a list cursor", binding.label());
+            assert_eq!(keys.action_for(&key), Some(Action::CycleMedium));
+        }
+    }
+
+    #[test]
+    fn config_file_rebinds_named_keys() {
+        let (keys, warnings) = KeyConfig::from_json(r#"{ "quit": ["Ctrl+s"], "save": ["F5"], "reload_strains": ["F6"] }"#);
+        assert!(warnings.is_empty(), "{:?}", warnings);
+
+        assert_eq!(keys.action_for(&press(KeyCode::Char('s'), KeyModifiers::CONTROL)), Some(Action::Quit));
+        assert_eq!(keys.action_for(&press(KeyCode::F(5), KeyModifiers::NONE)), Some(Action::SaveNow));
+        assert_eq!(keys.action_for(&press(KeyCode::F(6), KeyModifiers::NONE)), Some(Action::ReloadStrains));
+        assert_eq!(keys.label(Action::Quit), "Ctrl+s");
+
+        // Actions the file leaves out keep their defaults
+        assert_eq!(keys.action_for(&press(KeyCode::Char('h'), KeyModifiers::NONE)), Some(Action::Harvest));
+        assert_eq!(keys.action_for(&press(KeyCode::Char('q'), KeyModifiers::NONE)), None);
+    }
+
+    #[test]
+    fn unknown_keys_and_actions_are_reported() {
+        let (keys, warnings) = KeyConfig::from_json(r#"{ "quit": ["Hyper+q", "F13"], "plant_tree": ["y"] }"#);
+
+        assert!(warnings.iter().any(|w| w.contains("unknown key 'Hyper+q' for 'quit'")), "{:?}", warnings);
+        assert!(warnings.iter().any(|w| w.contains("unknown key 'F13'")), "{:?}", warnings);
+        assert!(warnings.iter().any(|w| w.contains("unknown action 'plant_tree'")), "{:?}", warnings);
+        assert_eq!(keys.label(Action::Quit), "q", "nothing valid was given, so the default stays");
+    }
+
+    #[test]
+    fn keys_bound_twice_are_flagged() {
+        let (keys, warnings) = KeyConfig::from_json(r#"{ "water": ["h"] }"#);
+
+        let conflicts = keys.conflicts();
+        assert_eq!(conflicts, vec!["keys.json: key 'h' is bound to both 'harvest' and 'water'".to_string()]);
+        assert_eq!(warnings, conflicts, "loading reports the conflict too");
+        // The action listed first keeps the key
+        assert_eq!(keys.action_for(&press(KeyCode::Char('h'), KeyModifiers::NONE)), Some(Action::Harvest));
+    }
+
+    #[test]
+    fn list_screen_keys_are_looked_up_on_their_own() {
+        let (keys, warnings) = KeyConfig::from_json(r#"{ "cursor_down": ["j", "Down"] }"#);
+        assert_eq!(warnings, vec!["keys.json: key 'j' for 'cursor_down' hides 'burp_jar' on the list screens".to_string()]);
+
+        let j = press(KeyCode::Char('j'), KeyModifiers::NONE);
+        assert_eq!(keys.screen_action_for(&j), Some(Action::CursorDown));
+        assert_eq!(keys.action_for(&j), Some(Action::BurpJar), "elsewhere the jar keeps its key");
+        assert_eq!(keys.screen_action_for(&press(KeyCode::Char('h'), KeyModifiers::NONE)), None);
+        assert_eq!(keys.screen_action_for(&press(KeyCode::Char(' '), KeyModifiers::NONE)), Some(Action::PickParent));
+    }
+}
@@ -0,0 +1,3 @@
+pub(crate) mod keys;
+
+pub use keys::{Action, KeyConfig};
@@ -0,0 +1,314 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::rng::random_uuid;
+
+use super::genetics::{Genetics, Mutation, StrainInfo, MUTATION_INHERIT_CHANCE};
+
+/// Maximum random drift applied to each blended trait, as a fraction of its value
+pub const BREED_VARIANCE: f32 = 0.05;
+
+/// Ranges a hybrid's blended traits are clamped into (a shared seed too)
+pub const YIELD_RANGE: RangeInclusive<f32> = 50.0..=150.0;
+pub const GROWTH_RATE_RANGE: RangeInclusive<f32> = 0.9..=1.1;
+pub const RESILIENCE_RANGE: RangeInclusive<f32> = 0.0..=1.0;
+pub const QUALITY_CEILING_RANGE: RangeInclusive<f32> = 70.0..=100.0;
+
+/// Most terpenes/aromas/effects kept on a hybrid
+const MAX_TRAITS: usize = 4;
+
+/// Parent strains of a bred seed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Lineage {
+    pub mother: String,
+    pub father: String,
+}
+
+impl fmt::Display for Lineage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} × {}", self.mother, self.father)
+    }
+}
+
+/// A seed waiting in the inventory to be planted
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Seed {
+    pub id: Uuid,
+    pub strain_name: String,
+    pub genetics: Genetics,
+    pub lineage: Option<Lineage>,
+}
+
+/// One side of a cross: strain name plus its genetics
+pub struct Parent<'a> {
+    pub name: &'a str,
+    pub genetics: &'a Genetics,
+}
+
+/// Cross two parents into a new seed
+/// Each trait is the parental average with up to ±BREED_VARIANCE random drift;
+/// `number` is appended to the hybrid name ("Northern Haze #3")
+pub fn breed<R: Rng>(mother: Parent, father: Parent, number: u32, rng: &mut R) -> Seed {
+    let a = mother.genetics;
+    let b = father.genetics;
+    let strain_name = hybrid_name(mother.name, father.name, number);
+
+    let strain_info = match (&a.strain_info, &b.strain_info) {
+        (Some(sa), Some(sb)) => Some(blend_strain_info(sa, sb, &strain_name, rng)),
+        (Some(s), None) | (None, Some(s)) => Some(StrainInfo {
+            name: strain_name.clone(),
+            ..s.clone()
+        }),
+        (None, None) => None,
+    };
+
+    let mut genetics = Genetics {
+        yield_potential: clamp(blend(a.yield_potential, b.yield_potential, rng), YIELD_RANGE),
+        growth_rate: clamp(blend(a.growth_rate, b.growth_rate, rng), GROWTH_RATE_RANGE),
+        resilience: clamp(blend(a.resilience, b.resilience, rng), RESILIENCE_RANGE),
+        quality_ceiling: clamp(blend(a.quality_ceiling, b.quality_ceiling, rng), QUALITY_CEILING_RANGE),
+        strain_info,
+        thc_percent: blend(a.thc_percent, b.thc_percent, rng).max(0.0),
+        cbd_percent: blend(a.cbd_percent, b.cbd_percent, rng).max(0.0),
+        rarity: a.rarity.max(b.rarity),
+        hardy: a.hardy || b.hardy,
+        mutation: inherit_mutation(a, b, rng),
+    };
+    if genetics.mutation.is_none() {
+        genetics.apply_mutation(Mutation::roll(rng));
+    }
+
+    Seed {
+        id: random_uuid(rng),
+        strain_name,
+        genetics,
+        lineage: Some(Lineage {
+            mother: mother.name.to_string(),
+            father: father.name.to_string(),
+        }),
+    }
+}
+
+/// Each parent's mutation has a chance to pass on (otherwise `breed` rolls a fresh one)
+fn inherit_mutation<R: Rng>(a: &Genetics, b: &Genetics, rng: &mut R) -> Option<Mutation> {
+    [a.mutation, b.mutation]
+        .into_iter()
+        .flatten()
+        .find(|_| rng.gen_bool(MUTATION_INHERIT_CHANCE))
+}
+
+/// Name a hybrid from the first word of the mother and the last word of the father
+/// "Northern Lights" × "Super Silver Haze" → "Northern Haze #3"
+pub fn hybrid_name(mother: &str, father: &str, number: u32) -> String {
+    let first = base_name(mother).split_whitespace().next().unwrap_or("Hybrid");
+    let last = base_name(father).split_whitespace().last().unwrap_or("Cross");
+
+    if first == last {
+        format!("{} #{}", first, number)
+    } else {
+        format!("{} {} #{}", first, last, number)
+    }
+}
+
+/// Strip a previous "#N" suffix so hybrids of hybrids don't stack numbers
+fn base_name(name: &str) -> &str {
+    match name.rsplit_once(" #") {
+        Some((base, n)) if n.chars().all(|c| c.is_ascii_digit()) => base,
+        _ => name,
+    }
+}
+
+/// Average of two values with up to ±BREED_VARIANCE drift
+fn blend<R: Rng>(a: f32, b: f32, rng: &mut R) -> f32 {
+    let average = (a + b) / 2.0;
+    average * (1.0 + rng.gen_range(-BREED_VARIANCE..=BREED_VARIANCE))
+}
+
+/// Clamp a trait into one of the trait ranges
+fn clamp(value: f32, range: RangeInclusive<f32>) -> f32 {
+    value.clamp(*range.start(), *range.end())
+}
+
+/// Combine two strain records; ranges are averaged, categories picked from a parent
+fn blend_strain_info<R: Rng>(a: &StrainInfo, b: &StrainInfo, name: &str, rng: &mut R) -> StrainInfo {
+    let pick = |rng: &mut R, x: &String, y: &String| if rng.gen_bool(0.5) { x.clone() } else { y.clone() };
+
+    let thc_min = (a.thc_min + b.thc_min) / 2.0;
+    let cbd_min = (a.cbd_min + b.cbd_min) / 2.0;
+
+    StrainInfo {
+        name: name.to_string(),
+        strain_type: if a.strain_type == b.strain_type {
+            a.strain_type.clone()
+        } else {
+            "Hybrid".to_string()
+        },
+        genetics: format!("{} × {}", a.name, b.name),
+        thc_min,
+        thc_max: ((a.thc_max + b.thc_max) / 2.0).max(thc_min),
+        cbd_min,
+        cbd_max: ((a.cbd_max + b.cbd_max) / 2.0).max(cbd_min),
+        flowering_time: (a.flowering_time + b.flowering_time) / 2,
+        difficulty: pick(rng, &a.difficulty, &b.difficulty),
+        yield_potential: pick(rng, &a.yield_potential, &b.yield_potential),
+        dominant_terpenes: merge_traits(&a.dominant_terpenes, &b.dominant_terpenes),
+        aroma: merge_traits(&a.aroma, &b.aroma),
+        effects: merge_traits(&a.effects, &b.effects),
+        height: pick(rng, &a.height, &b.height),
+        phenotype: pick(rng, &a.phenotype, &b.phenotype),
+    }
+}
+
+/// Interleave two trait lists without duplicates, keeping the strongest of each
+fn merge_traits(a: &[String], b: &[String]) -> Vec<String> {
+    let mut merged: Vec<String> = Vec::new();
+    for i in 0..a.len().max(b.len()) {
+        for list in [a, b] {
+            if let Some(item) = list.get(i) {
+                if !merged.contains(item) {
+                    merged.push(item.clone());
+                }
+            }
+        }
+    }
+    merged.truncate(MAX_TRAITS);
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::genetics::Rarity;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn genetics(yield_potential: f32, growth_rate: f32, thc: f32, cbd: f32) -> Genetics {
+        Genetics {
+            yield_potential,
+            growth_rate,
+            resilience: 0.5,
+            quality_ceiling: 90.0,
+            strain_info: None,
+            thc_percent: thc,
+            cbd_percent: cbd,
+            rarity: Rarity::Common,
+            hardy: false,
+            mutation: None,
+        }
+    }
+
+    fn within(value: f32, a: f32, b: f32) -> bool {
+        let lo = a.min(b) * (1.0 - BREED_VARIANCE) - 1e-4;
+        let hi = a.max(b) * (1.0 + BREED_VARIANCE) + 1e-4;
+        (lo..=hi).contains(&value)
+    }
+
+    #[test]
+    fn blended_traits_stay_within_parent_bounds() {
+        let mother = genetics(60.0, 0.92, 14.0, 0.2);
+        let father = genetics(140.0, 1.08, 24.0, 1.0);
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for number in 1..=200 {
+            let seed = breed(
+                Parent { name: "Northern Lights", genetics: &mother },
+                Parent { name: "Super Silver Haze", genetics: &father },
+                number,
+                &mut rng,
+            );
+            let g = &seed.genetics;
+            assert!(within(g.yield_potential, 60.0, 140.0), "yield {}", g.yield_potential);
+            assert!(within(g.growth_rate, 0.92, 1.08), "growth {}", g.growth_rate);
+            if g.mutation != Some(Mutation::ExtraResinous) {
+                assert!(within(g.thc_percent, 14.0, 24.0), "thc {}", g.thc_percent);
+            }
+            assert!(within(g.cbd_percent, 0.2, 1.0), "cbd {}", g.cbd_percent);
+        }
+    }
+
+    #[test]
+    fn breeding_is_deterministic_for_a_seeded_rng() {
+        let mother = genetics(80.0, 1.0, 18.0, 0.5);
+        let father = genetics(120.0, 1.05, 22.0, 0.3);
+        let cross = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            breed(
+                Parent { name: "A", genetics: &mother },
+                Parent { name: "B", genetics: &father },
+                1,
+                &mut rng,
+            )
+            .genetics
+            .yield_potential
+        };
+        assert_eq!(cross(7), cross(7));
+    }
+
+    #[test]
+    fn freshly_bred_resinous_seeds_get_the_thc_boost() {
+        let parent = genetics(100.0, 1.0, 20.0, 0.5);
+        let mut rng = StdRng::seed_from_u64(7);
+        let (resinous, plain): (Vec<_>, Vec<_>) = (0..2_000)
+            .map(|n| {
+                breed(Parent { name: "A", genetics: &parent }, Parent { name: "B", genetics: &parent }, n, &mut rng).genetics
+            })
+            .partition(|g| g.mutation == Some(Mutation::ExtraResinous));
+
+        assert!(!resinous.is_empty(), "some crosses mutate");
+        let highest_plain = plain.iter().map(|g| g.thc_percent).fold(0.0, f32::max);
+        for g in &resinous {
+            assert!(g.thc_percent > highest_plain, "thc {} vs {}", g.thc_percent, highest_plain);
+        }
+    }
+
+    #[test]
+    fn mutations_can_be_inherited() {
+        let mut mother = genetics(100.0, 1.0, 20.0, 0.5);
+        mother.mutation = Some(Mutation::Albino);
+        let father = genetics(100.0, 1.0, 20.0, 0.5);
+        let mut rng = StdRng::seed_from_u64(99);
+
+        let albino = (0..1_000)
+            .filter(|&n| {
+                let seed = breed(
+                    Parent { name: "A", genetics: &mother },
+                    Parent { name: "B", genetics: &father },
+                    n,
+                    &mut rng,
+                );
+                seed.genetics.mutation == Some(Mutation::Albino)
+            })
+            .count();
+
+        // ~50% inherited plus a sliver of fresh rolls
+        assert!((430..=580).contains(&albino), "inherited {} of 1000", albino);
+    }
+
+    #[test]
+    fn hybrid_names_and_lineage() {
+        assert_eq!(hybrid_name("Northern Lights", "Super Silver Haze", 3), "Northern Haze #3");
+        assert_eq!(hybrid_name("Northern Haze #3", "Blue Dream", 4), "Northern Dream #4");
+        assert_eq!(hybrid_name("Haze", "Haze", 1), "Haze #1");
+
+        let mut rng = StdRng::seed_from_u64(1);
+        let g = genetics(100.0, 1.0, 20.0, 0.5);
+        let seed = breed(
+            Parent { name: "Northern Lights", genetics: &g },
+            Parent { name: "Super Silver Haze", genetics: &g },
+            1,
+            &mut rng,
+        );
+        assert_eq!(
+            seed.lineage.as_ref().unwrap().to_string(),
+            "Northern Lights × Super Silver Haze"
+        );
+
+        let plant = crate::domain::Plant::from_seed(seed);
+        assert_eq!(plant.strain_name, "Northern Haze #1");
+        assert_eq!(plant.lineage.unwrap().mother, "Northern Lights");
+    }
+}
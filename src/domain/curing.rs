@@ -0,0 +1,156 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::harvest::HarvestResult;
+
+/// Length of a full cure in in-game days (rolled per jar)
+pub const CURE_MIN_DAYS: f32 = 14.0;
+pub const CURE_MAX_DAYS: f32 = 28.0;
+
+/// Dry weight that fills a jar to the brim
+pub const JAR_CAPACITY_GRAMS: f32 = 150.0;
+
+/// A jar should be burped at least this often (in-game days)
+pub const BURP_INTERVAL_DAYS: f32 = 2.0;
+
+/// Jar humidity right after burping, and the level where moisture spikes start
+pub const JAR_BURPED_HUMIDITY: f32 = 62.0;
+pub const JAR_SPIKE_HUMIDITY: f32 = 70.0;
+const JAR_MAX_HUMIDITY: f32 = 85.0;
+
+/// Humidity gained per day once a jar is overdue for a burp
+const JAR_HUMIDITY_RISE_PER_DAY: f32 = 3.0;
+
+/// The first days of a cure are the sensitive ones
+const EARLY_CURE_DAYS: f32 = 7.0;
+
+/// Quality lost per day of an early moisture spike
+const SPIKE_QUALITY_LOSS_PER_DAY: f32 = 2.0;
+
+/// A dried harvest sealed in a jar, slowly gaining quality
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CuringJar {
+    pub harvest: HarvestResult,
+    pub target_days: f32,
+    pub elapsed_days: f32,
+    pub days_since_burp: f32,
+    pub humidity: f32,
+    /// Best quality this jar can still reach (lowered by moisture spikes)
+    pub ceiling: f32,
+}
+
+impl CuringJar {
+    /// Seal a dry harvest; quality climbs toward the genetics ceiling
+    pub fn seal<R: Rng>(harvest: HarvestResult, rng: &mut R) -> Self {
+        let ceiling = harvest
+            .genetics
+            .as_ref()
+            .map_or(harvest.quality_score, |g| g.quality_ceiling)
+            .max(harvest.quality_score);
+        Self {
+            target_days: rng.gen_range(CURE_MIN_DAYS..=CURE_MAX_DAYS),
+            elapsed_days: 0.0,
+            days_since_burp: 0.0,
+            humidity: JAR_BURPED_HUMIDITY,
+            ceiling,
+            harvest,
+        }
+    }
+
+    /// Let game time pass inside the jar
+    pub fn advance(&mut self, hours: f32) {
+        let days = hours / 24.0;
+        self.elapsed_days += days;
+        self.days_since_burp += days;
+
+        if self.days_since_burp > BURP_INTERVAL_DAYS {
+            self.humidity = (self.humidity + JAR_HUMIDITY_RISE_PER_DAY * days).min(JAR_MAX_HUMIDITY);
+        }
+
+        let quality = &mut self.harvest.quality_score;
+        if self.humidity > JAR_SPIKE_HUMIDITY {
+            // Trapped moisture: early on it costs quality, and the lost ground can't be regained
+            if self.elapsed_days < EARLY_CURE_DAYS {
+                *quality = (*quality - SPIKE_QUALITY_LOSS_PER_DAY * days).max(0.0);
+            }
+            self.ceiling = self.ceiling.min(*quality);
+        } else {
+            // Close ~95% of the gap to the ceiling over the cure
+            let step = (days * 3.0 / self.target_days).min(1.0);
+            *quality += (self.ceiling - *quality) * step;
+        }
+    }
+
+    /// Open the lid to let moisture out
+    pub fn burp(&mut self) {
+        self.days_since_burp = 0.0;
+        self.humidity = JAR_BURPED_HUMIDITY;
+    }
+
+    pub fn is_overdue(&self) -> bool {
+        self.days_since_burp > BURP_INTERVAL_DAYS
+    }
+
+    pub fn is_cured(&self) -> bool {
+        self.elapsed_days >= self.target_days
+    }
+
+    /// How full the jar is (0.0-1.0)
+    pub fn fill(&self) -> f32 {
+        (self.harvest.weight_grams / JAR_CAPACITY_GRAMS).min(1.0)
+    }
+
+    /// Cure progress (0.0-1.0)
+    pub fn progress(&self) -> f32 {
+        (self.elapsed_days / self.target_days).min(1.0)
+    }
+
+    /// Open the jar for good and record the cure
+    pub fn finish(self) -> HarvestResult {
+        let mut harvest = self.harvest;
+        harvest.cure_days = Some(self.elapsed_days);
+        harvest.regrade();
+        harvest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+    use rand::rngs::StdRng;
+
+    fn jar(rng: &mut StdRng) -> CuringJar {
+        let mut plant = test_support::plant();
+        plant.genetics.quality_ceiling = 95.0;
+        let mut harvest = HarvestResult::from_plant(&plant);
+        harvest.quality_score = 70.0;
+        CuringJar::seal(harvest, rng)
+    }
+
+    /// Cure a jar to completion, burping every `burp_every` days (None = never)
+    fn cure(burp_every: Option<f32>) -> f32 {
+        let mut rng = test_support::rng();
+        let mut jar = jar(&mut rng);
+
+        while !jar.is_cured() {
+            if burp_every.is_some_and(|days| jar.days_since_burp >= days) {
+                jar.burp();
+            }
+            jar.advance(6.0);
+        }
+        jar.finish().quality_score
+    }
+
+    #[test]
+    fn burped_jars_climb_toward_the_ceiling() {
+        let quality = cure(Some(1.0));
+        assert!(quality > 90.0 && quality <= 95.0, "cured to {}", quality);
+    }
+
+    #[test]
+    fn neglected_jars_stall_early() {
+        let neglected = cure(None);
+        assert!(neglected < cure(Some(1.0)) - 5.0, "neglected jar reached {}", neglected);
+    }
+}
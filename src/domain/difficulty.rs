@@ -0,0 +1,159 @@
+use serde::{Deserialize, Serialize};
+
+use super::plant::{
+    NUTRIENT_CRITICAL, NUTRIENT_OPTIMAL_MAX, NUTRIENT_OPTIMAL_MIN, WATER_CRITICAL, WATER_OPTIMAL_MAX,
+    WATER_OPTIMAL_MIN,
+};
+
+/// How precise the care has to be
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub fn next(self) -> Self {
+        match self {
+            Difficulty::Easy => Difficulty::Normal,
+            Difficulty::Normal => Difficulty::Hard,
+            Difficulty::Hard => Difficulty::Easy,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+        }
+    }
+
+    pub fn bands(self) -> &'static CareBands {
+        match self {
+            Difficulty::Easy => &EASY,
+            Difficulty::Normal => &NORMAL,
+            Difficulty::Hard => &HARD,
+        }
+    }
+}
+
+/// Water and nutrient levels (%) the health and stress checks work from
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CareBands {
+    pub water_optimal: (f32, f32),
+    pub nutrient_optimal: (f32, f32),
+    /// Narrower bands inside the optimal ones that make a plant Excellent
+    pub water_excellent: (f32, f32),
+    pub nutrient_excellent: (f32, f32),
+    /// Outside these the plant is in Critical health
+    pub water_survivable: (f32, f32),
+    pub nutrient_survivable: (f32, f32),
+    /// Below these a stress event is recorded (and the gauges blink)
+    pub water_critical: f32,
+    pub nutrient_critical: f32,
+    /// Above this the roots burn
+    pub nutrient_burn: f32,
+    /// How much auto-care adds once a level drops below its band
+    pub auto_water_dose: f32,
+    pub auto_feed_dose: f32,
+}
+
+impl CareBands {
+    pub fn water_is_optimal(&self, water: f32) -> bool {
+        (self.water_optimal.0..=self.water_optimal.1).contains(&water)
+    }
+
+    pub fn nutrients_are_optimal(&self, nutrients: f32) -> bool {
+        (self.nutrient_optimal.0..=self.nutrient_optimal.1).contains(&nutrients)
+    }
+}
+
+/// The original bands
+const NORMAL: CareBands = CareBands {
+    water_optimal: (WATER_OPTIMAL_MIN, WATER_OPTIMAL_MAX),
+    nutrient_optimal: (NUTRIENT_OPTIMAL_MIN, NUTRIENT_OPTIMAL_MAX),
+    water_excellent: (50.0, 70.0),
+    nutrient_excellent: (60.0, 75.0),
+    water_survivable: (10.0, 95.0),
+    nutrient_survivable: (20.0, 95.0),
+    water_critical: WATER_CRITICAL,
+    nutrient_critical: NUTRIENT_CRITICAL,
+    nutrient_burn: 90.0,
+    auto_water_dose: 50.0,
+    auto_feed_dose: 40.0,
+};
+
+const EASY: CareBands = CareBands {
+    water_optimal: (30.0, 88.0),
+    nutrient_optimal: (40.0, 88.0),
+    water_excellent: (40.0, 78.0),
+    nutrient_excellent: (50.0, 80.0),
+    water_survivable: (5.0, 98.0),
+    nutrient_survivable: (10.0, 98.0),
+    water_critical: 12.0,
+    nutrient_critical: 20.0,
+    nutrient_burn: 95.0,
+    auto_water_dose: 50.0,
+    auto_feed_dose: 40.0,
+};
+
+/// Narrow bands: auto-care doses are small enough not to overshoot them
+const HARD: CareBands = CareBands {
+    water_optimal: (50.0, 70.0),
+    nutrient_optimal: (58.0, 74.0),
+    water_excellent: (55.0, 65.0),
+    nutrient_excellent: (63.0, 70.0),
+    water_survivable: (15.0, 92.0),
+    nutrient_survivable: (28.0, 92.0),
+    water_critical: 30.0,
+    nutrient_critical: 40.0,
+    nutrient_burn: 85.0,
+    auto_water_dose: 15.0,
+    auto_feed_dose: 12.0,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    #[test]
+    fn bands_nest_inside_each_other() {
+        for difficulty in ALL {
+            let b = difficulty.bands();
+            for (excellent, optimal, survivable, critical) in [
+                (b.water_excellent, b.water_optimal, b.water_survivable, b.water_critical),
+                (b.nutrient_excellent, b.nutrient_optimal, b.nutrient_survivable, b.nutrient_critical),
+            ] {
+                assert!(optimal.0 <= excellent.0 && excellent.1 <= optimal.1, "{:?}", difficulty);
+                assert!(survivable.0 < critical && critical < optimal.0, "{:?}", difficulty);
+                assert!(optimal.1 < survivable.1, "{:?}", difficulty);
+            }
+        }
+    }
+
+    #[test]
+    fn harder_means_narrower() {
+        let width = |band: (f32, f32)| band.1 - band.0;
+        let [easy, normal, hard] = ALL.map(|d| *d.bands());
+        assert!(width(easy.water_optimal) > width(normal.water_optimal));
+        assert!(width(normal.water_optimal) > width(hard.water_optimal));
+        assert!(width(easy.nutrient_optimal) > width(normal.nutrient_optimal));
+        assert!(width(normal.nutrient_optimal) > width(hard.nutrient_optimal));
+    }
+
+    #[test]
+    fn auto_care_doses_land_inside_the_band() {
+        for difficulty in [Difficulty::Easy, Difficulty::Hard] {
+            let b = difficulty.bands();
+            let water = b.water_optimal.0 - 0.1 + b.auto_water_dose;
+            let nutrients = b.nutrient_optimal.0 - 0.1 + b.auto_feed_dose;
+            assert!(b.water_is_optimal(water), "{:?}", difficulty);
+            assert!(b.nutrients_are_optimal(nutrients), "{:?}", difficulty);
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::harvest::HarvestResult;
+
+/// Share of the wet weight lost while drying (rolled per batch)
+pub const DRY_LOSS_MIN: f32 = 0.75;
+pub const DRY_LOSS_MAX: f32 = 0.80;
+
+/// Healthy drying window in in-game days
+pub const MIN_DRY_DAYS: f32 = 7.0;
+pub const MAX_DRY_DAYS: f32 = 10.0;
+
+/// Quality lost per day a batch dried faster than MIN_DRY_DAYS
+const FAST_DRY_PENALTY_PER_DAY: f32 = 0.04;
+
+/// Mold chance per day a batch hung past MAX_DRY_DAYS
+const MOLD_CHANCE_PER_DAY: f64 = 0.1;
+
+/// Quality kept by a moldy batch
+const MOLD_QUALITY_MULTIPLIER: f32 = 0.5;
+
+/// Room temperature at which a batch dries on schedule; each degree above
+/// speeds drying by DRY_RATE_PER_DEGREE (and each degree below slows it)
+const DRY_NEUTRAL_TEMP: f32 = 24.0;
+const DRY_RATE_PER_DEGREE: f32 = 0.1;
+
+/// Buds hanging to dry after harvest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DryingBatch {
+    pub strain_name: String,
+    pub wet_grams: f32,
+    pub started_at: DateTime<Utc>,
+    /// Days the batch needs at the neutral temperature
+    pub target_days: f32,
+    /// In-game days hanging so far
+    pub elapsed_days: f32,
+    /// Drying done, in neutral-temperature days (reaches target_days when dry)
+    pub progress_days: f32,
+    /// Fraction of wet weight that will evaporate
+    pub loss: f32,
+    /// Harvest as measured at the chop; weight and quality are settled on finish
+    pub harvest: HarvestResult,
+}
+
+impl DryingBatch {
+    /// Hang a fresh harvest; its `weight_grams` is what will be left once dry.
+    /// The batch starts hanging when the harvest was cut, so the same harvest
+    /// and rng state always hang the same batch
+    pub fn start<R: Rng>(harvest: HarvestResult, rng: &mut R) -> Self {
+        let loss = rng.gen_range(DRY_LOSS_MIN..=DRY_LOSS_MAX);
+        Self {
+            strain_name: harvest.strain_name.clone(),
+            wet_grams: harvest.weight_grams / (1.0 - loss),
+            started_at: harvest.completed_at,
+            target_days: rng.gen_range(MIN_DRY_DAYS..=MAX_DRY_DAYS),
+            elapsed_days: 0.0,
+            progress_days: 0.0,
+            loss,
+            harvest,
+        }
+    }
+
+    /// Let game time pass; warm rooms dry faster
+    pub fn advance(&mut self, hours: f32, temperature: f32) {
+        let rate = (1.0 + (temperature - DRY_NEUTRAL_TEMP) * DRY_RATE_PER_DEGREE).clamp(0.5, 2.0);
+        self.elapsed_days += hours / 24.0;
+        self.progress_days = (self.progress_days + hours / 24.0 * rate).min(self.target_days);
+    }
+
+    pub fn is_dry(&self) -> bool {
+        self.progress_days >= self.target_days
+    }
+
+    /// Current hanging weight, falling from wet toward dry
+    pub fn current_grams(&self) -> f32 {
+        let progress = (self.progress_days / self.target_days).min(1.0);
+        self.wet_grams * (1.0 - self.loss * progress)
+    }
+
+    /// Progress label, e.g. "day 4/9"
+    pub fn day_label(&self) -> String {
+        format!("day {}/{}", self.elapsed_days as u32 + 1, self.target_days.round() as u32)
+    }
+
+    /// Take the batch down and settle its final dry weight and quality
+    pub fn finish<R: Rng>(self, rng: &mut R) -> HarvestResult {
+        let mut harvest = self.harvest;
+        let days = self.elapsed_days;
+
+        if days < MIN_DRY_DAYS {
+            let penalty = ((MIN_DRY_DAYS - days) * FAST_DRY_PENALTY_PER_DAY).min(0.3);
+            harvest.quality_score *= 1.0 - penalty;
+        }
+        if days > MAX_DRY_DAYS {
+            let chance = ((days - MAX_DRY_DAYS) as f64 * MOLD_CHANCE_PER_DAY).min(0.9);
+            if rng.gen_bool(chance) {
+                harvest.quality_score *= MOLD_QUALITY_MULTIPLIER;
+                harvest.moldy = true;
+            }
+        }
+
+        harvest.set_weight(self.wet_grams * (1.0 - self.loss));
+        harvest.wet_weight_grams = Some(self.wet_grams);
+        harvest.dry_days = Some(days);
+        harvest.regrade();
+        harvest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn batch(rng: &mut StdRng) -> DryingBatch {
+        DryingBatch::start(test_support::harvest(), rng)
+    }
+
+    #[test]
+    fn drying_loses_most_of_the_wet_weight() {
+        let mut rng = test_support::rng();
+        let mut batch = batch(&mut rng);
+        let wet = batch.wet_grams;
+
+        while !batch.is_dry() {
+            batch.advance(6.0, DRY_NEUTRAL_TEMP);
+        }
+        assert!((MIN_DRY_DAYS..=MAX_DRY_DAYS + 0.25).contains(&batch.elapsed_days));
+
+        let quality = batch.harvest.quality_score;
+        let dry = batch.finish(&mut rng);
+        let lost = 1.0 - dry.weight_grams / wet;
+        assert!((DRY_LOSS_MIN - 1e-3..=DRY_LOSS_MAX + 1e-3).contains(&lost), "lost {}", lost);
+        assert_eq!(dry.quality_score, quality);
+        assert!(!dry.moldy);
+    }
+
+    #[test]
+    fn hot_rooms_dry_too_fast_and_dock_quality() {
+        let mut rng = test_support::rng();
+        let mut batch = batch(&mut rng);
+        let quality = batch.harvest.quality_score;
+
+        while !batch.is_dry() {
+            batch.advance(6.0, 32.0);
+        }
+        assert!(batch.elapsed_days < MIN_DRY_DAYS);
+        assert!(batch.finish(&mut rng).quality_score < quality);
+    }
+
+    #[test]
+    fn the_same_harvest_and_rng_hang_the_same_batch() {
+        let harvest = test_support::harvest();
+        let first = DryingBatch::start(harvest.clone(), &mut StdRng::seed_from_u64(1));
+        let again = DryingBatch::start(harvest, &mut StdRng::seed_from_u64(1));
+
+        assert_eq!(first.started_at, first.harvest.completed_at);
+        assert_eq!(serde_json::to_string(&first).unwrap(), serde_json::to_string(&again).unwrap());
+    }
+}
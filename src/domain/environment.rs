@@ -0,0 +1,95 @@
+//! Grow-room climate: temperature and humidity wander on layered value noise
+//! seeded by the plant, so a reload replays exactly the same curve
+
+/// Room temperature (C) the noise wanders around, how far, and the limits
+const ROOM_TEMP_BASE: f32 = 24.0;
+const ROOM_TEMP_SWING: f32 = 2.5;
+const ROOM_TEMP_RANGE: (f32, f32) = (20.0, 28.0);
+
+/// Humidity (%) drifts this far either side of what the watering sets
+const ROOM_HUMIDITY_SWING: f32 = 4.0;
+const ROOM_HUMIDITY_RANGE: (f32, f32) = (45.0, 80.0);
+
+/// Days between lattice points of the slowest noise layer
+const CLIMATE_WAVELENGTH_DAYS: f32 = 1.5;
+
+/// Keeps the humidity curve independent of the temperature one
+const HUMIDITY_SALT: u64 = 0x6875_6D69_6469_7479;
+
+/// Room temperature for the plant seeded `seed`, `days` after planting
+pub fn room_temperature(seed: u64, days: f32) -> f32 {
+    let drift = layered_noise(seed, days / CLIMATE_WAVELENGTH_DAYS);
+    (ROOM_TEMP_BASE + drift * ROOM_TEMP_SWING).clamp(ROOM_TEMP_RANGE.0, ROOM_TEMP_RANGE.1)
+}
+
+/// Room humidity: wetter soil raises it, the noise moves it around that
+pub fn room_humidity(seed: u64, days: f32, water_level: f32) -> f32 {
+    let drift = layered_noise(seed ^ HUMIDITY_SALT, days / CLIMATE_WAVELENGTH_DAYS);
+    (50.0 + water_level * 0.2 + drift * ROOM_HUMIDITY_SWING).clamp(ROOM_HUMIDITY_RANGE.0, ROOM_HUMIDITY_RANGE.1)
+}
+
+/// Two octaves of value noise, -1..1; the faster one adds the hourly jitter
+fn layered_noise(seed: u64, t: f32) -> f32 {
+    0.7 * value_noise(seed, t) + 0.3 * value_noise(seed.rotate_left(17), t * 3.1)
+}
+
+/// Smoothly interpolated random values at whole `t`, -1..1
+fn value_noise(seed: u64, t: f32) -> f32 {
+    let cell = t.floor();
+    let along = t - cell;
+    let ease = along * along * (3.0 - 2.0 * along);
+    let (a, b) = (lattice(seed, cell as i64), lattice(seed, cell as i64 + 1));
+    a + (b - a) * ease
+}
+
+/// Fixed random value in -1..1 at one lattice point
+fn lattice(seed: u64, point: i64) -> f32 {
+    let mut h = seed ^ (point as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xFF51_AFD7_ED55_8CCD);
+    h ^= h >> 33;
+    (h % 20_001) as f32 / 10_000.0 - 1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Plant;
+    use crate::test_support;
+
+    #[test]
+    fn the_climate_replays_for_the_same_plant() {
+        let curve = |plant: &Plant| {
+            (0..40)
+                .map(|d| (room_temperature(plant.climate_seed(), d as f32), room_humidity(plant.climate_seed(), d as f32, 60.0)))
+                .collect::<Vec<_>>()
+        };
+        let plant = test_support::plant();
+        let loaded: Plant = serde_json::from_str(&serde_json::to_string(&plant).unwrap()).unwrap();
+        assert_eq!(curve(&loaded), curve(&plant), "a saved plant keeps its weather");
+        assert_ne!(curve(&test_support::plant_from(1)), curve(&plant), "each plant gets its own weather");
+    }
+
+    #[test]
+    fn readings_wander_inside_realistic_ranges() {
+        let temps: Vec<f32> = (0..2000).map(|h| room_temperature(7, h as f32 / 24.0)).collect();
+        assert!(temps.iter().all(|t| (ROOM_TEMP_RANGE.0..=ROOM_TEMP_RANGE.1).contains(t)));
+        let spread = temps.iter().cloned().fold(f32::MIN, f32::max) - temps.iter().cloned().fold(f32::MAX, f32::min);
+        assert!(spread > 1.5, "temperature barely moved: {}", spread);
+
+        for water in [0.0, 50.0, 100.0] {
+            for h in 0..500 {
+                let humidity = room_humidity(7, h as f32 / 24.0, water);
+                assert!((ROOM_HUMIDITY_RANGE.0..=ROOM_HUMIDITY_RANGE.1).contains(&humidity));
+            }
+        }
+    }
+
+    #[test]
+    fn hour_to_hour_changes_stay_gentle() {
+        for h in 0..2000 {
+            let (now, next) = (room_temperature(3, h as f32 / 24.0), room_temperature(3, (h + 1) as f32 / 24.0));
+            assert!((next - now).abs() < 0.5, "jumped {} -> {} at hour {}", now, next, h);
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+/// Most events kept in the grow-room feed
+pub const EVENT_LOG_LIMIT: usize = 20;
+
+/// Something the grow room did on its own (light switch, stage change, auto-harvest)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoomEvent {
+    pub day: u32,
+    pub text: String,
+}
+
+/// Append an event, dropping the oldest once the feed is full
+pub fn push_event(events: &mut Vec<RoomEvent>, day: u32, text: impl Into<String>) {
+    events.push(RoomEvent { day, text: text.into() });
+    if events.len() > EVENT_LOG_LIMIT {
+        let excess = events.len() - EVENT_LOG_LIMIT;
+        events.drain(..excess);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn feed_keeps_the_latest_events() {
+        let mut events = Vec::new();
+        for day in 0..30 {
+            push_event(&mut events, day, format!("event {}", day));
+        }
+        assert_eq!(events.len(), EVENT_LOG_LIMIT);
+        assert_eq!(events[0].day, 10);
+        assert_eq!(events.last().unwrap().text, "event 29");
+    }
+}
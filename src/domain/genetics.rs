@@ -1,8 +1,12 @@
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
+use super::strains;
+
 /// Strain information from database
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Missing fields fall back to defaults so older strain files keep loading
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct StrainInfo {
     pub name: String,
     #[serde(rename = "type")]
@@ -22,6 +26,166 @@ pub struct StrainInfo {
     pub phenotype: String,
 }
 
+/// THC (%) at or above which a plant counts as high / moderate THC
+pub const HIGH_THC: f32 = 20.0;
+pub const MODERATE_THC: f32 = 12.0;
+
+/// CBD (%) below which a plant counts as low CBD
+pub const LOW_CBD: f32 = 1.0;
+
+/// THC:CBD ratios within this band (either way round) count as balanced
+pub const BALANCED_RATIO: f32 = 2.0;
+
+/// Plain-language read of a cannabinoid profile for the strain panel
+/// High THC reads as high whatever the CBD alongside it
+pub fn potency_label(thc: f32, cbd: f32) -> &'static str {
+    if thc >= HIGH_THC && cbd < LOW_CBD {
+        "High THC / Low CBD - potent, psychoactive"
+    } else if thc >= HIGH_THC {
+        "High THC / Notable CBD - potent, rounded"
+    } else if cbd >= LOW_CBD && thc / cbd <= BALANCED_RATIO && cbd / thc.max(0.01) <= BALANCED_RATIO {
+        "Balanced 1:1 - mellow, clear-headed"
+    } else if cbd > thc {
+        "CBD dominant - calming, barely psychoactive"
+    } else if thc >= MODERATE_THC {
+        "Moderate THC - steady, social buzz"
+    } else {
+        "Mild THC - gentle, beginner friendly"
+    }
+}
+
+/// Drop weights for seed rarity (out of 100)
+pub const RARITY_WEIGHTS: [(Rarity, u32); 4] = [
+    (Rarity::Common, 60),
+    (Rarity::Uncommon, 25),
+    (Rarity::Rare, 12),
+    (Rarity::Legendary, 3),
+];
+
+/// Resilience added by the "hardy" bonus trait
+pub const HARDY_RESILIENCE_BONUS: f32 = 0.2;
+
+/// How rare a seed is; rarer seeds roll better genetics
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub enum Rarity {
+    #[default]
+    Common,
+    Uncommon,
+    Rare,
+    Legendary,
+}
+
+impl Rarity {
+    pub const ALL: [Rarity; 4] = [Rarity::Common, Rarity::Uncommon, Rarity::Rare, Rarity::Legendary];
+
+    /// Roll a rarity using RARITY_WEIGHTS
+    pub fn roll<R: Rng>(rng: &mut R) -> Self {
+        let total: u32 = RARITY_WEIGHTS.iter().map(|(_, w)| w).sum();
+        let mut pick = rng.gen_range(0..total);
+        for (rarity, weight) in RARITY_WEIGHTS {
+            if pick < weight {
+                return rarity;
+            }
+            pick -= weight;
+        }
+        Rarity::Common
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Rarity::Common => "Common",
+            Rarity::Uncommon => "Uncommon",
+            Rarity::Rare => "Rare",
+            Rarity::Legendary => "Legendary",
+        }
+    }
+
+    /// Lowest fraction of each trait range this rarity can roll
+    fn range_floor(&self) -> f32 {
+        match self {
+            Rarity::Common => 0.0,
+            Rarity::Uncommon => 0.25,
+            Rarity::Rare => 0.5,
+            Rarity::Legendary => 0.75,
+        }
+    }
+
+    /// Chance of the "hardy" bonus trait
+    fn bonus_trait_chance(&self) -> f64 {
+        match self {
+            Rarity::Common => 0.0,
+            Rarity::Uncommon => 0.05,
+            Rarity::Rare => 0.15,
+            Rarity::Legendary => 0.35,
+        }
+    }
+}
+
+/// Chance that a new seed carries a mutation
+pub const MUTATION_CHANCE: f64 = 0.03;
+
+/// Chance a parent's mutation is passed on when breeding
+pub const MUTATION_INHERIT_CHANCE: f64 = 0.5;
+
+/// THC multiplier for Extra Resinous plants
+const RESINOUS_THC_MULTIPLIER: f32 = 1.15;
+
+/// Rare named mutations that change how a plant looks or performs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Mutation {
+    Albino,        // White foliage
+    PurplePheno,   // Purple buds and purple-tinged leaves
+    Dwarf,         // Half height, -20% yield
+    Giant,         // +30% height
+    ExtraResinous, // More THC, heavier trichome sparkle
+}
+
+impl Mutation {
+    pub const ALL: [Mutation; 5] = [
+        Mutation::Albino,
+        Mutation::PurplePheno,
+        Mutation::Dwarf,
+        Mutation::Giant,
+        Mutation::ExtraResinous,
+    ];
+
+    /// Roll for a fresh mutation (MUTATION_CHANCE)
+    pub fn roll<R: Rng>(rng: &mut R) -> Option<Self> {
+        if rng.gen_bool(MUTATION_CHANCE) {
+            Some(Self::ALL[rng.gen_range(0..Self::ALL.len())])
+        } else {
+            None
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Mutation::Albino => "Albino",
+            Mutation::PurplePheno => "Purple Pheno",
+            Mutation::Dwarf => "Dwarf",
+            Mutation::Giant => "Giant",
+            Mutation::ExtraResinous => "Extra Resinous",
+        }
+    }
+
+    /// Max plant height, in percent of normal
+    pub fn height_percent(&self) -> u16 {
+        match self {
+            Mutation::Dwarf => 50,
+            Mutation::Giant => 130,
+            _ => 100,
+        }
+    }
+
+    /// Harvest weight multiplier
+    pub fn yield_multiplier(&self) -> f32 {
+        match self {
+            Mutation::Dwarf => 0.8,
+            _ => 1.0,
+        }
+    }
+}
+
 /// Genetic traits that determine plant characteristics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Genetics {
@@ -39,48 +203,60 @@ pub struct Genetics {
     pub thc_percent: f32,
     /// Actual CBD % (within strain range)
     pub cbd_percent: f32,
+    /// Seed rarity tier
+    #[serde(default)]
+    pub rarity: Rarity,
+    /// Bonus trait: extra resilience
+    #[serde(default)]
+    pub hardy: bool,
+    /// Rare named mutation, if any
+    #[serde(default)]
+    pub mutation: Option<Mutation>,
 }
 
 impl Genetics {
-    /// Load strains from JSON file
-    pub fn load_strains() -> Vec<StrainInfo> {
-        // Try to load from current directory first, then from installed location
-        let paths = [
-            "strains.json",
-            "./strains.json",
-            "/home/zed/ganjatui/strains.json",
-        ];
-
-        for path in &paths {
-            if let Ok(content) = std::fs::read_to_string(path) {
-                if let Ok(strains) = serde_json::from_str::<Vec<StrainInfo>>(&content) {
-                    return strains;
-                }
-            }
+    /// How hard this plant stretches in early flowering
+    /// 1.0 roughly doubles height gain; Sativas stretch more, Indicas less
+    pub fn stretch_factor(&self) -> f32 {
+        match self.strain_info.as_ref().map(|s| s.strain_type.as_str()) {
+            Some("Sativa") => 1.5,
+            Some("Indica") => 0.6,
+            _ => 1.0,
         }
-
-        // Fallback to empty vec if file not found
-        Vec::new()
     }
 
     /// Generate random genetics for a new seed with strain data
-    pub fn random() -> Self {
-        let mut rng = rand::thread_rng();
-        let strains = Self::load_strains();
+    pub fn random<R: Rng>(rng: &mut R) -> Self {
+        Self::random_with(rng, &strains::database().strains)
+    }
 
+    /// Generate random genetics from a given RNG and strain list
+    /// Rarer seeds roll yield, quality and THC from the top of their ranges
+    pub fn random_with<R: Rng>(rng: &mut R, strains: &[StrainInfo]) -> Self {
         let strain_info = if !strains.is_empty() {
             Some(strains[rng.gen_range(0..strains.len())].clone())
         } else {
             None
         };
+        Self::roll(rng, strain_info)
+    }
+
+    /// Roll genetics for a specific strain (rarity and mutations still random)
+    pub fn from_strain<R: Rng>(rng: &mut R, strain: &StrainInfo) -> Self {
+        Self::roll(rng, Some(strain.clone()))
+    }
+
+    fn roll<R: Rng>(rng: &mut R, strain_info: Option<StrainInfo>) -> Self {
+        let rarity = Rarity::roll(rng);
+        let floor = rarity.range_floor();
 
         // Generate genetics based on strain or random
         let (yield_potential, resilience, quality_ceiling, thc_percent, cbd_percent) = if let Some(ref strain) = strain_info {
             let yield_base = match strain.yield_potential.as_str() {
-                "High" => rng.gen_range(100.0..=150.0),
-                "Medium" => rng.gen_range(70.0..=110.0),
-                "Low" => rng.gen_range(50.0..=80.0),
-                _ => rng.gen_range(50.0..=150.0),
+                "High" => roll_range(rng, 100.0, 150.0, floor),
+                "Medium" => roll_range(rng, 70.0, 110.0, floor),
+                "Low" => roll_range(rng, 50.0, 80.0, floor),
+                _ => roll_range(rng, 50.0, 150.0, floor),
             };
 
             let resilience_val = match strain.difficulty.as_str() {
@@ -91,27 +267,37 @@ impl Genetics {
             };
 
             let quality_val = match strain.strain_type.as_str() {
-                "Sativa" | "Indica" => rng.gen_range(80.0..=100.0),
-                "Hybrid" => rng.gen_range(85.0..=100.0),
-                _ => rng.gen_range(70.0..=100.0),
+                "Sativa" | "Indica" => roll_range(rng, 80.0, 100.0, floor),
+                "Hybrid" => roll_range(rng, 85.0, 100.0, floor),
+                _ => roll_range(rng, 70.0, 100.0, floor),
             };
 
-            let thc = rng.gen_range(strain.thc_min..=strain.thc_max);
+            let thc = roll_range(rng, strain.thc_min, strain.thc_max, floor);
             let cbd = rng.gen_range(strain.cbd_min..=strain.cbd_max);
 
             (yield_base, resilience_val, quality_val, thc, cbd)
         } else {
             // Random genetics if no strain data
             (
-                rng.gen_range(50.0..=150.0),
+                roll_range(rng, 50.0, 150.0, floor),
                 rng.gen_range(0.0..=1.0),
-                rng.gen_range(70.0..=100.0),
-                rng.gen_range(15.0..=25.0),
+                roll_range(rng, 70.0, 100.0, floor),
+                roll_range(rng, 15.0, 25.0, floor),
                 rng.gen_range(0.1..=1.0),
             )
         };
 
-        Self {
+        // Rare seeds sometimes come out extra hardy
+        let hardy = rng.gen_bool(rarity.bonus_trait_chance());
+        let resilience = if hardy {
+            (resilience + HARDY_RESILIENCE_BONUS).min(1.0)
+        } else {
+            resilience
+        };
+
+        let mutation = Mutation::roll(rng);
+
+        let mut genetics = Self {
             yield_potential,
             growth_rate: rng.gen_range(0.9..=1.1),
             resilience,
@@ -119,6 +305,95 @@ impl Genetics {
             strain_info,
             thc_percent,
             cbd_percent,
+            rarity,
+            hardy,
+            mutation: None,
+        };
+        genetics.apply_mutation(mutation);
+        genetics
+    }
+
+    /// Give these genetics a freshly rolled mutation, with its effect on the
+    /// traits (Extra Resinous raises THC). Inherited mutations come with the
+    /// parents' traits already changed, so they are set directly
+    pub fn apply_mutation(&mut self, mutation: Option<Mutation>) {
+        if mutation == Some(Mutation::ExtraResinous) {
+            self.thc_percent *= RESINOUS_THC_MULTIPLIER;
+        }
+        self.mutation = mutation;
+    }
+}
+
+/// Roll within `lo..=hi`, restricted to the top `1 - floor` of the range
+fn roll_range<R: Rng>(rng: &mut R, lo: f32, hi: f32, floor: f32) -> f32 {
+    lo + (hi - lo) * rng.gen_range(floor..=1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+
+    #[test]
+    fn potency_labels_follow_the_thresholds() {
+        assert!(potency_label(24.0, 0.2).starts_with("High THC"));
+        assert!(potency_label(24.0, 1.5).starts_with("High THC / Notable CBD"));
+        assert!(potency_label(20.0, 12.0).starts_with("High THC"), "high THC wins over a balanced ratio");
+        assert!(potency_label(15.0, 0.5).starts_with("Moderate"));
+        assert!(potency_label(8.0, 0.3).starts_with("Mild"));
+        assert!(potency_label(7.0, 8.0).starts_with("Balanced"));
+        assert!(potency_label(1.0, 14.0).starts_with("CBD dominant"));
+    }
+
+    #[test]
+    fn rarity_distribution_matches_weights() {
+        const SAMPLES: u32 = 100_000;
+        let mut counts = [0u32; 4];
+        for seed in 0..SAMPLES {
+            counts[test_support::plant_from(seed as u64).genetics.rarity as usize] += 1;
         }
+
+        for (rarity, weight) in RARITY_WEIGHTS {
+            let observed = counts[rarity as usize] as f32 / SAMPLES as f32 * 100.0;
+            assert!(
+                (observed - weight as f32).abs() < 1.0,
+                "{:?}: expected ~{}%, got {:.2}%",
+                rarity,
+                weight,
+                observed
+            );
+        }
+    }
+
+    #[test]
+    fn rare_seeds_roll_from_top_of_ranges() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let strains = strains::embedded();
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..2_000 {
+            let g = Genetics::random_with(&mut rng, &strains);
+            if g.rarity == Rarity::Legendary {
+                let strain = g.strain_info.as_ref().unwrap();
+                let thc_floor = strain.thc_min + (strain.thc_max - strain.thc_min) * 0.75;
+                assert!(g.thc_percent >= thc_floor - 1e-3);
+                assert!(g.quality_ceiling >= 85.0);
+            }
+            if !g.hardy {
+                continue;
+            }
+            assert!(g.rarity > Rarity::Common);
+        }
+    }
+
+    #[test]
+    fn mutations_are_rare() {
+        const SAMPLES: u64 = 100_000;
+        let mutated = (0..SAMPLES)
+            .filter(|&seed| test_support::plant_from(seed).genetics.mutation.is_some())
+            .count();
+        let observed = mutated as f64 / SAMPLES as f64;
+        assert!((observed - MUTATION_CHANCE).abs() < 0.005, "got {:.4}", observed);
     }
 }
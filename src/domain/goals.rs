@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+
+use super::harvest::LifetimeStats;
+
+/// An optional season objective for the player to work toward
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Goal {
+    /// Harvest this much dried bud across all grows
+    TotalYield { grams: f32 },
+    /// Finish a single grow at or above this quality
+    QualityGrow { quality: f32 },
+    /// Harvest this many plants
+    Harvests { count: u32 },
+}
+
+impl Goal {
+    /// Goals offered on the goals screen
+    pub const PRESETS: [Goal; 7] = [
+        Goal::TotalYield { grams: 250.0 },
+        Goal::TotalYield { grams: 500.0 },
+        Goal::TotalYield { grams: 1000.0 },
+        Goal::QualityGrow { quality: 85.0 },
+        Goal::QualityGrow { quality: 95.0 },
+        Goal::Harvests { count: 5 },
+        Goal::Harvests { count: 10 },
+    ];
+
+    /// Where the player stands and what the goal asks for, in the goal's own units
+    /// Lifetime totals only grow, so jarring a harvest never sets a goal back
+    pub fn standing(&self, lifetime: &LifetimeStats) -> (f32, f32) {
+        match *self {
+            Goal::TotalYield { grams } => (lifetime.bud_grams, grams),
+            Goal::QualityGrow { quality } => (lifetime.best_quality, quality),
+            Goal::Harvests { count } => (lifetime.harvests as f32, count as f32),
+        }
+    }
+
+    /// Progress toward the goal (0.0-1.0)
+    pub fn progress(&self, lifetime: &LifetimeStats) -> f32 {
+        let (current, target) = self.standing(lifetime);
+        if target <= 0.0 {
+            1.0
+        } else {
+            (current / target).clamp(0.0, 1.0)
+        }
+    }
+
+    pub fn is_met(&self, lifetime: &LifetimeStats) -> bool {
+        self.progress(lifetime) >= 1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+
+    #[test]
+    fn yield_goal_counts_dried_bud() {
+        let goal = Goal::TotalYield { grams: 500.0 };
+        let mut lifetime = LifetimeStats::default();
+        let harvest = test_support::harvest();
+        lifetime.record_dried(&harvest);
+
+        let (current, _) = goal.standing(&lifetime);
+        assert_eq!(current, harvest.bud_grams, "trim is not yield");
+        assert!(harvest.trim_grams > 0.0);
+        assert_eq!(goal.progress(&lifetime), (harvest.bud_grams / 500.0).min(1.0));
+    }
+
+    #[test]
+    fn quality_goal_needs_a_single_grow() {
+        let goal = Goal::QualityGrow { quality: 95.0 };
+        let mut lifetime = LifetimeStats::default();
+        lifetime.record_quality(90.0);
+        lifetime.record_quality(90.0);
+
+        assert!(!goal.is_met(&lifetime));
+        lifetime.record_quality(96.0);
+        assert!(goal.is_met(&lifetime));
+    }
+}
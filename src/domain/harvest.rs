@@ -1,7 +1,228 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use super::plant::Plant;
+use super::difficulty::CareBands;
+use super::genetics::{Genetics, Mutation, Rarity};
+use super::medium::GrowMedium;
+use super::plant::{Plant, CO2_MAX_BONUS};
+
+/// Share of the quality score that comes from terpenes
+const TERPENE_QUALITY_WEIGHT: f32 = 0.1;
+
+/// Weights of each optimal-band percentage in the care multiplier (sum to 1)
+const WATER_CARE_WEIGHT: f32 = 0.35;
+const NUTRIENT_CARE_WEIGHT: f32 = 0.35;
+const TEMP_CARE_WEIGHT: f32 = 0.15;
+const HUMIDITY_CARE_WEIGHT: f32 = 0.15;
+
+/// Yield and quality kept by buds cut at the very start of flowering
+/// (rising to 1.0 as they ripen)
+const UNRIPE_YIELD_FLOOR: f32 = 0.5;
+const UNRIPE_QUALITY_FLOOR: f32 = 0.8;
+
+fn default_percentage() -> f32 {
+    100.0
+}
+
+/// Grade points lost per stress event (at most GRADE_STRESS_MAX)
+const GRADE_STRESS_PER_EVENT: f32 = 0.5;
+const GRADE_STRESS_MAX: f32 = 5.0;
+
+/// Grade points per terpene point above (or below) the midpoint of 50
+const GRADE_TERPENE_WEIGHT: f32 = 0.05;
+
+/// Care multiplier (0.7-1.0) from the optimal-band percentages of water,
+/// nutrients, temperature and humidity
+fn care_quality(water_pct: f32, nutrient_pct: f32, temp_pct: f32, humidity_pct: f32) -> f32 {
+    ((water_pct * WATER_CARE_WEIGHT
+        + nutrient_pct * NUTRIENT_CARE_WEIGHT
+        + temp_pct * TEMP_CARE_WEIGHT
+        + humidity_pct * HUMIDITY_CARE_WEIGHT)
+        / 100.0)
+        .max(0.7)
+}
+
+/// Stress penalty - each stress event takes 2% (max 30%)
+fn stress_penalty(stress_events: usize) -> f32 {
+    (stress_events as f32 * 0.02).min(0.3)
+}
+
+/// How strongly the buds smell (0-100): the terpenes built in flower,
+/// expressed in full by a well-kept, unstressed plant and muted by poor care
+fn terpene_intensity(terpene_score: f32, care_quality: f32, stress_penalty: f32) -> f32 {
+    (terpene_score * care_quality * (1.0 - stress_penalty)).clamp(0.0, 100.0)
+}
+
+/// Aroma intensity the plant would have if harvested now
+pub fn projected_terpene_intensity(plant: &Plant) -> f32 {
+    let care = &plant.care_history;
+    terpene_intensity(
+        plant.terpene_intensity,
+        care_quality(
+            care.calculate_water_percentage(),
+            care.calculate_nutrient_percentage(),
+            care.calculate_temp_percentage(),
+            care.calculate_humidity_percentage(),
+        ),
+        stress_penalty(care.stress_events.len()),
+    )
+}
+
+/// Word for an aroma intensity, from "Faint" to "Pungent"
+pub fn aroma_descriptor(intensity: f32) -> &'static str {
+    if intensity >= 80.0 {
+        "Pungent"
+    } else if intensity >= 60.0 {
+        "Strong"
+    } else if intensity >= 40.0 {
+        "Fragrant"
+    } else if intensity >= 20.0 {
+        "Mild"
+    } else {
+        "Faint"
+    }
+}
+
+/// Trim share of a harvest before canopy, flowering and quality adjust it
+const BASE_TRIM_FRACTION: f32 = 0.15;
+
+/// Extra trim share from a sparse canopy and from low quality (at 0%)
+const SPARSE_CANOPY_TRIM: f32 = 0.15;
+const LOW_QUALITY_TRIM: f32 = 0.15;
+
+/// Trim share removed per flowering day beyond TRIM_FLOWERING_BASELINE (denser buds)
+const TRIM_PER_FLOWERING_DAY: f32 = 0.002;
+const TRIM_FLOWERING_BASELINE: f32 = 60.0;
+
+/// Share of a harvest that comes off as trim: sparse, stressed plants and
+/// short flowering leave more leaf; dense, long-flowered buds trim clean
+pub fn trim_fraction(canopy_density: f32, flowering_days: f32, quality_score: f32) -> f32 {
+    let sparse = (1.0 - canopy_density / 100.0).clamp(0.0, 1.0) * SPARSE_CANOPY_TRIM;
+    let low_quality = (1.0 - quality_score / 100.0).clamp(0.0, 1.0) * LOW_QUALITY_TRIM;
+    let flowering = (flowering_days - TRIM_FLOWERING_BASELINE) * TRIM_PER_FLOWERING_DAY;
+    (BASE_TRIM_FRACTION + sparse + low_quality - flowering).clamp(0.1, 0.45)
+}
+
+/// Letter grade for a finished harvest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Grade {
+    S,
+    A,
+    B,
+    C,
+    D,
+}
+
+impl Grade {
+    /// Grade for a bare score: S 95+, A 85-94, B 70-84, C 50-69, D below
+    pub fn from_score(score: f32) -> Self {
+        if score >= 95.0 {
+            Grade::S
+        } else if score >= 85.0 {
+            Grade::A
+        } else if score >= 70.0 {
+            Grade::B
+        } else if score >= 50.0 {
+            Grade::C
+        } else {
+            Grade::D
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Grade::S => "S",
+            Grade::A => "A",
+            Grade::B => "B",
+            Grade::C => "C",
+            Grade::D => "D",
+        }
+    }
+}
+
+/// Grade a harvest: quality, docked for stress and nudged by terpenes when known
+pub fn grade(quality_score: f32, stress_events: Option<usize>, terpene_score: Option<f32>) -> Grade {
+    let stress = stress_events.map_or(0.0, |n| (n as f32 * GRADE_STRESS_PER_EVENT).min(GRADE_STRESS_MAX));
+    let terpenes = terpene_score.map_or(0.0, |t| (t - 50.0) * GRADE_TERPENE_WEIGHT);
+    Grade::from_score(quality_score - stress + terpenes)
+}
+
+/// Factors that went into a harvest's quality score
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QualityBreakdown {
+    /// Share of grow time with water in the optimal band (0-100)
+    pub water_optimal_pct: f32,
+    /// Share of grow time with nutrients in the optimal band (0-100)
+    pub nutrient_optimal_pct: f32,
+    /// Share of grow time with temperature in the optimal band (0-100)
+    #[serde(default = "default_percentage")]
+    pub temp_optimal_pct: f32,
+    /// Share of grow time with humidity in the optimal band (0-100)
+    #[serde(default = "default_percentage")]
+    pub humidity_optimal_pct: f32,
+    /// Care multiplier from the four percentages above (0.7-1.0)
+    pub care_multiplier: f32,
+    pub stress_events: usize,
+    /// Fraction removed by stress (0.0-0.3)
+    pub stress_penalty: f32,
+    pub terpene_score: f32,
+    /// Yield multiplier from mutations
+    pub mutation_multiplier: f32,
+    /// What the plant grew in, which narrows the care bands
+    #[serde(default)]
+    pub medium: GrowMedium,
+}
+
+impl QualityBreakdown {
+    /// Compact summary, e.g. "Water 92% · NPK 80% · Climate 95% · 3 stress (-6%) · Terps 71"
+    pub fn summary(&self) -> String {
+        let mut text = format!(
+            "Water {:.0}% · NPK {:.0}% · Climate {:.0}% · {} stress (-{:.0}%) · Terps {:.0}",
+            self.water_optimal_pct,
+            self.nutrient_optimal_pct,
+            self.climate_optimal_pct(),
+            self.stress_events,
+            self.stress_penalty * 100.0,
+            self.terpene_score
+        );
+        if self.mutation_multiplier != 1.0 {
+            text.push_str(&format!(" · Mutation x{:.1}", self.mutation_multiplier));
+        }
+        text
+    }
+
+    /// Average of the temperature and humidity percentages
+    pub fn climate_optimal_pct(&self) -> f32 {
+        (self.temp_optimal_pct + self.humidity_optimal_pct) / 2.0
+    }
+
+    /// The factor that cost the most quality, as a short tip
+    /// `bands` are the difficulty's, before the medium narrows them
+    pub fn biggest_issue(&self, bands: &CareBands) -> Option<String> {
+        let water_loss = 100.0 - self.water_optimal_pct;
+        let nutrient_loss = 100.0 - self.nutrient_optimal_pct;
+        let stress_loss = self.stress_penalty * 200.0; // 2% per event weighs like 4 points of care
+        // Climate weighs less than water or nutrients in the care multiplier
+        let climate_loss = (100.0 - self.climate_optimal_pct())
+            * (TEMP_CARE_WEIGHT + HUMIDITY_CARE_WEIGHT) / WATER_CARE_WEIGHT;
+
+        if water_loss.max(nutrient_loss).max(stress_loss).max(climate_loss) < 10.0 {
+            None
+        } else if stress_loss >= water_loss && stress_loss >= nutrient_loss && stress_loss >= climate_loss {
+            Some("avoid stress events".to_string())
+        } else if climate_loss >= water_loss && climate_loss >= nutrient_loss {
+            Some("keep temperature at 20-28°C and humidity at 50-70%".to_string())
+        } else {
+            let bands = self.medium.bands(bands);
+            let (what, (low, high)) = if water_loss >= nutrient_loss {
+                ("water", bands.water_optimal)
+            } else {
+                ("nutrients", bands.nutrient_optimal)
+            };
+            Some(format!("keep {} in the {:.0}-{:.0}% band", what, low, high))
+        }
+    }
+}
 
 /// Result of harvesting a plant with calculated yield and quality
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -9,10 +230,60 @@ pub struct HarvestResult {
     pub strain_name: String,
     pub harvest_day: u32,
     pub completed_at: DateTime<Utc>,
+    /// Total weight, always bud_grams + trim_grams (kept for older saves and totals)
     pub weight_grams: f32,
+    /// Premium flower
+    #[serde(default)]
+    pub bud_grams: f32,
+    /// Sugar leaf and trim, for extracts later
+    #[serde(default)]
+    pub trim_grams: f32,
     pub quality_score: f32,  // 0-100
     pub thc_percent: f32,
     pub cbd_percent: f32,
+    #[serde(default)]
+    pub rarity: Rarity,
+    #[serde(default)]
+    pub mutation: Option<Mutation>,
+    /// Terpene intensity at harvest (0-100)
+    #[serde(default)]
+    pub terpene_score: f32,
+    /// Aroma intensity: the terpene score as care and stress let it express (0-100)
+    #[serde(default)]
+    pub terpene_intensity: f32,
+    /// Strain info copied at harvest (empty on old saves or strains without info)
+    #[serde(default)]
+    pub strain_type: String,
+    #[serde(default)]
+    pub phenotype: String,
+    #[serde(default, alias = "terpenes")]
+    pub dominant_terpenes: Vec<String>,
+    #[serde(default)]
+    pub aroma: Vec<String>,
+    /// Genetics of the harvested plant, kept for breeding
+    #[serde(default)]
+    pub genetics: Option<Genetics>,
+    /// How the quality score was reached (missing on old harvests)
+    #[serde(default)]
+    pub breakdown: Option<QualityBreakdown>,
+    /// Weight at the chop, before drying (missing on harvests from before drying existed)
+    #[serde(default)]
+    pub wet_weight_grams: Option<f32>,
+    /// In-game days the batch hung to dry
+    #[serde(default)]
+    pub dry_days: Option<f32>,
+    /// Hung too long and caught mold
+    #[serde(default)]
+    pub moldy: bool,
+    /// In-game days spent curing in a jar (None if never jarred)
+    #[serde(default)]
+    pub cure_days: Option<f32>,
+    /// Letter grade, settled when the harvest is finished (missing on old saves)
+    #[serde(default)]
+    pub grade: Option<Grade>,
+    /// Top colas cut early, with the rest of the plant left to ripen
+    #[serde(default)]
+    pub partial: bool,
 }
 
 impl HarvestResult {
@@ -24,17 +295,36 @@ impl HarvestResult {
         // Care quality multiplier based on optimal conditions (0.7-1.0)
         let water_pct = plant.care_history.calculate_water_percentage();
         let nutrient_pct = plant.care_history.calculate_nutrient_percentage();
-        let care_quality = ((water_pct + nutrient_pct) / 200.0).max(0.7);
+        let temp_pct = plant.care_history.calculate_temp_percentage();
+        let humidity_pct = plant.care_history.calculate_humidity_percentage();
+        let care_quality = care_quality(water_pct, nutrient_pct, temp_pct, humidity_pct);
 
         // Stress penalty - each stress event reduces yield by 2% (max -30%)
         let stress_count = plant.care_history.stress_events.len();
-        let stress_penalty = (stress_count as f32 * 0.02).min(0.3);
+        let stress_penalty = stress_penalty(stress_count);
+
+        // Mutations like Dwarf shrink the harvest
+        let mutation_multiplier = plant.genetics.mutation.map_or(1.0, |m| m.yield_multiplier());
 
-        // Final weight calculation
-        let weight_grams = base_yield * care_quality * (1.0 - stress_penalty);
+        // Buds cut before they ripen are lighter and weaker
+        let ripeness = plant.ripeness();
+        let ripe_yield = UNRIPE_YIELD_FLOOR + (1.0 - UNRIPE_YIELD_FLOOR) * ripeness;
+        let ripe_quality = UNRIPE_QUALITY_FLOOR + (1.0 - UNRIPE_QUALITY_FLOOR) * ripeness;
 
-        // Quality score (0-100) based on care and stress
-        let quality_score = (care_quality * 100.0 * (1.0 - stress_penalty))
+        // CO2 enrichment under strong light fattens the buds it helped grow
+        let co2_multiplier = plant.co2_yield_multiplier();
+
+        // Final weight calculation (minus any top colas already cut)
+        let weight_grams = base_yield * care_quality * (1.0 - stress_penalty) * mutation_multiplier * ripe_yield
+            * co2_multiplier
+            * plant.remaining_share();
+
+        // Quality score (0-100) based on care and stress, with a small terpene component
+        let terpene_score = plant.terpene_intensity;
+        let care_score = care_quality * 100.0 * (1.0 - stress_penalty);
+        let quality_score = ((care_score * (1.0 - TERPENE_QUALITY_WEIGHT) + terpene_score * TERPENE_QUALITY_WEIGHT)
+            * ripe_quality
+            + plant.ripening_bonus())
             .clamp(0.0, 100.0);
 
         // Cannabinoid content affected by quality (0.7-1.0 multiplier)
@@ -42,14 +332,453 @@ impl HarvestResult {
         let thc_percent = plant.genetics.thc_percent * cannabinoid_multiplier;
         let cbd_percent = plant.genetics.cbd_percent * cannabinoid_multiplier;
 
-        HarvestResult {
+        let strain = plant.genetics.strain_info.as_ref();
+
+        // Split the weight into bud and trim
+        let flowering_days = plant
+            .days_alive
+            .saturating_sub(plant.stage_start_day(crate::domain::GrowthStage::Flowering));
+        let trim_share = trim_fraction(plant.canopy_density, flowering_days as f32, quality_score);
+
+        let mut harvest = HarvestResult {
             strain_name: plant.strain_name.clone(),
             harvest_day: plant.days_alive,
             completed_at: Utc::now(),
-            weight_grams,
+            weight_grams: 0.0,
+            bud_grams: 0.0,
+            trim_grams: 0.0,
             quality_score,
             thc_percent,
             cbd_percent,
+            rarity: plant.genetics.rarity,
+            mutation: plant.genetics.mutation,
+            terpene_score,
+            terpene_intensity: terpene_intensity(terpene_score, care_quality, stress_penalty),
+            strain_type: strain.map(|s| s.strain_type.clone()).unwrap_or_default(),
+            phenotype: strain.map(|s| s.phenotype.clone()).unwrap_or_default(),
+            dominant_terpenes: strain.map(|s| s.dominant_terpenes.clone()).unwrap_or_default(),
+            aroma: strain.map(|s| s.aroma.clone()).unwrap_or_default(),
+            genetics: Some(plant.genetics.clone()),
+            breakdown: Some(QualityBreakdown {
+                water_optimal_pct: water_pct,
+                nutrient_optimal_pct: nutrient_pct,
+                temp_optimal_pct: temp_pct,
+                humidity_optimal_pct: humidity_pct,
+                care_multiplier: care_quality,
+                stress_events: stress_count,
+                stress_penalty,
+                terpene_score,
+                mutation_multiplier,
+                medium: plant.medium,
+            }),
+            wet_weight_grams: None,
+            dry_days: None,
+            moldy: false,
+            cure_days: None,
+            grade: None,
+            partial: false,
+        };
+        harvest.split_weight(weight_grams, trim_share);
+        harvest.regrade();
+        harvest
+    }
+
+    /// Best yield (with CO2 all grow long) and quality the genetics allowed
+    /// (None for harvests saved without genetics)
+    pub fn potential(&self) -> Option<(f32, f32)> {
+        self.genetics.as_ref().map(|g| {
+            let mutation_multiplier = g.mutation.map_or(1.0, |m| m.yield_multiplier());
+            (g.yield_potential * mutation_multiplier * (1.0 + CO2_MAX_BONUS), g.quality_ceiling)
+        })
+    }
+
+    /// Change the total weight, keeping the bud/trim ratio
+    pub fn set_weight(&mut self, grams: f32) {
+        let trim_share = if self.weight_grams > 0.0 {
+            self.trim_grams / self.weight_grams
+        } else {
+            0.0
+        };
+        self.split_weight(grams, trim_share);
+    }
+
+    /// Rebuild bud and trim from the total after loading: harvests saved
+    /// before the split count their whole weight as bud, and a hand-edited
+    /// split that no longer adds up keeps its trim share
+    pub fn migrate_split(&mut self) {
+        self.set_weight(self.weight_grams);
+    }
+
+    /// The only place the weights are written, so bud + trim is always the total
+    fn split_weight(&mut self, grams: f32, trim_share: f32) {
+        self.weight_grams = grams;
+        self.trim_grams = grams * trim_share.clamp(0.0, 1.0);
+        self.bud_grams = grams - self.trim_grams;
+    }
+
+    /// Recompute the grade after the quality score changed (drying, curing)
+    pub fn regrade(&mut self) {
+        let stress_events = self.breakdown.as_ref().map(|b| b.stress_events);
+        let terpene_score = (self.terpene_score > 0.0).then_some(self.terpene_score);
+        self.grade = Some(grade(self.quality_score, stress_events, terpene_score));
+    }
+
+    /// Stored grade, or one estimated from the score for harvests saved before grades
+    pub fn grade(&self) -> Grade {
+        self.grade.unwrap_or_else(|| Grade::from_score(self.quality_score))
+    }
+
+    /// Aroma intensity (harvests from before it was tracked use the terpene score)
+    pub fn aroma_intensity(&self) -> f32 {
+        if self.terpene_intensity > 0.0 {
+            self.terpene_intensity
+        } else {
+            self.terpene_score
+        }
+    }
+
+    /// One-line flavor summary, e.g. "Pungent citrus and pine - 82 terp score"
+    pub fn flavor_report(&self) -> String {
+        let strength = aroma_descriptor(self.aroma_intensity());
+
+        let notes: Vec<String> = self.aroma.iter().take(2).map(|a| a.to_lowercase()).collect();
+        let flavor = if notes.is_empty() {
+            "aroma".to_string()
+        } else {
+            notes.join(" and ")
+        };
+
+        format!("{} {} - {:.0} terp score", strength, flavor, self.terpene_score)
+    }
+}
+
+/// Personal bests (and the worst yield) across the harvest history
+/// Yields count bud only; ties go to the most recent harvest
+#[derive(Debug, Clone, Copy)]
+pub struct HarvestRecords<'a> {
+    pub best_yield: &'a HarvestResult,
+    pub best_quality: &'a HarvestResult,
+    pub highest_thc: &'a HarvestResult,
+    pub worst_yield: &'a HarvestResult,
+}
+
+impl<'a> HarvestRecords<'a> {
+    /// Fold the history (oldest first) in one pass; None when it is empty
+    pub fn from_history(history: &'a [HarvestResult]) -> Option<Self> {
+        let (first, rest) = history.split_first()?;
+        let start = Self {
+            best_yield: first,
+            best_quality: first,
+            highest_thc: first,
+            worst_yield: first,
+        };
+        Some(rest.iter().fold(start, |mut records, harvest| {
+            if harvest.bud_grams >= records.best_yield.bud_grams {
+                records.best_yield = harvest;
+            }
+            if harvest.quality_score >= records.best_quality.quality_score {
+                records.best_quality = harvest;
+            }
+            if harvest.thc_percent >= records.highest_thc.thc_percent {
+                records.highest_thc = harvest;
+            }
+            if harvest.bud_grams <= records.worst_yield.bud_grams {
+                records.worst_yield = harvest;
+            }
+            records
+        }))
+    }
+}
+
+/// Running totals over every grow. They live outside the harvest history,
+/// so trimming or archiving it (a new game) never resets them
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LifetimeStats {
+    pub bud_grams: f32,      // Dried bud, top-cola cuts included
+    pub harvests: u32,       // Plants cut down
+    pub best_quality: f32,   // Highest dried or cured quality score
+    pub days_simulated: f32, // Game days a plant spent growing
+}
+
+impl LifetimeStats {
+    /// Count a harvest once it has dried
+    pub fn record_dried(&mut self, harvest: &HarvestResult) {
+        self.bud_grams += harvest.bud_grams;
+        self.record_quality(harvest.quality_score);
+    }
+
+    /// A cure only changes quality; its bud was counted when it dried
+    pub fn record_quality(&mut self, quality_score: f32) {
+        self.best_quality = self.best_quality.max(quality_score);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Difficulty;
+    use crate::test_support;
+
+    #[test]
+    fn harvest_saved_before_strain_info_still_loads() {
+        let json = include_str!("../../tests/fixtures/harvest_pre_strain_info.json");
+        let harvest: HarvestResult = serde_json::from_str(json).unwrap();
+
+        assert_eq!(harvest.strain_name, "Northern Lights");
+        assert_eq!(harvest.dominant_terpenes, ["Myrcene", "Caryophyllene"]);
+        assert_eq!(harvest.aroma, ["Earthy", "Sweet"]);
+        assert!(harvest.strain_type.is_empty());
+        assert!(harvest.phenotype.is_empty());
+        assert_eq!(harvest.grade, None);
+        assert_eq!(harvest.grade(), Grade::B);
+    }
+
+    #[test]
+    fn old_single_weight_migrates_to_bud() {
+        let json = include_str!("../../tests/fixtures/harvest_pre_strain_info.json");
+        let mut harvest: HarvestResult = serde_json::from_str(json).unwrap();
+        harvest.migrate_split();
+
+        assert_eq!(harvest.bud_grams, 84.2);
+        assert_eq!(harvest.trim_grams, 0.0);
+    }
+
+    #[test]
+    fn bud_and_trim_always_add_up_to_the_weight() {
+        let adds_up = |h: &HarvestResult| (h.bud_grams + h.trim_grams - h.weight_grams).abs() < 1e-3;
+        let mut harvest = test_support::harvest();
+        assert!(harvest.trim_grams > 0.0 && adds_up(&harvest));
+
+        harvest.set_weight(harvest.weight_grams * 0.25);
+        assert!(adds_up(&harvest));
+
+        // A save edited by hand: the total wins and the trim share is kept
+        harvest.weight_grams = 100.0;
+        harvest.trim_grams = 200.0;
+        harvest.migrate_split();
+        assert_eq!((harvest.bud_grams, harvest.trim_grams), (0.0, 100.0));
+    }
+
+    #[test]
+    fn poor_care_leaves_more_trim() {
+        let mut last = 0.0;
+        for quality in [100.0, 90.0, 75.0, 60.0, 40.0] {
+            let trim = trim_fraction(70.0, 60.0, quality);
+            assert!(trim > last, "quality {} gave {}", quality, trim);
+            assert!((0.1..=0.45).contains(&trim));
+            last = trim;
         }
+        assert!(trim_fraction(30.0, 60.0, 80.0) > trim_fraction(90.0, 60.0, 80.0));
+        assert!(trim_fraction(70.0, 50.0, 80.0) > trim_fraction(70.0, 75.0, 80.0));
+    }
+
+    #[test]
+    fn potential_bounds_the_achieved_yield() {
+        let plant = test_support::plant();
+        let harvest = HarvestResult::from_plant(&plant);
+        let (potential_grams, ceiling) = harvest.potential().unwrap();
+
+        assert!(harvest.weight_grams <= potential_grams + 1e-3);
+        assert_eq!(ceiling, plant.genetics.quality_ceiling);
+
+        let old = HarvestResult { genetics: None, ..harvest };
+        assert!(old.potential().is_none());
+    }
+
+    #[test]
+    fn co2_enrichment_adds_to_the_yield() {
+        let mut plant = test_support::plant();
+        plant.total_hours_elapsed = 90.0 * 24.0;
+        let ambient = HarvestResult::from_plant(&plant).weight_grams;
+
+        // Half the grow at the full bonus
+        plant.co2_bonus_hours = plant.total_hours_elapsed * 0.5 * CO2_MAX_BONUS;
+        let enriched = HarvestResult::from_plant(&plant);
+        let expected = ambient * (1.0 + 0.5 * CO2_MAX_BONUS);
+        assert!((enriched.weight_grams - expected).abs() < 1e-3, "{} vs {}", enriched.weight_grams, expected);
+        assert!(enriched.weight_grams <= enriched.potential().unwrap().0);
+    }
+
+    #[test]
+    fn hot_humid_grows_score_lower() {
+        use crate::domain::plant::CareHistory;
+
+        let mut plant = test_support::plant();
+        let hours = 90.0 * 24.0;
+        let optimal = CareHistory {
+            total_hours: hours,
+            total_optimal_water_hours: hours,
+            total_optimal_nutrient_hours: hours,
+            total_optimal_temp_hours: hours,
+            total_optimal_humidity_hours: hours,
+            environment_hours: hours,
+            ..CareHistory::default()
+        };
+        plant.care_history = optimal.clone();
+        let good = HarvestResult::from_plant(&plant).quality_score;
+
+        // Weeks at 32°C and 85% humidity: water and feeding were still perfect
+        plant.care_history = CareHistory {
+            total_optimal_temp_hours: hours * 0.2,
+            total_optimal_humidity_hours: hours * 0.2,
+            ..optimal.clone()
+        };
+        let hot = HarvestResult::from_plant(&plant);
+        assert!(good - hot.quality_score >= 15.0, "good {} vs hot {}", good, hot.quality_score);
+        let tip = hot.breakdown.unwrap().biggest_issue(Difficulty::Normal.bands()).unwrap();
+        assert!(tip.starts_with("keep temperature"), "{}", tip);
+
+        // Legacy histories without environment tracking are treated as optimal
+        plant.care_history = CareHistory {
+            total_optimal_temp_hours: 0.0,
+            total_optimal_humidity_hours: 0.0,
+            environment_hours: 0.0,
+            ..optimal
+        };
+        assert_eq!(HarvestResult::from_plant(&plant).quality_score, good);
+    }
+
+    #[test]
+    fn care_tips_quote_the_bands_of_the_grow() {
+        let dry = QualityBreakdown {
+            water_optimal_pct: 40.0,
+            nutrient_optimal_pct: 95.0,
+            temp_optimal_pct: 100.0,
+            humidity_optimal_pct: 100.0,
+            care_multiplier: 0.8,
+            stress_events: 0,
+            stress_penalty: 0.0,
+            terpene_score: 50.0,
+            mutation_multiplier: 1.0,
+            medium: GrowMedium::Soil,
+        };
+        let tip = |breakdown: &QualityBreakdown, difficulty: Difficulty| breakdown.biggest_issue(difficulty.bands()).unwrap();
+        assert_eq!(tip(&dry, Difficulty::Normal), "keep water in the 40-80% band");
+        assert_eq!(tip(&dry, Difficulty::Easy), "keep water in the 30-88% band");
+        assert_eq!(tip(&dry, Difficulty::Hard), "keep water in the 50-70% band");
+
+        // Hydro narrows the band the plant was held to
+        let hydro = QualityBreakdown { medium: GrowMedium::Hydro, ..dry.clone() };
+        let (low, high) = GrowMedium::Hydro.bands(Difficulty::Hard.bands()).water_optimal;
+        assert_eq!(tip(&hydro, Difficulty::Hard), format!("keep water in the {:.0}-{:.0}% band", low, high));
+
+        let hungry = QualityBreakdown { water_optimal_pct: 95.0, nutrient_optimal_pct: 40.0, ..dry };
+        assert_eq!(tip(&hungry, Difficulty::Hard), "keep nutrients in the 58-74% band");
+    }
+
+    #[test]
+    fn lower_buds_ripen_lighter_but_better() {
+        use crate::domain::GrowthStage;
+
+        let mut plant = test_support::plant();
+        plant.stage = GrowthStage::ReadyToHarvest;
+        plant.days_alive = 90;
+        let full = HarvestResult::from_plant(&plant);
+
+        plant.partial_harvest_day = Some(90);
+        plant.days_alive = 100;
+        let rest = HarvestResult::from_plant(&plant);
+        assert!((rest.weight_grams - full.weight_grams * 0.6).abs() < 1e-3);
+        assert!((rest.quality_score - full.quality_score - 5.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn records_prefer_the_most_recent_tie() {
+        let base = test_support::harvest();
+        let harvest = |day: u32, bud_grams: f32, quality_score: f32, thc_percent: f32| HarvestResult {
+            harvest_day: day,
+            bud_grams,
+            quality_score,
+            thc_percent,
+            ..base.clone()
+        };
+        let history = [
+            harvest(1, 80.0, 90.0, 20.0),
+            harvest(2, 40.0, 95.0, 18.0),
+            harvest(3, 80.0, 70.0, 20.0),
+            harvest(4, 40.0, 60.0, 12.0),
+        ];
+
+        let records = HarvestRecords::from_history(&history).unwrap();
+        assert_eq!(records.best_yield.harvest_day, 3);
+        assert_eq!(records.best_quality.harvest_day, 2);
+        assert_eq!(records.highest_thc.harvest_day, 3);
+        assert_eq!(records.worst_yield.harvest_day, 4);
+        assert!(HarvestRecords::from_history(&[]).is_none());
+    }
+
+    #[test]
+    fn lifetime_stats_count_bud_once_and_keep_the_best_quality() {
+        let mut harvest = test_support::harvest();
+        harvest.bud_grams = 40.0;
+        harvest.quality_score = 80.0;
+
+        let mut lifetime = LifetimeStats::default();
+        lifetime.record_dried(&harvest);
+        lifetime.record_quality(88.0); // The same harvest, cured
+        harvest.quality_score = 60.0;
+        lifetime.record_dried(&harvest);
+
+        assert_eq!(lifetime.bud_grams, 80.0);
+        assert_eq!(lifetime.best_quality, 88.0);
+    }
+
+    #[test]
+    fn set_weight_keeps_the_split() {
+        let mut harvest = test_support::harvest();
+        let share = harvest.trim_grams / harvest.weight_grams;
+        assert!((harvest.bud_grams + harvest.trim_grams - harvest.weight_grams).abs() < 1e-3);
+
+        harvest.set_weight(harvest.weight_grams * 0.5);
+        assert!((harvest.trim_grams / harvest.weight_grams - share).abs() < 1e-4);
+        assert!((harvest.bud_grams + harvest.trim_grams - harvest.weight_grams).abs() < 1e-3);
+    }
+
+    #[test]
+    fn grade_boundaries() {
+        let cases = [
+            (100.0, Grade::S),
+            (95.0, Grade::S),
+            (94.9, Grade::A),
+            (85.0, Grade::A),
+            (84.9, Grade::B),
+            (70.0, Grade::B),
+            (69.9, Grade::C),
+            (50.0, Grade::C),
+            (49.9, Grade::D),
+            (0.0, Grade::D),
+        ];
+        for (score, expected) in cases {
+            assert_eq!(Grade::from_score(score), expected, "score {}", score);
+            assert_eq!(grade(score, None, None), expected, "score {}", score);
+        }
+    }
+
+    #[test]
+    fn stress_and_terpenes_shift_the_grade() {
+        assert_eq!(grade(96.0, Some(4), None), Grade::A);
+        assert_eq!(grade(96.0, Some(40), Some(100.0)), Grade::A, "stress is capped");
+        assert_eq!(grade(93.0, Some(0), Some(100.0)), Grade::S);
+        assert_eq!(grade(86.0, None, Some(0.0)), Grade::B);
+        assert_eq!(grade(86.0, None, Some(50.0)), Grade::A);
+    }
+
+    #[test]
+    fn stress_mutes_the_aroma() {
+        use crate::domain::{StressCause, StressEvent, StressSeverity};
+
+        let mut plant = test_support::plant();
+        plant.terpene_intensity = 90.0;
+        let cared_for = HarvestResult::from_plant(&plant);
+        assert_eq!(cared_for.terpene_intensity, projected_terpene_intensity(&plant));
+        assert_eq!(aroma_descriptor(cared_for.aroma_intensity()), "Pungent");
+
+        let stress = StressEvent { day: 1, severity: StressSeverity::Moderate, cause: StressCause::LowWater };
+        plant.care_history.stress_events = vec![stress; 15];
+        let stressed = HarvestResult::from_plant(&plant);
+        assert_eq!(stressed.terpene_score, cared_for.terpene_score, "the same terpenes were built");
+        assert!(stressed.terpene_intensity < cared_for.terpene_intensity * 0.75);
+        assert_eq!(aroma_descriptor(stressed.aroma_intensity()), "Strong");
     }
 }
@@ -0,0 +1,130 @@
+use serde::{Deserialize, Serialize};
+
+use super::difficulty::CareBands;
+
+/// What the roots grow in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum GrowMedium {
+    /// Buffers mistakes and dries out slowly (every plant before media existed)
+    #[default]
+    Soil,
+    /// Drains faster and holds fewer nutrients
+    Coco,
+    /// Roots hang in solution: levels swing fast and the bands are tight
+    Hydro,
+}
+
+impl GrowMedium {
+    pub fn next(self) -> Self {
+        match self {
+            GrowMedium::Soil => GrowMedium::Coco,
+            GrowMedium::Coco => GrowMedium::Hydro,
+            GrowMedium::Hydro => GrowMedium::Soil,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            GrowMedium::Soil => "Soil",
+            GrowMedium::Coco => "Coco",
+            GrowMedium::Hydro => "Hydro",
+        }
+    }
+
+    /// Short note on how the medium behaves
+    pub fn description(self) -> &'static str {
+        match self {
+            GrowMedium::Soil => "slow drainage, forgiving",
+            GrowMedium::Coco => "drains faster, tighter bands",
+            GrowMedium::Hydro => "fast swings, unforgiving",
+        }
+    }
+
+    /// Multiplier on the per-stage water drain
+    pub fn water_drain(self) -> f32 {
+        match self {
+            GrowMedium::Soil => 1.0,
+            GrowMedium::Coco => 1.3,
+            GrowMedium::Hydro => 1.8,
+        }
+    }
+
+    /// Multiplier on the per-stage nutrient drain
+    pub fn nutrient_drain(self) -> f32 {
+        match self {
+            GrowMedium::Soil => 1.0,
+            GrowMedium::Coco => 1.25,
+            GrowMedium::Hydro => 1.6,
+        }
+    }
+
+    /// Points taken off each end of the optimal water and nutrient bands
+    fn band_margin(self) -> f32 {
+        match self {
+            GrowMedium::Soil => 0.0,
+            GrowMedium::Coco => 2.0,
+            GrowMedium::Hydro => 4.0,
+        }
+    }
+
+    /// The difficulty's bands, tightened for this medium. Auto-care doses
+    /// shrink with the band so they still land inside it
+    pub fn bands(self, base: &CareBands) -> CareBands {
+        let margin = self.band_margin();
+        let shrink = |(low, high): (f32, f32)| (low + margin, high - margin);
+        let inside = |(low, high): (f32, f32), (min, max): (f32, f32)| (low.max(min), high.min(max));
+
+        let water_optimal = shrink(base.water_optimal);
+        let nutrient_optimal = shrink(base.nutrient_optimal);
+        CareBands {
+            water_optimal,
+            nutrient_optimal,
+            water_excellent: inside(base.water_excellent, water_optimal),
+            nutrient_excellent: inside(base.nutrient_excellent, nutrient_optimal),
+            auto_water_dose: (base.auto_water_dose - 2.0 * margin).max(1.0),
+            auto_feed_dose: (base.auto_feed_dose - 2.0 * margin).max(1.0),
+            ..*base
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Difficulty;
+
+    const MEDIA: [GrowMedium; 3] = [GrowMedium::Soil, GrowMedium::Coco, GrowMedium::Hydro];
+
+    #[test]
+    fn soil_keeps_the_difficulty_bands() {
+        for difficulty in [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard] {
+            assert_eq!(GrowMedium::Soil.bands(difficulty.bands()), *difficulty.bands());
+        }
+    }
+
+    #[test]
+    fn tighter_media_still_nest_and_auto_care_lands_inside() {
+        for difficulty in [Difficulty::Easy, Difficulty::Hard] {
+            for medium in MEDIA {
+                let b = medium.bands(difficulty.bands());
+                let case = format!("{:?} in {:?}", difficulty, medium);
+                for (excellent, optimal) in [(b.water_excellent, b.water_optimal), (b.nutrient_excellent, b.nutrient_optimal)] {
+                    assert!(optimal.0 <= excellent.0 && excellent.1 <= optimal.1, "{}", case);
+                    assert!(excellent.0 < excellent.1, "{}", case);
+                }
+                assert!(b.water_is_optimal(b.water_optimal.0 - 0.1 + b.auto_water_dose), "{}", case);
+                assert!(b.nutrients_are_optimal(b.nutrient_optimal.0 - 0.1 + b.auto_feed_dose), "{}", case);
+            }
+        }
+    }
+
+    #[test]
+    fn hydro_is_the_least_forgiving() {
+        let width = |band: (f32, f32)| band.1 - band.0;
+        let [soil, coco, hydro] = MEDIA.map(|m| m.bands(Difficulty::Normal.bands()));
+        assert!(width(soil.water_optimal) > width(coco.water_optimal));
+        assert!(width(coco.water_optimal) > width(hydro.water_optimal));
+        assert!(GrowMedium::Hydro.water_drain() > GrowMedium::Coco.water_drain());
+        assert!(GrowMedium::Coco.nutrient_drain() > GrowMedium::Soil.nutrient_drain());
+    }
+}
@@ -1,8 +1,23 @@
-pub mod genetics;
-pub mod harvest;
-pub mod plant;
+pub(crate) mod breeding;
+pub(crate) mod curing;
+pub(crate) mod difficulty;
+pub(crate) mod drying;
+pub(crate) mod environment;
+pub(crate) mod events;
+pub(crate) mod genetics;
+pub(crate) mod goals;
+pub(crate) mod harvest;
+pub(crate) mod medium;
+pub(crate) mod plant;
+pub(crate) mod rng;
+pub(crate) mod share;
+pub mod strains;
 
-pub use harvest::HarvestResult;
+pub use breeding::Seed;
+pub use difficulty::{CareBands, Difficulty};
+pub use goals::Goal;
+pub use harvest::{Grade, HarvestResult, LifetimeStats};
+pub use medium::GrowMedium;
 pub use plant::{
     GrowthStage, HealthStatus, LightCycle, Plant,
     StressEvent, StressSeverity, StressCause,
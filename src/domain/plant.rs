@@ -1,15 +1,89 @@
+use std::collections::VecDeque;
+
 use chrono::{DateTime, Utc};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::breeding::{Lineage, Seed};
+use super::difficulty::CareBands;
 use super::genetics::Genetics;
+use super::medium::GrowMedium;
+use super::rng::random_uuid;
+use super::strains::FLOWERING_DAYS;
+
+// Optimal environment bands (shared by simulation and UI); water and
+// nutrients are the Normal difficulty's, see `CareBands`
+pub const TEMP_OPTIMAL_MIN: f32 = 20.0;
+pub const TEMP_OPTIMAL_MAX: f32 = 28.0;
+pub const HUMIDITY_OPTIMAL_MIN: f32 = 50.0;
+pub const HUMIDITY_OPTIMAL_MAX: f32 = 70.0;
+pub const WATER_OPTIMAL_MIN: f32 = 40.0;
+pub const WATER_OPTIMAL_MAX: f32 = 80.0;
+pub const NUTRIENT_OPTIMAL_MIN: f32 = 50.0;
+pub const NUTRIENT_OPTIMAL_MAX: f32 = 80.0;
+
+/// Levels below which the plant counts as stressed on Normal difficulty
+pub const WATER_CRITICAL: f32 = 20.0;
+pub const NUTRIENT_CRITICAL: f32 = 30.0;
+
+/// Water level below which the plant starts to droop
+pub const WILT_WATER: f32 = 25.0;
+/// Game hours a fully wilted plant takes to stand back up once put right
+const WILT_RECOVERY_HOURS: f32 = 24.0;
+
+// Temperatures outside this band only come from corrupted saves
+const SANE_TEMP_MIN: f32 = 0.0;
+const SANE_TEMP_MAX: f32 = 45.0;
+
+/// Maximum growth bonus from CO2 enrichment (+30% at 100% CO2)
+pub const CO2_MAX_BONUS: f32 = 0.3;
+/// Ambient CO2 level above which enrichment starts to help
+const CO2_AMBIENT: f32 = 80.0;
+
+/// Length of the flowering stretch window in days
+pub const STRETCH_DAYS: u32 = 15;
+
+/// Terpene intensity gained per game hour of flowering under neutral conditions
+const TERPENE_GAIN_PER_HOUR: f32 = 0.1;
+/// Terpene intensity lost per game hour when late flower runs hot
+const TERPENE_HEAT_LOSS_PER_HOUR: f32 = 0.15;
+/// Late-flower temperature at or below which terpenes are preserved best
+const TERPENE_COOL_TEMP: f32 = 24.0;
+/// Nutrient level below which late flower counts as flushing
+const TERPENE_FLUSH_NUTRIENTS: f32 = 40.0;
+
+/// Root growth per game hour with water in the optimal band (full roots by ~day 80)
+const ROOT_GROWTH_PER_HOUR: f32 = 100.0 / (80.0 * 24.0);
+/// Share of that growth kept when water is merely acceptable
+const ROOT_ACCEPTABLE_FACTOR: f32 = 0.4;
+/// Root loss per game hour in drought or while drowning
+const ROOT_DIEBACK_PER_HOUR: f32 = 0.02;
+/// Water level below which roots dry out and die back
+const ROOT_DROUGHT_LEVEL: f32 = 20.0;
+
+/// Water level above which the roots start drowning
+pub const OVERWATER_LEVEL: f32 = 90.0;
+/// Game hours of overwatering before growth and health suffer
+pub const OVERWATER_GRACE_HOURS: f32 = 24.0;
+
+/// Strain flowering time matching the baseline flowering stage; strains that
+/// flower longer or shorter stretch or shrink the stage in proportion
+const REFERENCE_FLOWERING_TIME: f32 = 63.0;
+
+/// Share of the yield taken by a partial harvest of the top colas
+pub const PARTIAL_HARVEST_SHARE: f32 = 0.4;
+/// In-game days the lower buds keep ripening before the rest is cut
+pub const PARTIAL_RIPENING_DAYS: u32 = 10;
+/// Quality points the lower buds gain per extra day of ripening
+const PARTIAL_QUALITY_PER_DAY: f32 = 0.5;
 
 /// Growth stages of the plant
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum GrowthStage {
-    Seed,
-    Germination,    // Days 1-3
-    Seedling,       // Days 4-14
+    Seed,           // Just planted
+    Germination,    // Days 0-1
+    Seedling,       // Days 2-10
     Vegetative,     // Days 15-45
     PreFlower,      // Days 46-52
     Flowering,      // Days 53-90
@@ -29,6 +103,17 @@ impl GrowthStage {
             GrowthStage::ReadyToHarvest => "Ready to Harvest",
         }
     }
+
+    /// Optimal light intensity band (min, max) for this stage
+    /// Seedlings want gentle light, flowering plants want it strong
+    pub fn optimal_light(&self) -> (f32, f32) {
+        match self {
+            GrowthStage::Seed | GrowthStage::Germination | GrowthStage::Seedling => (20.0, 45.0),
+            GrowthStage::Vegetative => (45.0, 75.0),
+            GrowthStage::PreFlower => (55.0, 85.0),
+            GrowthStage::Flowering | GrowthStage::ReadyToHarvest => (65.0, 95.0),
+        }
+    }
 }
 
 /// Light cycle settings
@@ -50,6 +135,18 @@ pub enum HealthStatus {
     Critical,
 }
 
+impl HealthStatus {
+    /// One step worse (Critical stays Critical)
+    pub fn worse(self) -> Self {
+        match self {
+            HealthStatus::Excellent => HealthStatus::Good,
+            HealthStatus::Good => HealthStatus::Fair,
+            HealthStatus::Fair => HealthStatus::Poor,
+            HealthStatus::Poor | HealthStatus::Critical => HealthStatus::Critical,
+        }
+    }
+}
+
 /// Stress event severity
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum StressSeverity {
@@ -66,6 +163,31 @@ pub enum StressCause {
     LowNutrients,
     NutrientBurn,
     WrongLightCycle,
+    LightBurn,
+}
+
+impl StressSeverity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StressSeverity::Minor => "Minor",
+            StressSeverity::Moderate => "Moderate",
+            StressSeverity::Severe => "Severe",
+        }
+    }
+}
+
+impl StressCause {
+    /// What went wrong, in a few words
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StressCause::LowWater => "thirsty",
+            StressCause::HighWater => "overwatered",
+            StressCause::LowNutrients => "hungry for nutrients",
+            StressCause::NutrientBurn => "nutrient burn",
+            StressCause::WrongLightCycle => "wrong light cycle",
+            StressCause::LightBurn => "light burn",
+        }
+    }
 }
 
 /// A stress event recorded in care history
@@ -82,12 +204,22 @@ pub struct CareHistory {
     /// Total game hours elapsed (for percentage calculations)
     #[serde(default)]
     pub total_hours: f32,
-    /// Total hours water was in optimal range (40-80%)
+    /// Total hours water was in the optimal band (set by difficulty and medium)
     #[serde(default)]
     pub total_optimal_water_hours: f32,
-    /// Total hours nutrients were in optimal range (50-80%)
+    /// Total hours nutrients were in the optimal band (set by difficulty and medium)
     #[serde(default)]
     pub total_optimal_nutrient_hours: f32,
+    /// Total hours temperature was in optimal range (20-28°C)
+    #[serde(default)]
+    pub total_optimal_temp_hours: f32,
+    /// Total hours humidity was in optimal range (50-70%)
+    #[serde(default)]
+    pub total_optimal_humidity_hours: f32,
+    /// Hours covered by the temperature/humidity tracking (older saves
+    /// started tracking late; the hours before count as optimal)
+    #[serde(default)]
+    pub environment_hours: f32,
 
     /// Deprecated - use calculate_water_percentage() instead
     #[serde(default = "default_percentage")]
@@ -106,6 +238,10 @@ fn default_percentage() -> f32 {
     100.0
 }
 
+fn default_light_intensity() -> f32 {
+    50.0
+}
+
 impl CareHistory {
     /// Calculate actual water percentage based on cumulative tracking
     pub fn calculate_water_percentage(&self) -> f32 {
@@ -125,6 +261,26 @@ impl CareHistory {
         }
     }
 
+    /// Share of the grow with temperature in the optimal band
+    pub fn calculate_temp_percentage(&self) -> f32 {
+        self.environment_percentage(self.total_optimal_temp_hours)
+    }
+
+    /// Share of the grow with humidity in the optimal band
+    pub fn calculate_humidity_percentage(&self) -> f32 {
+        self.environment_percentage(self.total_optimal_humidity_hours)
+    }
+
+    /// Untracked hours (from before the environment was recorded) count as optimal
+    fn environment_percentage(&self, optimal_hours: f32) -> f32 {
+        if self.total_hours == 0.0 {
+            100.0
+        } else {
+            let untracked = (self.total_hours - self.environment_hours).max(0.0);
+            ((optimal_hours + untracked) / self.total_hours * 100.0).min(100.0)
+        }
+    }
+
     /// Check if a recent stress event of this cause was already recorded
     /// Prevents spam of events - only records if no event of same cause in last 5 days
     pub fn has_recent_stress(&self, cause: StressCause, current_day: u32) -> bool {
@@ -142,6 +298,9 @@ impl Default for CareHistory {
             total_hours: 0.0,
             total_optimal_water_hours: 0.0,
             total_optimal_nutrient_hours: 0.0,
+            total_optimal_temp_hours: 0.0,
+            total_optimal_humidity_hours: 0.0,
+            environment_hours: 0.0,
             water_optimal_percentage: 100.0,
             nutrient_optimal_percentage: 100.0,
             light_cycle_correct: true,
@@ -150,6 +309,17 @@ impl Default for CareHistory {
     }
 }
 
+/// Maximum number of resource samples kept per plant (one per game day)
+pub const RESOURCE_HISTORY_LEN: usize = 100;
+
+/// A daily snapshot of resource levels for the care history chart
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ResourceSample {
+    pub day: u32,
+    pub water_level: f32,
+    pub nutrient_level: f32,
+}
+
 /// The main plant structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Plant {
@@ -173,23 +343,63 @@ pub struct Plant {
     pub humidity: f32,            // 0-100% (50-70% optimal)
     pub root_development: f32,    // 0-100% (root system strength)
     pub canopy_density: f32,      // 0-100% (foliage coverage)
+    #[serde(default = "default_light_intensity")]
+    pub light_intensity: f32,     // 0-100% (lamp output)
+    #[serde(default)]
+    pub co2_bonus_hours: f32,     // Extra growth hours credited by CO2 enrichment
+    #[serde(default)]
+    pub overwatered_hours: f32,   // Consecutive game hours above OVERWATER_LEVEL
+    #[serde(default)]
+    pub droop: f32,               // Wilt still easing off since the plant was last put right
+    #[serde(default)]
+    pub terpene_intensity: f32,   // 0-100% (aroma strength, builds during flowering)
+
+    /// Rolling buffer of recent water/nutrient samples (oldest first)
+    #[serde(default)]
+    pub resource_history: VecDeque<ResourceSample>,
+
+    /// Parent strains, for plants grown from bred seeds
+    #[serde(default)]
+    pub lineage: Option<Lineage>,
+
+    /// Day the top colas were cut, if the plant was partially harvested
+    #[serde(default)]
+    pub partial_harvest_day: Option<u32>,
+
+    /// What the roots grow in (saves from before media grew in soil)
+    #[serde(default)]
+    pub medium: GrowMedium,
+
+    /// Day each stage was reached, in the order the plant grew into them
+    #[serde(default)]
+    pub stage_days: Vec<(GrowthStage, u32)>,
 }
 
 impl Plant {
     /// Create a new plant with random genetics
-    pub fn new_random() -> Self {
-        let genetics = Genetics::random();
+    pub fn new_random<R: Rng>(rng: &mut R) -> Self {
+        let id = random_uuid(rng);
+        let genetics = Genetics::random(rng);
         let strain_name = genetics.strain_info
             .as_ref()
             .map(|s| s.name.clone())
             .unwrap_or_else(|| "Unknown Strain".to_string());
 
+        Self::with_genetics(id, strain_name, genetics, None)
+    }
+
+    /// Create a new plant from a seed in the inventory (the plant keeps the seed's id)
+    pub fn from_seed(seed: Seed) -> Self {
+        Self::with_genetics(seed.id, seed.strain_name, seed.genetics, seed.lineage)
+    }
+
+    fn with_genetics(id: Uuid, strain_name: String, genetics: Genetics, lineage: Option<Lineage>) -> Self {
         Self {
-            id: Uuid::new_v4(),
+            id,
             strain_name,
-            stage: GrowthStage::Seedling,  // Start directly as seedling
+            stage: GrowthStage::Seed,  // Germinates once time starts passing
             planted_at: Utc::now(),
-            days_alive: 1,  // Start at day 1
+            days_alive: 0,
             total_hours_elapsed: 0.0,
             water_level: 60.0,
             nutrient_level: 60.0,
@@ -203,29 +413,117 @@ impl Plant {
             humidity: 60.0,
             root_development: 10.0,
             canopy_density: 5.0,
+            light_intensity: 30.0,
+            co2_bonus_hours: 0.0,
+            overwatered_hours: 0.0,
+            droop: 0.0,
+            terpene_intensity: 0.0,
+            resource_history: VecDeque::new(),
+            lineage,
+            partial_harvest_day: None,
+            medium: GrowMedium::default(),
+            stage_days: Vec::new(),
         }
     }
 
     // Removed new() method - use new_random() instead
 
-    /// Calculate growth stage based on days alive
-    pub fn calculate_stage(days: u32) -> GrowthStage {
-        match days {
-            1..=10 => GrowthStage::Seedling,      // Days 1-10: small seedling
-            11..=40 => GrowthStage::Vegetative,   // Days 11-40: vegetative growth
-            41..=48 => GrowthStage::PreFlower,    // Days 41-48: pre-flower
-            49..=85 => GrowthStage::Flowering,    // Days 49-85: flowering
-            _ => GrowthStage::ReadyToHarvest,     // Days 86+: ready to harvest
+    /// First day of a stage on the baseline schedule (growth_rate 1.0)
+    fn base_stage_start_day(stage: GrowthStage) -> u32 {
+        match stage {
+            GrowthStage::Seed | GrowthStage::Germination => 0, // Days 0-1: sprouting
+            GrowthStage::Seedling => 2,
+            GrowthStage::Vegetative => 11,      // Days 11-40: vegetative growth
+            GrowthStage::PreFlower => 41,       // Days 41-48: pre-flower
+            GrowthStage::Flowering => 49,       // Days 49-85: flowering
+            GrowthStage::ReadyToHarvest => 86,  // Days 86+: ready to harvest
+        }
+    }
+
+    /// Length of the flowering stage before growth rate, scaled by the strain's
+    /// flowering time (the baseline 37 days without strain data)
+    fn flowering_days(&self) -> f32 {
+        let baseline = (Self::base_stage_start_day(GrowthStage::ReadyToHarvest)
+            - Self::base_stage_start_day(GrowthStage::Flowering)) as f32;
+        self.genetics.strain_info.as_ref().map_or(baseline, |strain| {
+            let days = strain.flowering_time.clamp(*FLOWERING_DAYS.start(), *FLOWERING_DAYS.end());
+            baseline * days as f32 / REFERENCE_FLOWERING_TIME
+        })
+    }
+
+    /// First day of a stage for this plant
+    /// Fast-growing genetics (growth_rate > 1.0) reach each stage sooner, and
+    /// the strain's flowering time decides when the buds are ready
+    pub fn stage_start_day(&self, stage: GrowthStage) -> u32 {
+        let base = match stage {
+            GrowthStage::ReadyToHarvest => {
+                Self::base_stage_start_day(GrowthStage::Flowering) as f32 + self.flowering_days()
+            }
+            _ => Self::base_stage_start_day(stage) as f32,
+        };
+        (base / self.genetics.growth_rate).round() as u32
+    }
+
+    /// Calculate growth stage based on days alive, scaled by genetics
+    pub fn calculate_stage(&self, days: u32) -> GrowthStage {
+        if days >= self.stage_start_day(GrowthStage::ReadyToHarvest) {
+            GrowthStage::ReadyToHarvest
+        } else if days >= self.stage_start_day(GrowthStage::Flowering) {
+            GrowthStage::Flowering
+        } else if days >= self.stage_start_day(GrowthStage::PreFlower) {
+            GrowthStage::PreFlower
+        } else if days >= self.stage_start_day(GrowthStage::Vegetative) {
+            GrowthStage::Vegetative
+        } else if days >= self.stage_start_day(GrowthStage::Seedling) {
+            GrowthStage::Seedling
+        } else {
+            GrowthStage::Germination
         }
     }
 
-    /// Calculate health based on current resource levels
-    pub fn calculate_health(water: f32, nutrients: f32) -> HealthStatus {
-        let water_optimal = water >= 40.0 && water <= 80.0;
-        let nutrient_optimal = nutrients >= 50.0 && nutrients <= 80.0;
+    /// How far the plant droops (0 = upright, 1 = fully wilted): what its
+    /// water and health pull it down to now, or the droop it is still
+    /// recovering from if that is more
+    pub fn wilt(&self) -> f32 {
+        self.wilt_pressure().max(self.droop)
+    }
 
-        let water_critical = water < 10.0 || water > 95.0;
-        let nutrient_critical = nutrients < 20.0 || nutrients > 95.0;
+    /// Droop the current conditions cause: thirst below `WILT_WATER`, roots
+    /// drowning past the overwatering grace period, or Poor/Critical health
+    fn wilt_pressure(&self) -> f32 {
+        let thirst = if self.water_level < WILT_WATER {
+            0.5 + 0.5 * (WILT_WATER - self.water_level.max(0.0)) / WILT_WATER
+        } else {
+            0.0
+        };
+        let drowning = if self.is_overwatered() {
+            0.5 + 0.5 * (self.water_level - OVERWATER_LEVEL).max(0.0) / (100.0 - OVERWATER_LEVEL)
+        } else {
+            0.0
+        };
+        let sickness = match self.health {
+            HealthStatus::Poor => 0.5,
+            HealthStatus::Critical => 1.0,
+            _ => 0.0,
+        };
+        thirst.max(drowning).max(sickness)
+    }
+
+    /// Let `hours` of game time pass for the droop: it follows the plant down
+    /// at once, but eases back up over `WILT_RECOVERY_HOURS` once put right
+    pub fn settle_wilt(&mut self, hours: f32) {
+        let recovered = self.droop - hours / WILT_RECOVERY_HOURS;
+        self.droop = self.wilt_pressure().max(recovered).clamp(0.0, 1.0);
+    }
+
+    /// Calculate health based on current resource levels and the difficulty's bands
+    pub fn calculate_health(water: f32, nutrients: f32, bands: &CareBands) -> HealthStatus {
+        let within = |band: (f32, f32), level: f32| (band.0..=band.1).contains(&level);
+        let water_optimal = bands.water_is_optimal(water);
+        let nutrient_optimal = bands.nutrients_are_optimal(nutrients);
+
+        let water_critical = !within(bands.water_survivable, water);
+        let nutrient_critical = !within(bands.nutrient_survivable, nutrients);
 
         if water_critical || nutrient_critical {
             HealthStatus::Critical
@@ -233,14 +531,228 @@ impl Plant {
             HealthStatus::Poor
         } else if !water_optimal || !nutrient_optimal {
             HealthStatus::Fair
-        } else if water >= 50.0 && water <= 70.0 && nutrients >= 60.0 && nutrients <= 75.0 {
+        } else if within(bands.water_excellent, water) && within(bands.nutrient_excellent, nutrients) {
             HealthStatus::Excellent
         } else {
             HealthStatus::Good
         }
     }
 
-    // Removed water() and feed() methods - plant is auto-managed now
+    /// Second half of flowering (and beyond), when terpenes are most fragile
+    pub fn is_late_flower(&self) -> bool {
+        let flower_start = self.stage_start_day(GrowthStage::Flowering);
+        let ready = self.stage_start_day(GrowthStage::ReadyToHarvest);
+        self.stage == GrowthStage::ReadyToHarvest
+            || (self.stage == GrowthStage::Flowering && self.days_alive >= (flower_start + ready) / 2)
+    }
+
+    /// Build terpenes during flowering
+    /// Low stress helps; in late flower a flush (low nutrients) and cool temps
+    /// boost them, while heat above the optimal band cooks them off
+    pub fn update_terpenes(&mut self, hours: f32) {
+        if !matches!(self.stage, GrowthStage::Flowering | GrowthStage::ReadyToHarvest) {
+            return;
+        }
+
+        let stress_factor = (1.0 - self.care_history.stress_events.len() as f32 * 0.05).max(0.5);
+        let mut gain = TERPENE_GAIN_PER_HOUR * stress_factor;
+
+        if self.is_late_flower() {
+            if self.nutrient_level < TERPENE_FLUSH_NUTRIENTS {
+                gain *= 1.2;
+            }
+            if self.temperature <= TERPENE_COOL_TEMP {
+                gain *= 1.2;
+            } else if self.temperature > TEMP_OPTIMAL_MAX {
+                gain = -TERPENE_HEAT_LOSS_PER_HOUR;
+            }
+        }
+
+        self.terpene_intensity = (self.terpene_intensity + gain * hours).clamp(0.0, 100.0);
+    }
+
+    /// Grow roots when watering is good; drought and drowning stall them and cause dieback
+    pub fn update_roots(&mut self, hours: f32, bands: &CareBands) {
+        let rate = if self.water_level < ROOT_DROUGHT_LEVEL || self.is_overwatered() {
+            -ROOT_DIEBACK_PER_HOUR
+        } else if bands.water_is_optimal(self.water_level) {
+            ROOT_GROWTH_PER_HOUR
+        } else {
+            ROOT_GROWTH_PER_HOUR * ROOT_ACCEPTABLE_FACTOR
+        };
+        self.root_development = (self.root_development + rate * hours).clamp(0.0, 100.0);
+    }
+
+    /// Roots have sat in water long enough to hurt growth
+    pub fn is_overwatered(&self) -> bool {
+        self.overwatered_hours >= OVERWATER_GRACE_HOURS
+    }
+
+    /// Record today's water/nutrient levels, once per game day
+    pub fn record_resource_sample(&mut self) {
+        if self.resource_history.back().is_some_and(|s| s.day == self.days_alive) {
+            return;
+        }
+
+        if self.resource_history.len() >= RESOURCE_HISTORY_LEN {
+            self.resource_history.pop_front();
+        }
+        self.resource_history.push_back(ResourceSample {
+            day: self.days_alive,
+            water_level: self.water_level,
+            nutrient_level: self.nutrient_level,
+        });
+    }
+
+    /// Water the plant (manual care)
+    pub fn water(&mut self) {
+        self.water_level = (self.water_level + 25.0).min(100.0);
+    }
+
+    /// Feed the plant nutrients (manual care)
+    pub fn feed(&mut self) {
+        self.nutrient_level = (self.nutrient_level + 20.0).min(100.0);
+    }
+
+    /// Adjust lamp intensity by a delta, clamped to 0-100
+    pub fn adjust_light(&mut self, delta: f32) {
+        self.light_intensity = (self.light_intensity + delta).clamp(0.0, 100.0);
+    }
+
+    /// Pull hand-edited or corrupted values back into range and
+    /// re-derive the day count and stage from elapsed game time
+    pub fn sanitize(&mut self) {
+        let percent = |v: f32| if v.is_finite() { v.clamp(0.0, 100.0) } else { 0.0 };
+        self.water_level = percent(self.water_level);
+        self.nutrient_level = percent(self.nutrient_level);
+        self.co2_level = percent(self.co2_level);
+        self.light_absorption = percent(self.light_absorption);
+        self.humidity = percent(self.humidity);
+        self.root_development = percent(self.root_development);
+        self.canopy_density = percent(self.canopy_density);
+        self.light_intensity = percent(self.light_intensity);
+        self.terpene_intensity = percent(self.terpene_intensity);
+        self.temperature = if self.temperature.is_finite() {
+            self.temperature.clamp(SANE_TEMP_MIN, SANE_TEMP_MAX)
+        } else {
+            TEMP_OPTIMAL_MIN
+        };
+
+        // Stage timing divides by growth rate, so keep it in the rolled range
+        self.genetics.growth_rate = if self.genetics.growth_rate.is_finite() {
+            self.genetics.growth_rate.clamp(0.9, 1.1)
+        } else {
+            1.0
+        };
+
+        if !self.total_hours_elapsed.is_finite() || self.total_hours_elapsed < 0.0 {
+            self.total_hours_elapsed = 0.0;
+        }
+        if !self.overwatered_hours.is_finite() || self.overwatered_hours < 0.0 {
+            self.overwatered_hours = 0.0;
+        }
+        self.droop = if self.droop.is_finite() { self.droop.clamp(0.0, 1.0) } else { 0.0 };
+        self.days_alive = (self.total_hours_elapsed / 24.0) as u32;
+        self.stage = self.calculate_stage(self.days_alive);
+    }
+
+    /// How ripe the buds are: climbs from 0.0 to 1.0 through flowering
+    /// Other stages count as ripe (only flowering plants can be cut early)
+    pub fn ripeness(&self) -> f32 {
+        if self.stage != GrowthStage::Flowering {
+            return 1.0;
+        }
+        let start = self.stage_start_day(GrowthStage::Flowering);
+        let ready = self.stage_start_day(GrowthStage::ReadyToHarvest);
+        (self.days_alive.saturating_sub(start) as f32 / ready.saturating_sub(start).max(1) as f32).min(1.0)
+    }
+
+    /// Whether the top colas can be cut now, leaving the rest to ripen
+    pub fn can_partial_harvest(&self) -> bool {
+        self.stage == GrowthStage::ReadyToHarvest && self.partial_harvest_day.is_none()
+    }
+
+    /// Share of the full yield still on the plant
+    pub fn remaining_share(&self) -> f32 {
+        if self.partial_harvest_day.is_some() {
+            1.0 - PARTIAL_HARVEST_SHARE
+        } else {
+            1.0
+        }
+    }
+
+    /// Quality bonus the lower buds earned ripening after a partial harvest
+    pub fn ripening_bonus(&self) -> f32 {
+        self.partial_harvest_day.map_or(0.0, |day| {
+            self.days_alive.saturating_sub(day).min(PARTIAL_RIPENING_DAYS) as f32 * PARTIAL_QUALITY_PER_DAY
+        })
+    }
+
+    /// Whether the lower buds have had their extra days and should come down
+    pub fn lower_buds_done(&self) -> bool {
+        self.partial_harvest_day
+            .is_some_and(|day| self.days_alive >= day + PARTIAL_RIPENING_DAYS)
+    }
+
+    /// Check if the plant is in the early-flowering stretch window
+    pub fn is_stretching(&self) -> bool {
+        self.stage == GrowthStage::Flowering
+            && self.days_alive < self.stage_start_day(GrowthStage::Flowering) + STRETCH_DAYS
+    }
+
+    /// Why CO2 enrichment is currently not helping, if anything
+    /// CO2 only boosts growth under strong light in a warm tent
+    pub fn co2_blocker(&self) -> Option<&'static str> {
+        let (light_min, light_max) = self.stage.optimal_light();
+        let light_upper = (light_min + light_max) / 2.0;
+        let temp_upper = (TEMP_OPTIMAL_MIN + TEMP_OPTIMAL_MAX) / 2.0;
+
+        if self.light_intensity < light_upper {
+            Some("needs more light")
+        } else if self.light_intensity > light_max {
+            Some("light too strong")
+        } else if self.temperature < temp_upper {
+            Some("tent too cold")
+        } else if self.temperature > TEMP_OPTIMAL_MAX {
+            Some("tent too hot")
+        } else {
+            None
+        }
+    }
+
+    /// Growth multiplier from CO2 (1.0 = no bonus, up to 1.0 + CO2_MAX_BONUS)
+    pub fn co2_growth_multiplier(&self, enriched: bool) -> f32 {
+        if !enriched || self.co2_blocker().is_some() {
+            return 1.0;
+        }
+
+        let excess = ((self.co2_level - CO2_AMBIENT) / (100.0 - CO2_AMBIENT)).clamp(0.0, 1.0);
+        1.0 + CO2_MAX_BONUS * excess
+    }
+
+    /// Day used for drawing the plant (days alive plus CO2 growth bonus)
+    pub fn growth_day(&self) -> u32 {
+        self.days_alive + (self.co2_bonus_hours / 24.0) as u32
+    }
+
+    /// Yield multiplier from CO2: the average growth bonus over the grow so far
+    pub fn co2_yield_multiplier(&self) -> f32 {
+        if self.total_hours_elapsed <= 0.0 {
+            return 1.0;
+        }
+        1.0 + (self.co2_bonus_hours / self.total_hours_elapsed).clamp(0.0, CO2_MAX_BONUS)
+    }
+
+    /// Seed of the plant's own climate curve, taken from its id so a saved
+    /// plant keeps its weather
+    pub fn climate_seed(&self) -> u64 {
+        self.id.as_u128() as u64
+    }
+
+    /// Check if the buds are currently being bleached by light burn
+    pub fn is_light_burned(&self) -> bool {
+        self.care_history.has_recent_stress(StressCause::LightBurn, self.days_alive)
+    }
 
     /// Toggle light cycle
     pub fn toggle_light_cycle(&mut self) {
@@ -250,3 +762,188 @@ impl Plant {
         };
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Difficulty;
+    use crate::test_support;
+
+    fn enriched_plant() -> Plant {
+        let mut plant = test_support::plant();
+        plant.stage = GrowthStage::Flowering;
+        plant.co2_level = 100.0;
+        plant.light_intensity = 90.0;
+        plant.temperature = 26.0;
+        plant
+    }
+
+    fn late_flower_plant(temperature: f32) -> Plant {
+        let mut plant = test_support::plant();
+        plant.care_history = CareHistory::default();
+        plant.genetics.growth_rate = 1.0;
+        plant.stage = GrowthStage::Flowering;
+        plant.days_alive = 80;
+        plant.nutrient_level = 60.0;
+        plant.temperature = temperature;
+        plant.terpene_intensity = 50.0;
+        plant
+    }
+
+    #[test]
+    fn late_flower_heat_reduces_terpenes() {
+        let mut cool = late_flower_plant(22.0);
+        let mut hot = late_flower_plant(31.0);
+        assert!(hot.is_late_flower());
+
+        cool.update_terpenes(48.0);
+        hot.update_terpenes(48.0);
+
+        assert!(cool.terpene_intensity > 50.0);
+        assert!(hot.terpene_intensity < 50.0);
+    }
+
+    #[test]
+    fn fast_genetics_flower_sooner() {
+        let mut plant = test_support::plant();
+        plant.genetics.strain_info = None;
+        plant.genetics.growth_rate = 1.0;
+        assert_eq!(plant.stage_start_day(GrowthStage::Flowering), 49);
+        assert_eq!(plant.calculate_stage(48), GrowthStage::PreFlower);
+
+        plant.genetics.growth_rate = 1.1;
+        assert_eq!(plant.stage_start_day(GrowthStage::Flowering), 45);
+        assert_eq!(plant.calculate_stage(48), GrowthStage::Flowering);
+
+        plant.genetics.growth_rate = 0.9;
+        assert_eq!(plant.stage_start_day(GrowthStage::ReadyToHarvest), 96);
+        assert_eq!(plant.calculate_stage(90), GrowthStage::Flowering);
+    }
+
+    #[test]
+    fn short_flowering_strains_finish_sooner() {
+        let mut plant = test_support::plant();
+        plant.genetics.growth_rate = 1.0;
+        let mut strain = plant.genetics.strain_info.clone().expect("the fixture plant has a strain");
+
+        strain.flowering_time = 56;
+        plant.genetics.strain_info = Some(strain.clone());
+        let fast = plant.stage_start_day(GrowthStage::ReadyToHarvest);
+
+        strain.flowering_time = 70;
+        plant.genetics.strain_info = Some(strain);
+        let slow = plant.stage_start_day(GrowthStage::ReadyToHarvest);
+
+        assert!(fast < slow, "56-day strain ready on day {}, 70-day on day {}", fast, slow);
+        assert_eq!(plant.stage_start_day(GrowthStage::Flowering), 49, "only flowering is stretched");
+        assert_eq!(plant.calculate_stage(slow - 1), GrowthStage::Flowering);
+        assert_eq!(plant.calculate_stage(slow), GrowthStage::ReadyToHarvest);
+    }
+
+    #[test]
+    fn co2_bonus_requires_enrichment() {
+        let plant = enriched_plant();
+        assert_eq!(plant.co2_growth_multiplier(false), 1.0);
+        assert!((plant.co2_growth_multiplier(true) - (1.0 + CO2_MAX_BONUS)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn co2_bonus_needs_strong_light() {
+        let mut plant = enriched_plant();
+        plant.light_intensity = 66.0; // In band, but lower half
+        assert_eq!(plant.co2_growth_multiplier(true), 1.0);
+        assert_eq!(plant.co2_blocker(), Some("needs more light"));
+    }
+
+    #[test]
+    fn co2_bonus_needs_warm_tent() {
+        let mut plant = enriched_plant();
+        plant.temperature = 21.0;
+        assert_eq!(plant.co2_growth_multiplier(true), 1.0);
+        assert_eq!(plant.co2_blocker(), Some("tent too cold"));
+    }
+
+    #[test]
+    fn co2_bonus_scales_with_level() {
+        let mut plant = enriched_plant();
+        plant.co2_level = 90.0;
+        let half = plant.co2_growth_multiplier(true);
+        assert!((half - (1.0 + CO2_MAX_BONUS / 2.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn thirst_and_sickness_wilt_the_plant() {
+        let mut plant = enriched_plant();
+        plant.water_level = 60.0;
+        plant.health = HealthStatus::Good;
+        assert_eq!(plant.wilt(), 0.0);
+
+        plant.water_level = 20.0;
+        let thirsty = plant.wilt();
+        plant.water_level = 0.0;
+        assert!(thirsty >= 0.5 && plant.wilt() > thirsty, "the drier, the limper");
+
+        plant.water_level = 60.0;
+        plant.health = HealthStatus::Critical;
+        assert_eq!(plant.wilt(), 1.0);
+    }
+
+    #[test]
+    fn drowning_roots_droop_the_plant() {
+        let mut plant = enriched_plant();
+        plant.health = HealthStatus::Good;
+        plant.water_level = 98.0;
+        plant.overwatered_hours = OVERWATER_GRACE_HOURS / 2.0;
+        assert_eq!(plant.wilt(), 0.0, "a soaking within the grace period is fine");
+
+        plant.overwatered_hours = OVERWATER_GRACE_HOURS;
+        assert!(plant.wilt() > 0.5);
+    }
+
+    #[test]
+    fn a_wilted_plant_stands_back_up_within_a_day() {
+        let mut plant = enriched_plant();
+        plant.health = HealthStatus::Good;
+        plant.water_level = 5.0;
+        plant.settle_wilt(1.0);
+        let thirsty = plant.wilt();
+
+        // Watered back into the band, the plant eases up over the next day
+        plant.water_level = 60.0;
+        plant.settle_wilt(6.0);
+        let recovering = plant.wilt();
+        assert!(recovering > 0.0 && recovering < thirsty, "eases back instead of snapping upright");
+        plant.settle_wilt(WILT_RECOVERY_HOURS);
+        assert_eq!(plant.wilt(), 0.0);
+    }
+
+    #[test]
+    fn difficulty_decides_how_precise_care_must_be() {
+        let health = |difficulty: Difficulty| Plant::calculate_health(45.0, 55.0, difficulty.bands());
+        assert_eq!(health(Difficulty::Easy), HealthStatus::Excellent);
+        assert_eq!(health(Difficulty::Normal), HealthStatus::Good);
+        assert_eq!(health(Difficulty::Hard), HealthStatus::Poor);
+    }
+
+    #[test]
+    fn roots_follow_watering() {
+        let mut watered = test_support::plant();
+        let mut neglected = watered.clone();
+        watered.water_level = 60.0;
+        neglected.water_level = 10.0;
+
+        let bands = Difficulty::Normal.bands();
+        for _ in 0..(30 * 24) {
+            watered.update_roots(1.0, bands);
+            neglected.update_roots(1.0, bands);
+        }
+
+        assert!(watered.root_development > 40.0);
+        assert!(neglected.root_development < 10.0);
+
+        for _ in 0..(60 * 24) {
+            watered.update_roots(1.0, bands);
+        }
+        assert_eq!(watered.root_development, 100.0);
+    }
+}
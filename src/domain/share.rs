@@ -0,0 +1,383 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::breeding::{Lineage, Seed, GROWTH_RATE_RANGE, QUALITY_CEILING_RANGE, RESILIENCE_RANGE, YIELD_RANGE};
+use super::genetics::{Genetics, Mutation, Rarity, StrainInfo};
+use super::plant::Plant;
+use super::rng::random_uuid;
+use super::strains::{self, FLOWERING_DAYS};
+
+/// Share code format written by this build (first byte of every code)
+pub const CODE_VERSION: u8 = 1;
+
+/// Every share code starts with this, so stray text is rejected early
+const CODE_PREFIX: &str = "GTUI-";
+
+/// Longest code accepted (a shared hybrid is well under this)
+const MAX_CODE_LEN: usize = 4096;
+
+/// Longest plant or strain name kept from a code
+const MAX_NAME_LEN: usize = 40;
+
+/// Most terpenes/aromas/effects kept from a code
+const MAX_TRAITS: usize = 4;
+
+/// Highest THC/CBD (%) a shared plant may carry
+const MAX_THC: f32 = 35.0;
+const MAX_CBD: f32 = 25.0;
+
+/// Why a pasted code could not be imported
+#[derive(Debug, Clone, PartialEq)]
+pub enum CodeError {
+    Invalid,          // Not a code, cut short or mistyped
+    NewerVersion(u8), // Made by a newer build
+}
+
+impl fmt::Display for CodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CodeError::Invalid => write!(f, "invalid code - check it was copied completely"),
+            CodeError::NewerVersion(v) => {
+                write!(f, "code is version {} - update ganjatui to import it", v)
+            }
+        }
+    }
+}
+
+/// A plant's genetics in a form that can be passed between players
+#[derive(Debug, Clone)]
+pub struct SharedPlant {
+    pub name: String,
+    pub genetics: Genetics,
+    pub lineage: Option<Lineage>,
+}
+
+/// Wire form of a shared plant (short keys keep codes compact)
+#[derive(Serialize, Deserialize)]
+struct Payload {
+    #[serde(rename = "n")]
+    name: String,
+    #[serde(rename = "s", default)]
+    strain: Option<SharedStrain>,
+    #[serde(rename = "y")]
+    yield_potential: f32,
+    #[serde(rename = "g")]
+    growth_rate: f32,
+    #[serde(rename = "r")]
+    resilience: f32,
+    #[serde(rename = "q")]
+    quality_ceiling: f32,
+    #[serde(rename = "t")]
+    thc_percent: f32,
+    #[serde(rename = "c")]
+    cbd_percent: f32,
+    #[serde(rename = "k", default)]
+    rarity: Rarity,
+    #[serde(rename = "h", default)]
+    hardy: bool,
+    #[serde(rename = "m", default)]
+    mutation: Option<Mutation>,
+    #[serde(rename = "l", default)]
+    lineage: Option<Lineage>,
+}
+
+/// Database strains travel by name; bred hybrids carry their blended info
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum SharedStrain {
+    Known(String),
+    Custom(Box<StrainInfo>),
+}
+
+impl SharedPlant {
+    pub fn from_plant(plant: &Plant) -> Self {
+        Self {
+            name: plant.strain_name.clone(),
+            genetics: plant.genetics.clone(),
+            lineage: plant.lineage.clone(),
+        }
+    }
+
+    /// Encode as `GTUI-` followed by base64 of version, payload and checksum
+    pub fn encode(&self) -> String {
+        let g = &self.genetics;
+        let strain = g.strain_info.as_ref().map(|info| {
+            let known = strains::database().strains.iter().any(|s| s.name == info.name);
+            if known {
+                SharedStrain::Known(info.name.clone())
+            } else {
+                SharedStrain::Custom(Box::new(info.clone()))
+            }
+        });
+        let payload = Payload {
+            name: self.name.clone(),
+            strain,
+            yield_potential: g.yield_potential,
+            growth_rate: g.growth_rate,
+            resilience: g.resilience,
+            quality_ceiling: g.quality_ceiling,
+            thc_percent: g.thc_percent,
+            cbd_percent: g.cbd_percent,
+            rarity: g.rarity,
+            hardy: g.hardy,
+            mutation: g.mutation,
+            lineage: self.lineage.clone(),
+        };
+
+        let mut bytes = vec![CODE_VERSION];
+        bytes.extend(serde_json::to_vec(&payload).unwrap_or_default());
+        let checksum = fnv1a(&bytes);
+        bytes.extend(checksum.to_le_bytes());
+        format!("{}{}", CODE_PREFIX, base64_encode(&bytes))
+    }
+
+    /// Decode a pasted code; whitespace (line wraps) is ignored and every
+    /// stat is clamped to what the game could have rolled
+    pub fn decode(code: &str) -> Result<Self, CodeError> {
+        let code: String = code.chars().filter(|c| !c.is_whitespace()).collect();
+        if code.len() > MAX_CODE_LEN {
+            return Err(CodeError::Invalid);
+        }
+        let body = code.strip_prefix(CODE_PREFIX).ok_or(CodeError::Invalid)?;
+        let bytes = base64_decode(body).ok_or(CodeError::Invalid)?;
+        if bytes.len() < 5 {
+            return Err(CodeError::Invalid);
+        }
+
+        let (data, checksum) = bytes.split_at(bytes.len() - 4);
+        if fnv1a(data).to_le_bytes() != checksum {
+            return Err(CodeError::Invalid);
+        }
+        let version = data[0];
+        if version > CODE_VERSION {
+            return Err(CodeError::NewerVersion(version));
+        }
+        let payload: Payload = serde_json::from_slice(&data[1..]).map_err(|_| CodeError::Invalid)?;
+        Ok(Self::from_payload(payload))
+    }
+
+    /// Turn the shared plant into a seed for the inventory
+    pub fn into_seed<R: Rng>(self, rng: &mut R) -> Seed {
+        Seed {
+            id: random_uuid(rng),
+            strain_name: self.name,
+            genetics: self.genetics,
+            lineage: self.lineage,
+        }
+    }
+
+    fn from_payload(p: Payload) -> Self {
+        let strain_info = match p.strain {
+            // A strain missing from this player's database grows generic
+            Some(SharedStrain::Known(name)) => strains::database().strains.iter().find(|s| s.name == name).cloned(),
+            Some(SharedStrain::Custom(info)) => Some(legal_strain(*info)),
+            None => None,
+        };
+        let genetics = Genetics {
+            yield_potential: legal(p.yield_potential, YIELD_RANGE),
+            growth_rate: legal(p.growth_rate, GROWTH_RATE_RANGE),
+            resilience: legal(p.resilience, RESILIENCE_RANGE),
+            quality_ceiling: legal(p.quality_ceiling, QUALITY_CEILING_RANGE),
+            strain_info,
+            thc_percent: legal(p.thc_percent, 0.0..=MAX_THC),
+            cbd_percent: legal(p.cbd_percent, 0.0..=MAX_CBD),
+            rarity: p.rarity,
+            hardy: p.hardy,
+            mutation: p.mutation,
+        };
+        let lineage = p.lineage.map(|l| Lineage {
+            mother: legal_name(&l.mother),
+            father: legal_name(&l.father),
+        });
+
+        Self {
+            name: legal_name(&p.name),
+            genetics,
+            lineage,
+        }
+    }
+}
+
+/// Clamp a shared stat into range (NaN takes the minimum)
+fn legal(value: f32, range: RangeInclusive<f32>) -> f32 {
+    if value.is_nan() {
+        *range.start()
+    } else {
+        value.clamp(*range.start(), *range.end())
+    }
+}
+
+/// Trimmed, length-capped name (blank names get a placeholder)
+fn legal_name(name: &str) -> String {
+    let name: String = name.trim().chars().take(MAX_NAME_LEN).collect();
+    if name.is_empty() {
+        "Shared Strain".to_string()
+    } else {
+        name
+    }
+}
+
+/// Keep a shared hybrid's strain info within what a strain file may contain
+fn legal_strain(info: StrainInfo) -> StrainInfo {
+    let short = |list: Vec<String>| -> Vec<String> {
+        list.into_iter().take(MAX_TRAITS).map(|s| s.chars().take(MAX_NAME_LEN).collect()).collect()
+    };
+    let thc_min = legal(info.thc_min, 0.0..=MAX_THC);
+    let cbd_min = legal(info.cbd_min, 0.0..=MAX_CBD);
+    StrainInfo {
+        name: legal_name(&info.name),
+        thc_min,
+        thc_max: legal(info.thc_max, thc_min..=MAX_THC),
+        cbd_min,
+        cbd_max: legal(info.cbd_max, cbd_min..=MAX_CBD),
+        flowering_time: info.flowering_time.clamp(*FLOWERING_DAYS.start(), *FLOWERING_DAYS.end()),
+        dominant_terpenes: short(info.dominant_terpenes),
+        aroma: short(info.aroma),
+        effects: short(info.effects),
+        ..info
+    }
+}
+
+/// 32-bit FNV-1a, enough to catch typos and truncated pastes
+fn fnv1a(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0x811c_9dc5, |hash, &b| (hash ^ b as u32).wrapping_mul(0x0100_0193))
+}
+
+/// URL-safe base64 alphabet (no `+` or `/`, so codes survive chat apps)
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Base64 without padding
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk.iter().enumerate().fold(0u32, |n, (i, &b)| n | (b as u32) << (16 - 8 * i));
+        for i in 0..=chunk.len() {
+            out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+        }
+    }
+    out
+}
+
+/// Inverse of `base64_encode`; None on any character outside the alphabet
+fn base64_decode(text: &str) -> Option<Vec<u8>> {
+    if text.len() % 4 == 1 {
+        return None;
+    }
+    let mut out = Vec::with_capacity(text.len() * 3 / 4);
+    for chunk in text.as_bytes().chunks(4) {
+        let mut n = 0u32;
+        for (i, &c) in chunk.iter().enumerate() {
+            let value = ALPHABET.iter().position(|&a| a == c)? as u32;
+            n |= value << (18 - 6 * i);
+        }
+        for i in 0..chunk.len() - 1 {
+            out.push((n >> (16 - 8 * i)) as u8);
+        }
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+
+    fn shared() -> SharedPlant {
+        SharedPlant::from_plant(&test_support::plant())
+    }
+
+    #[test]
+    fn base64_round_trips_every_length() {
+        for len in 0..10 {
+            let bytes: Vec<u8> = (0..len).map(|i| (i * 37 + 250) as u8).collect();
+            assert_eq!(base64_decode(&base64_encode(&bytes)), Some(bytes));
+        }
+        assert_eq!(base64_decode("abc!"), None);
+    }
+
+    #[test]
+    fn codes_round_trip() {
+        for seed in 0..20 {
+            let plant = SharedPlant::from_plant(&test_support::plant_from(seed));
+            let code = plant.encode();
+            let back = SharedPlant::decode(&code).unwrap();
+
+            assert_eq!(back.name, plant.name);
+            assert_eq!(back.genetics.yield_potential, plant.genetics.yield_potential);
+            assert_eq!(back.genetics.thc_percent, plant.genetics.thc_percent);
+            assert_eq!(back.genetics.rarity, plant.genetics.rarity);
+            assert_eq!(back.genetics.mutation, plant.genetics.mutation);
+            assert_eq!(
+                back.genetics.strain_info.map(|s| s.name),
+                plant.genetics.strain_info.map(|s| s.name)
+            );
+        }
+    }
+
+    #[test]
+    fn hybrids_carry_their_strain_info_and_lineage() {
+        let mut plant = shared();
+        let info = plant.genetics.strain_info.as_mut().unwrap();
+        info.name = "Northern Haze #3".to_string();
+        info.flowering_time = 58;
+        plant.name = info.name.clone();
+        plant.lineage = Some(Lineage { mother: "Northern Lights".to_string(), father: "Haze".to_string() });
+
+        // Copied out of a wrapped popup: line breaks and indentation
+        let code = plant.encode();
+        let wrapped: String = code.as_bytes().chunks(30).map(|c| format!("  {}\n", String::from_utf8_lossy(c))).collect();
+        let back = SharedPlant::decode(&wrapped).unwrap();
+
+        assert_eq!(back.genetics.strain_info.unwrap().flowering_time, 58);
+        assert_eq!(back.lineage, plant.lineage);
+    }
+
+    #[test]
+    fn garbage_is_an_invalid_code() {
+        let code = shared().encode();
+        assert_eq!(SharedPlant::decode("").unwrap_err(), CodeError::Invalid);
+        assert_eq!(SharedPlant::decode("hello there").unwrap_err(), CodeError::Invalid);
+        assert_eq!(SharedPlant::decode(&code[..code.len() - 6]).unwrap_err(), CodeError::Invalid);
+
+        // One flipped character fails the checksum
+        let mut typo = code.into_bytes();
+        let i = typo.len() / 2;
+        typo[i] = if typo[i] == b'A' { b'B' } else { b'A' };
+        assert_eq!(SharedPlant::decode(&String::from_utf8(typo).unwrap()).unwrap_err(), CodeError::Invalid);
+    }
+
+    #[test]
+    fn newer_codes_ask_for_an_update() {
+        let mut bytes = vec![CODE_VERSION + 1];
+        bytes.extend(b"{}");
+        bytes.extend(fnv1a(&bytes).to_le_bytes());
+        let code = format!("{}{}", CODE_PREFIX, base64_encode(&bytes));
+        assert_eq!(SharedPlant::decode(&code).unwrap_err(), CodeError::NewerVersion(CODE_VERSION + 1));
+    }
+
+    #[test]
+    fn forged_monsters_are_clamped() {
+        let payload = r#"{"n":"  ","y":500,"g":3,"r":-1,"q":1000,"t":200,"c":1e40,"k":"Legendary",
+            "s":{"name":"Monster","thc_min":90,"thc_max":10,"flowering_time":5}}"#;
+        let mut bytes = vec![CODE_VERSION];
+        bytes.extend(payload.as_bytes());
+        bytes.extend(fnv1a(&bytes).to_le_bytes());
+        let code = format!("{}{}", CODE_PREFIX, base64_encode(&bytes));
+
+        let plant = SharedPlant::decode(&code).unwrap();
+        let g = &plant.genetics;
+        assert_eq!(plant.name, "Shared Strain");
+        assert_eq!(g.yield_potential, 150.0);
+        assert_eq!(g.growth_rate, 1.1);
+        assert_eq!(g.resilience, 0.0);
+        assert_eq!(g.quality_ceiling, 100.0);
+        assert_eq!(g.thc_percent, MAX_THC);
+        assert_eq!(g.cbd_percent, MAX_CBD);
+        let info = g.strain_info.as_ref().unwrap();
+        assert!(info.thc_min <= info.thc_max && info.thc_max <= MAX_THC);
+        assert_eq!(info.flowering_time, *FLOWERING_DAYS.start());
+    }
+}
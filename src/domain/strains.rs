@@ -0,0 +1,524 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock, RwLock};
+
+use chrono::{Datelike, NaiveDate};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+
+use super::genetics::StrainInfo;
+
+/// Default strain database, compiled into the binary
+const EMBEDDED_STRAINS: &str = include_str!("../../strains.json");
+
+/// Environment variable pointing at a custom strain database
+pub const STRAINS_ENV: &str = "GANJATUI_STRAINS";
+
+/// Accepted flowering time range in days; anything outside is a typo
+pub const FLOWERING_DAYS: std::ops::RangeInclusive<u32> = 40..=120;
+
+/// Strain database, loaded once per process and swapped on reload
+static STRAINS: OnceLock<RwLock<Arc<StrainDatabase>>> = OnceLock::new();
+
+/// Strain database path given on the command line (`--strains`)
+static STRAINS_PATH: OnceLock<PathBuf> = OnceLock::new();
+
+/// Where the strain database was loaded from
+#[derive(Debug, Clone, PartialEq)]
+pub enum StrainSource {
+    Embedded,
+    UserFile(PathBuf),
+}
+
+impl fmt::Display for StrainSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StrainSource::Embedded => write!(f, "embedded"),
+            StrainSource::UserFile(path) => write!(f, "user file: {}", path.display()),
+        }
+    }
+}
+
+/// Loaded strains plus where they came from and any problems found on the way
+#[derive(Debug, Clone)]
+pub struct StrainDatabase {
+    pub strains: Vec<StrainInfo>,
+    /// Base database the strain packs were merged on top of
+    pub source: StrainSource,
+    /// Files merged on top of the base (other legacy files, then `strains.d`
+    /// packs), in merge order
+    pub packs: Vec<PathBuf>,
+    /// Files that were found but could not be read or parsed
+    pub errors: Vec<String>,
+    /// Entries that were skipped because they failed validation
+    pub warnings: Vec<String>,
+    /// Strains replaced by a later file with the same name
+    pub overrides: Vec<String>,
+}
+
+/// Why a strain file could not be loaded
+#[derive(Debug)]
+pub enum StrainLoadError {
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    Parse {
+        path: PathBuf,
+        line: usize,
+        column: usize,
+        /// Index and name of the entry that failed, when it can be found
+        entry: Option<String>,
+        /// Field of that entry holding the wrong kind of value
+        field: Option<String>,
+        message: String,
+    },
+}
+
+impl fmt::Display for StrainLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StrainLoadError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            StrainLoadError::Parse { path, line, column, entry, field, message } => {
+                write!(f, "{}:{}:{}: ", path.display(), line, column)?;
+                if let Some(entry) = entry {
+                    write!(f, "{}: ", entry)?;
+                }
+                if let Some(field) = field {
+                    write!(f, "field `{}`: ", field)?;
+                }
+                write!(f, "{}", message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for StrainLoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StrainLoadError::Io { source, .. } => Some(source),
+            StrainLoadError::Parse { .. } => None,
+        }
+    }
+}
+
+/// Use a custom strain database file (from `--strains`)
+/// Must be called before the first plant is created
+pub fn set_cli_path(path: PathBuf) {
+    let _ = STRAINS_PATH.set(path);
+}
+
+/// Strain database, loaded on first use and cached
+/// Callers get a snapshot; a reload swaps in a new one without disturbing them
+pub fn database() -> Arc<StrainDatabase> {
+    let lock = STRAINS.get_or_init(|| RwLock::new(Arc::new(load_database(STRAINS_PATH.get().map(PathBuf::as_path)))));
+    lock.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Re-read every strain file, replacing the cached database
+pub fn reload() -> Arc<StrainDatabase> {
+    let db = Arc::new(load_database(STRAINS_PATH.get().map(PathBuf::as_path)));
+    match STRAINS.get() {
+        Some(lock) => *lock.write().unwrap_or_else(|e| e.into_inner()) = db.clone(),
+        None => {
+            let _ = STRAINS.set(RwLock::new(db.clone()));
+        }
+    }
+    db
+}
+
+/// Directory of strain packs merged on top of the base database
+pub fn packs_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("ganjatui").join("strains.d"))
+}
+
+/// Build the strain database. An explicit `--strains` path or
+/// `GANJATUI_STRAINS` is used on its own. Otherwise every legacy single file
+/// is used: `strains.json` in the working directory is the base, with
+/// `~/.config/ganjatui/strains.json` merged on top (the embedded database
+/// stands in when neither exists). Every `strains.d/*.json` pack is then
+/// merged in file-name order, later files overriding strains of the same
+/// name. Broken user files are reported in `errors` and invalid entries in
+/// `warnings` instead of being skipped silently; `source` says whether the
+/// embedded database stood in.
+pub fn load_database(cli_path: Option<&Path>) -> StrainDatabase {
+    let explicit = cli_path
+        .map(Path::to_path_buf)
+        .or_else(|| std::env::var_os(STRAINS_ENV).map(PathBuf::from));
+    if let Some(path) = explicit {
+        return load_from(vec![(path, true)], None);
+    }
+
+    let mut candidates: Vec<(PathBuf, bool)> = vec![(PathBuf::from("strains.json"), false)];
+    if let Some(dir) = dirs::config_dir() {
+        candidates.push((dir.join("ganjatui").join("strains.json"), false));
+    }
+
+    load_from(candidates, packs_dir().as_deref())
+}
+
+/// Load every usable candidate in merge order (the first is the base, or the
+/// embedded database if none loads), then merge packs
+fn load_from(candidates: Vec<(PathBuf, bool)>, packs_dir: Option<&Path>) -> StrainDatabase {
+    let mut errors = Vec::new();
+    let mut warnings = Vec::new();
+    let mut found = Vec::new();
+
+    for (path, required) in candidates {
+        match load(&path) {
+            Ok(strains) => {
+                let (strains, skipped) = validate(strains, &path.display().to_string());
+                warnings.extend(skipped);
+                if strains.is_empty() {
+                    errors.push(format!("{}: no valid strains", path.display()));
+                    continue;
+                }
+                found.push((path, strains));
+            }
+            Err(StrainLoadError::Io { ref source, .. })
+                if !required && source.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => errors.push(e.to_string()),
+        }
+    }
+
+    let mut found = found.into_iter();
+    let (strains, source) = match found.next() {
+        Some((path, strains)) => (strains, StrainSource::UserFile(path)),
+        None => (embedded(), StrainSource::Embedded),
+    };
+    let mut db = StrainDatabase {
+        strains,
+        source,
+        packs: Vec::new(),
+        errors,
+        warnings,
+        overrides: Vec::new(),
+    };
+
+    // Name of the file each strain last came from, for collision messages
+    let mut origins: Vec<String> = vec![db.source.to_string(); db.strains.len()];
+    for (path, strains) in found {
+        merge(&mut db, &mut origins, path, strains);
+    }
+    if let Some(dir) = packs_dir {
+        merge_packs(&mut db, &mut origins, dir);
+    }
+    db
+}
+
+/// Merge every `*.json` file in `dir` into the database, sorted by file name
+fn merge_packs(db: &mut StrainDatabase, origins: &mut Vec<String>, dir: &Path) {
+    let mut files: Vec<PathBuf> = match std::fs::read_dir(dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .collect(),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return,
+        Err(e) => {
+            db.errors.push(format!("{}: {}", dir.display(), e));
+            return;
+        }
+    };
+    files.sort();
+
+    for path in files {
+        let strains = match load(&path) {
+            Ok(strains) => strains,
+            Err(e) => {
+                db.errors.push(e.to_string());
+                continue;
+            }
+        };
+        let (strains, skipped) = validate(strains, &path.display().to_string());
+        db.warnings.extend(skipped);
+        merge(db, origins, path, strains);
+    }
+}
+
+/// Merge one file's strains into the database, overriding strains of the same name
+fn merge(db: &mut StrainDatabase, origins: &mut Vec<String>, path: PathBuf, strains: Vec<StrainInfo>) {
+    let file_name = path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+    for strain in strains {
+        match db.strains.iter().position(|s| s.name.eq_ignore_ascii_case(&strain.name)) {
+            Some(i) => {
+                db.overrides.push(format!("{}: {} overrides {}", file_name, strain.name, origins[i]));
+                db.strains[i] = strain;
+                origins[i] = file_name.clone();
+            }
+            None => {
+                db.strains.push(strain);
+                origins.push(file_name.clone());
+            }
+        }
+    }
+    db.packs.push(path);
+}
+
+/// Read and parse a strain file; the error names the file and, for bad JSON,
+/// the line, column and entry that failed
+pub fn load(path: &Path) -> Result<Vec<StrainInfo>, StrainLoadError> {
+    let content = std::fs::read_to_string(path).map_err(|source| StrainLoadError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    parse(path, &content)
+}
+
+/// Parse strain JSON, pointing at the failing entry when one doesn't match the schema
+pub fn parse(path: &Path, content: &str) -> Result<Vec<StrainInfo>, StrainLoadError> {
+    serde_json::from_str(content).map_err(|e| {
+        let (entry, field) = match failing_entry(content) {
+            Some((entry, field)) => (Some(entry), field),
+            None => (None, None),
+        };
+        StrainLoadError::Parse {
+            path: path.to_path_buf(),
+            line: e.line(),
+            column: e.column(),
+            entry,
+            field,
+            message: e.to_string(),
+        }
+    })
+}
+
+/// Re-parse loosely to find which array entry (and field) has the wrong shape
+fn failing_entry(content: &str) -> Option<(String, Option<String>)> {
+    let entries: Vec<serde_json::Value> = serde_json::from_str(content).ok()?;
+    entries.iter().enumerate().find_map(|(i, value)| {
+        serde_json::from_value::<StrainInfo>(value.clone()).err()?;
+        let entry = match value.get("name").and_then(|n| n.as_str()) {
+            Some(name) => format!("entry {} ({})", i, name),
+            None => format!("entry {}", i),
+        };
+        Some((entry, failing_field(value)))
+    })
+}
+
+/// First field that fails to parse on its own (every field has a default)
+fn failing_field(entry: &serde_json::Value) -> Option<String> {
+    entry.as_object()?.iter().find_map(|(key, value)| {
+        let alone = serde_json::json!({ key: value });
+        serde_json::from_value::<StrainInfo>(alone).err().map(|_| key.clone())
+    })
+}
+
+/// Drop entries with impossible values, returning the kept strains and a warning per skip
+pub fn validate(strains: Vec<StrainInfo>, origin: &str) -> (Vec<StrainInfo>, Vec<String>) {
+    let mut warnings = Vec::new();
+    let valid = strains
+        .into_iter()
+        .enumerate()
+        .filter(|(i, strain)| match problem(strain) {
+            Some(problem) => {
+                let label = if strain.name.trim().is_empty() {
+                    format!("entry {}", i)
+                } else {
+                    format!("entry {} ({})", i, strain.name)
+                };
+                warnings.push(format!("{}: {}: {} - skipped", origin, label, problem));
+                false
+            }
+            None => true,
+        })
+        .map(|(_, strain)| strain)
+        .collect();
+    (valid, warnings)
+}
+
+/// First thing wrong with a strain entry, if any
+fn problem(strain: &StrainInfo) -> Option<String> {
+    if strain.name.trim().is_empty() {
+        return Some("missing name".to_string());
+    }
+    if strain.thc_min > strain.thc_max {
+        return Some(format!("thc_min {} is above thc_max {}", strain.thc_min, strain.thc_max));
+    }
+    if strain.cbd_min > strain.cbd_max {
+        return Some(format!("cbd_min {} is above cbd_max {}", strain.cbd_min, strain.cbd_max));
+    }
+    if !FLOWERING_DAYS.contains(&strain.flowering_time) {
+        return Some(format!(
+            "flowering_time {} is outside {}-{} days",
+            strain.flowering_time,
+            FLOWERING_DAYS.start(),
+            FLOWERING_DAYS.end()
+        ));
+    }
+    None
+}
+
+/// Parse the strain database embedded in the binary
+pub fn embedded() -> Vec<StrainInfo> {
+    serde_json::from_str(EMBEDDED_STRAINS).unwrap_or_default()
+}
+
+/// Featured strain for a calendar day; the same date always picks the same strain
+pub fn strain_of_the_day(date: NaiveDate, strains: &[StrainInfo]) -> Option<&StrainInfo> {
+    if strains.is_empty() {
+        return None;
+    }
+    let mut rng = ChaCha12Rng::seed_from_u64(date.num_days_from_ce() as u64);
+    strains.get(rng.gen_range(0..strains.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strain(name: &str, thc_min: f32, thc_max: f32, flowering_time: u32) -> StrainInfo {
+        StrainInfo {
+            name: name.to_string(),
+            thc_min,
+            thc_max,
+            flowering_time,
+            ..StrainInfo::default()
+        }
+    }
+
+    #[test]
+    fn embedded_database_parses() {
+        let strains = parse(Path::new("strains.json"), EMBEDDED_STRAINS).expect("embedded strains.json should parse");
+        assert!(!strains.is_empty());
+        assert_eq!(embedded().len(), strains.len());
+
+        let (valid, warnings) = validate(strains, "embedded");
+        assert!(warnings.is_empty(), "{:?}", warnings);
+        assert_eq!(valid.len(), embedded().len());
+    }
+
+    #[test]
+    fn broken_user_file_is_reported() {
+        let path = std::env::temp_dir().join(format!("ganjatui-broken-strains-{}.json", std::process::id()));
+        std::fs::write(&path, "[{\"name\": ").unwrap();
+
+        let db = load_database(Some(&path));
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(db.errors.iter().any(|e| e.starts_with(&path.display().to_string())));
+        assert!(!db.strains.is_empty());
+    }
+
+    #[test]
+    fn a_broken_file_is_an_error_not_an_empty_list() {
+        let path = std::env::temp_dir().join(format!("ganjatui-bad-strains-{}.json", std::process::id()));
+        std::fs::write(&path, "[\n  {\"name\": \"Typo\", \"flowering_time\": 60,}\n]").unwrap();
+
+        let loaded = load(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        match loaded {
+            Err(StrainLoadError::Parse { path: failed, line, .. }) => {
+                assert_eq!(failed, path);
+                assert_eq!(line, 2);
+            }
+            other => panic!("expected a parse error, got {:?}", other.map(|strains| strains.len())),
+        }
+        assert!(matches!(load(Path::new("/nonexistent/strains.json")), Err(StrainLoadError::Io { .. })));
+    }
+
+    #[test]
+    fn missing_cli_file_is_reported() {
+        let path = Path::new("/nonexistent/ganjatui/strains.json");
+        let db = load_database(Some(path));
+        assert!(db.errors.iter().any(|e| e.contains("/nonexistent/ganjatui/strains.json")));
+    }
+
+    #[test]
+    fn parse_error_names_the_failing_entry() {
+        let json = "[\n  {\"name\": \"Good\"},\n  {\"name\": \"Bad\", \"thc_min\": \"lots\"}\n]";
+        let err = parse(Path::new("custom.json"), json).unwrap_err();
+        match err {
+            StrainLoadError::Parse { line, entry, field, .. } => {
+                assert_eq!(line, 3);
+                assert_eq!(entry.as_deref(), Some("entry 1 (Bad)"));
+                assert_eq!(field.as_deref(), Some("thc_min"));
+            }
+            other => panic!("expected parse error, got {}", other),
+        }
+    }
+
+    #[test]
+    fn missing_fields_use_defaults() {
+        let strains = parse(Path::new("custom.json"), r#"[{"name": "Bare", "flowering_time": 60}]"#).unwrap();
+        assert_eq!(strains[0].name, "Bare");
+        assert!(strains[0].aroma.is_empty());
+    }
+
+    #[test]
+    fn invalid_entries_are_skipped_with_warnings() {
+        let strains = vec![
+            strain("Good", 15.0, 20.0, 60),
+            strain("Backwards", 25.0, 20.0, 60),
+            strain("Forever", 15.0, 20.0, 400),
+            strain("  ", 15.0, 20.0, 60),
+        ];
+        let (valid, warnings) = validate(strains, "custom.json");
+
+        assert_eq!(valid.len(), 1);
+        assert_eq!(valid[0].name, "Good");
+        assert_eq!(warnings.len(), 3);
+        assert!(warnings[0].contains("entry 1 (Backwards)"));
+        assert!(warnings[1].contains("flowering_time 400"));
+        assert!(warnings[2].contains("missing name"));
+    }
+
+    #[test]
+    fn packs_merge_in_name_order_and_override() {
+        let dir = std::env::temp_dir().join(format!("ganjatui-strains-d-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let pack = |name: &str, body: &str| std::fs::write(dir.join(name), body).unwrap();
+        pack("10-classics.json", r#"[{"name": "Haze", "thc_max": 20, "flowering_time": 70},
+                                     {"name": "Skunk", "flowering_time": 56}]"#);
+        pack("20-remix.json", r#"[{"name": "haze", "thc_max": 25, "flowering_time": 80}]"#);
+        pack("notes.txt", "not a pack");
+
+        let db = load_from(Vec::new(), Some(&dir));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(db.packs.len(), 2);
+        assert!(db.packs[0].ends_with("10-classics.json"));
+        let haze = db.strains.iter().find(|s| s.name.eq_ignore_ascii_case("haze")).unwrap();
+        assert_eq!(haze.thc_max, 25.0);
+        assert_eq!(db.overrides, vec!["20-remix.json: haze overrides 10-classics.json".to_string()]);
+        assert_eq!(db.strains.len(), embedded().len() + 2);
+        assert!(db.errors.is_empty(), "{:?}", db.errors);
+    }
+
+    #[test]
+    fn every_legacy_file_is_merged() {
+        let dir = std::env::temp_dir().join(format!("ganjatui-legacy-strains-{}", std::process::id()));
+        let (cwd, config) = (dir.join("cwd"), dir.join("config"));
+        std::fs::create_dir_all(&cwd).unwrap();
+        std::fs::create_dir_all(&config).unwrap();
+        std::fs::write(cwd.join("strains.json"), r#"[{"name": "Haze", "flowering_time": 70},
+                                                   {"name": "Skunk", "flowering_time": 56}]"#).unwrap();
+        std::fs::write(config.join("strains.json"), r#"[{"name": "Haze", "flowering_time": 80}]"#).unwrap();
+
+        let candidates = vec![(cwd.join("strains.json"), false), (config.join("strains.json"), false)];
+        let db = load_from(candidates, None);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(db.source, StrainSource::UserFile(cwd.join("strains.json")));
+        assert_eq!(db.packs, vec![config.join("strains.json")]);
+        let names: Vec<_> = db.strains.iter().map(|s| (s.name.as_str(), s.flowering_time)).collect();
+        assert_eq!(names, [("Haze", 80), ("Skunk", 56)]);
+        assert_eq!(db.overrides.len(), 1, "{:?}", db.overrides);
+    }
+
+    #[test]
+    fn strain_of_the_day_is_stable_per_date() {
+        let strains = vec![strain("A", 10.0, 20.0, 60), strain("B", 10.0, 20.0, 60), strain("C", 10.0, 20.0, 60)];
+        let day = NaiveDate::from_ymd_opt(2026, 4, 20).unwrap();
+
+        let first = strain_of_the_day(day, &strains).unwrap();
+        assert_eq!(strain_of_the_day(day, &strains).unwrap().name, first.name);
+        assert!(strain_of_the_day(day, &[]).is_none());
+
+        // Over a month every strain should get featured at least once
+        let featured: std::collections::HashSet<_> = (0..30)
+            .map(|d| &strain_of_the_day(day + chrono::Days::new(d), &strains).unwrap().name)
+            .collect();
+        assert_eq!(featured.len(), strains.len());
+    }
+}
@@ -0,0 +1,24 @@
+//! GanjaTUI simulation engine
+//!
+//! The binary in `main.rs` is only terminal and event-loop glue; the plant
+//! simulation, app state and update function live here so they can be
+//! driven directly from tests or other front ends. Every module exports only
+//! what the binary and the integration tests need; the rest stays
+//! crate-private.
+
+pub(crate) mod app;
+pub(crate) mod ascii;
+pub mod config;
+pub mod domain;
+pub(crate) mod message;
+pub mod storage;
+pub mod ui;
+pub(crate) mod update;
+
+#[cfg(test)]
+mod test_support;
+
+pub use app::App;
+pub use domain::{HarvestResult, Plant};
+pub use message::{Message, Screen};
+pub use update::update;
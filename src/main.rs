@@ -1,47 +1,156 @@
-mod app;
-mod ascii;
-mod domain;
-mod message;
-mod storage;
-mod ui;
-mod update;
+mod cli;
 
-use std::io;
-use std::time::Duration;
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
 
 use crossterm::{
-    event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+    event::{self, DisableFocusChange, EnableFocusChange, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{backend::CrosstermBackend, Terminal};
 
-use app::App;
-use message::{Message, Screen};
-use update::update;
+use ganjatui::config::{Action, KeyConfig};
+use ganjatui::domain::strains;
+use ganjatui::domain::GrowthStage;
+use ganjatui::ui::{ColorDepth, Export, PickerMode, ProfilePicker, VisualMode, LIBRARY_PAGE};
+use ganjatui::storage::{self, SaveConfig};
+use ganjatui::{ui, update, App, Message, Screen};
+
+use cli::CliArgs;
 
 fn main() -> io::Result<()> {
-    // Setup terminal
+    let cli = match CliArgs::parse(std::env::args().skip(1)) {
+        Ok(Some(cli)) => cli,
+        Ok(None) => {
+            println!("{}", cli::USAGE);
+            return Ok(());
+        }
+        Err(e) => {
+            eprintln!("Error: {}\n\n{}", e, cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+    if cli.validate_strains {
+        std::process::exit(validate_strains(cli.strains.as_deref()));
+    }
+    if let Some(path) = cli.strains {
+        strains::set_cli_path(path);
+    }
+
+    // Resolve the save directory once; without one the game still runs, unsaved
+    let mut saves = match SaveConfig::resolve(cli.save_dir.clone()) {
+        Ok(mut saves) => {
+            saves.backups = cli.backups.unwrap_or(saves.backups);
+            let profiles = storage::list_profiles(&saves);
+            match (cli.profile.clone(), profiles.as_slice()) {
+                (Some(profile), _) => saves.profile = profile,
+                (None, [only]) => saves.profile = only.clone(),
+                (None, _) => {}
+            }
+            Some(saves)
+        }
+        Err(e) => {
+            eprintln!(
+                "Error: no usable save directory ({}) - pick another with --save-dir or ${}; \
+                 progress will not be saved",
+                e,
+                storage::DATA_DIR_ENV
+            );
+            None
+        }
+    };
+
+    // No profile named: the picker decides, so a new profile can always be
+    // added (seeded runs, first launches and restores skip it)
+    let profile_count = saves.as_ref().map_or(0, |saves| storage::list_profiles(saves).len());
+    let unnamed = cli.profile.is_none() && cli.seed.is_none();
+    let pick = unnamed && cli.restore_backup.is_none() && profile_count > 0;
+
+    // Explicit rollback happens before anything loads the save
+    if let Some(n) = cli.restore_backup {
+        if unnamed && profile_count > 1 {
+            eprintln!("Error: several profiles exist - choose one with --profile to restore a backup");
+            std::process::exit(2);
+        }
+        let restored = match saves {
+            Some(ref saves) => storage::restore_backup(saves, n),
+            None => Err(io::Error::new(io::ErrorKind::NotFound, "no save directory")),
+        };
+        if let Err(e) = restored {
+            eprintln!("Error: could not restore backup {}: {}", n, e);
+            std::process::exit(1);
+        }
+        println!("Restored backup {}", n);
+    }
+
+    // Load key bindings before entering the alternate screen so warnings stay visible
+    let (keys, key_warnings) = KeyConfig::load();
+    for warning in &key_warnings {
+        eprintln!("Warning: {}", warning);
+    }
+
+    // Setup terminal (a panic restores it before the message prints)
+    install_panic_hook();
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
+    execute!(stdout, EnterAlternateScreen, EnableFocusChange)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
     // Detect terminal color capabilities
-    let supports_truecolor = supports_color::on(supports_color::Stream::Stdout)
-        .map(|level| level.has_16m)
-        .unwrap_or(false);
+    let depth = supports_color::on(supports_color::Stream::Stdout)
+        .map_or(ColorDepth::Basic16, |level| ColorDepth::from_levels(level.has_256, level.has_16m));
+
+    // Let the player choose a profile; quitting the picker quits the game
+    if let (true, Some(saves)) = (pick, saves.as_mut()) {
+        match pick_profile(&mut terminal, saves)? {
+            Some(profile) => saves.profile = profile,
+            None => {
+                disable_raw_mode()?;
+                execute!(terminal.backend_mut(), DisableFocusChange, LeaveAlternateScreen)?;
+                terminal.show_cursor()?;
+                return Ok(());
+            }
+        }
+    }
 
-    // Load or create app state
-    let mut app = storage::load(supports_truecolor).unwrap_or_else(|_| App::new(supports_truecolor));
+    // Load or create app state; seeded runs start fresh and leave the save alone
+    let (mut app, saves, load_error) = match (cli.seed, saves) {
+        (Some(seed), _) => (App::with_seed(depth, seed), None, None),
+        (None, Some(saves)) => match storage::load(&saves, depth) {
+            Ok(app) => (app, Some(saves), None),
+            // Unreadable or from a newer build: play on without touching the file
+            Err(e) => (App::new(depth), None, Some(e)),
+        },
+        (None, None) => (App::new(depth), None, None),
+    };
+    app.keys = keys;
+    app.unicode = locale_is_utf8();
+    app.profile = saves.as_ref().map(|saves| saves.profile.clone());
+    app.save_dir = saves.as_ref().map(SaveConfig::profile_dir);
+    app.save_state.mark_saved(Instant::now());
+
+    // Report broken strain files instead of silently using the embedded database
+    app.report_strain_problems(&strains::database());
+    if let Some(theme) = cli.theme {
+        app.theme = theme;
+    }
+    app.load_theme();
+    if let Some(e) = load_error {
+        app.report_error(format!("Could not load save ({}) - this session will not be saved", e));
+    }
+    if let Some(seed) = cli.seed {
+        app.show_toast(format!("Seeded run ({}) - this session will not be saved", seed));
+    }
 
     // Run the main loop
-    let result = run_app(&mut terminal, &mut app);
+    let result = run_app(&mut terminal, &mut app, saves.as_ref());
 
     // Cleanup terminal
     disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    execute!(terminal.backend_mut(), DisableFocusChange, LeaveAlternateScreen)?;
     terminal.show_cursor()?;
 
     // Print any errors
@@ -52,69 +161,422 @@ fn main() -> io::Result<()> {
     Ok(())
 }
 
+/// Whether the locale promises UTF-8 output (the first of LC_ALL, LC_CTYPE
+/// and LANG that is set decides, as in libc)
+fn locale_is_utf8() -> bool {
+    let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.is_empty());
+    locale.is_some_and(|value| {
+        let value = value.to_ascii_lowercase();
+        value.contains("utf-8") || value.contains("utf8")
+    })
+}
+
+/// Leave raw mode and the alternate screen before the default panic output,
+/// so a crash never leaves the shell unusable
+fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let _ = disable_raw_mode();
+        let _ = execute!(io::stdout(), DisableFocusChange, LeaveAlternateScreen, crossterm::cursor::Show);
+        default_hook(info);
+    }));
+}
+
 fn run_app(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
     app: &mut App,
+    saves: Option<&SaveConfig>,
 ) -> io::Result<()> {
     loop {
         // 1. RENDER: Draw the current state
         terminal.draw(|f| ui::view(f, app))?;
 
-        // 2. INPUT: Poll for events with timeout (50ms for smooth animations)
-        if event::poll(Duration::from_millis(50))? {
-            if let Event::Key(key) = event::read()? {
+        // 2. INPUT: Poll for events with timeout (frame interval, longer when unfocused)
+        if event::poll(Duration::from_millis(app.poll_interval_ms()))? {
+            match event::read()? {
                 // Only process KeyPress events (ignore KeyRelease)
-                if key.kind == KeyEventKind::Press {
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
                     let message = key_to_message(key, app);
 
+                    // Archive the run before a new game wipes it; if that fails, keep playing
+                    let mut archived = None;
+                    if matches!(message, Message::NewGame) {
+                        if let Some(saves) = saves {
+                            match storage::archive_run(app, saves) {
+                                Ok(path) => archived = Some(path),
+                                Err(e) => {
+                                    app.confirm_new_game = false;
+                                    app.status_error = Some(format!("Could not archive this run, new game cancelled: {}", e));
+                                    continue;
+                                }
+                            }
+                        }
+                    }
+
                     // 3. UPDATE: Transform state based on message
                     *app = update(app.clone(), message);
-
-                    // 4. PERSIST: Save state after updates
-                    if let Err(e) = storage::save(app) {
-                        eprintln!("Failed to save: {}", e);
+                    if let Some(path) = archived {
+                        app.show_toast(format!("New game started - last run archived to {}", path.display()));
                     }
 
+                    // 4. PERSIST: Save when asked, on the way out or when autosave is due
+                    persist(app, saves);
+                    export(app, saves);
+
                     // Check if we should quit
                     if !app.running {
                         break;
                     }
                 }
+                Event::FocusGained => *app = update(app.clone(), Message::FocusChanged(true)),
+                Event::FocusLost => *app = update(app.clone(), Message::FocusChanged(false)),
+                _ => {}
             }
         } else {
             // No input received, send Tick message for time updates
             *app = update(app.clone(), Message::Tick);
 
-            // Save periodically (every tick)
-            if let Err(e) = storage::save(app) {
-                eprintln!("Failed to save: {}", e);
+            // Ring the bell once when a plant becomes ready (opt-in)
+            if app.bell_pending {
+                app.bell_pending = false;
+                let mut stdout = io::stdout();
+                stdout.write_all(b"\x07")?;
+                stdout.flush()?;
             }
+
+            persist(app, saves);
         }
     }
 
     Ok(())
 }
 
+/// Save after an update if the player asked to, the game is quitting with
+/// unsaved changes, or the autosave interval is up
+fn persist(app: &mut App, saves: Option<&SaveConfig>) {
+    let Some(saves) = saves else { return };
+    let now = Instant::now();
+    let requested = std::mem::take(&mut app.save_requested);
+    let quitting = !app.running && app.save_state.exit_save_due();
+    if !requested && !quitting && !app.save_state.autosave_due(app.autosave_seconds, now) {
+        return;
+    }
+
+    match storage::save(app, saves) {
+        Ok(()) => {
+            app.save_state.mark_saved(now);
+            if requested {
+                app.flash_saved();
+            }
+        }
+        Err(e) => app.status_error = Some(format!("Could not save: {}", e)),
+    }
+}
+
+/// Write the plant art or grow diary into the profile's folder if the
+/// player asked for one (sessions that are not saved export nothing)
+fn export(app: &mut App, saves: Option<&SaveConfig>) {
+    let Some(export) = app.export_requested.take() else { return };
+    let Some(plant) = app.current_plant.clone() else { return };
+    let Some(saves) = saves else {
+        app.status_error = Some(format!("Could not export the {}: this session is not saved", export.name().to_lowercase()));
+        return;
+    };
+    let dir = saves.profile_dir();
+    let written = match export {
+        Export::Art => ui::write_plant_art(app, &plant, &dir).map(|art| art.ansi),
+        Export::Diary => ui::write_diary(app, &plant, &dir),
+    };
+    match written {
+        Ok(path) => app.show_toast(format!("{} saved to {}", export.name(), path.display())),
+        Err(e) => app.status_error = Some(format!("Could not export the {}: {}", export.name().to_lowercase(), e)),
+    }
+}
+
+/// Run the startup profile picker until a profile is chosen (None = quit)
+fn pick_profile(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    saves: &SaveConfig,
+) -> io::Result<Option<String>> {
+    let mut picker = ProfilePicker::new(storage::list_profiles(saves));
+    loop {
+        terminal.draw(|f| ui::render_profile_picker(f, &picker, f.area()))?;
+
+        let Event::Key(key) = event::read()? else { continue };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        match picker.mode {
+            PickerMode::Browse => match key.code {
+                KeyCode::Up | KeyCode::Char('k') => picker.move_cursor(-1),
+                KeyCode::Down | KeyCode::Char('j') => picker.move_cursor(1),
+                KeyCode::Enter => {
+                    if let Some(profile) = picker.selected() {
+                        return Ok(Some(profile.to_string()));
+                    }
+                }
+                KeyCode::Char('n') => picker.mode = PickerMode::Naming(String::new()),
+                KeyCode::Char('d') if picker.selected().is_some() => picker.mode = PickerMode::ConfirmDelete,
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(None),
+                _ => {}
+            },
+            PickerMode::Naming(ref mut typed) => match key.code {
+                KeyCode::Char(c) => typed.push(c),
+                KeyCode::Backspace => {
+                    typed.pop();
+                }
+                KeyCode::Enter => {
+                    if let Some(profile) = picker.new_name() {
+                        return Ok(Some(profile));
+                    }
+                }
+                KeyCode::Esc => picker.mode = PickerMode::Browse,
+                _ => {}
+            },
+            PickerMode::ConfirmDelete => match key.code {
+                KeyCode::Char('y') => {
+                    let deleted = picker
+                        .selected()
+                        .map_or(Ok(()), |profile| storage::delete_profile(saves, profile));
+                    match deleted {
+                        Ok(()) => picker.remove_selected(),
+                        Err(_) => picker.mode = PickerMode::Browse,
+                    }
+                }
+                _ => picker.mode = PickerMode::Browse,
+            },
+        }
+    }
+}
+
+/// `--validate-strains`: load the strain files exactly as the game would and
+/// report every problem; returns the exit code (1 if anything was broken)
+fn validate_strains(cli_path: Option<&Path>) -> i32 {
+    let db = strains::load_database(cli_path);
+    for error in &db.errors {
+        eprintln!("Error: {}", error);
+    }
+    for warning in &db.warnings {
+        eprintln!("Warning: {}", warning);
+    }
+    for note in &db.overrides {
+        println!("Note: {}", note);
+    }
+
+    println!("Base database: {}", db.source);
+    for pack in &db.packs {
+        println!("Strain pack: {}", pack.display());
+    }
+    println!("{} strains loaded", db.strains.len());
+
+    if db.errors.is_empty() && db.warnings.is_empty() {
+        println!("All strain files are valid");
+        0
+    } else {
+        eprintln!(
+            "{} broken file{}, {} skipped entr{}",
+            db.errors.len(),
+            if db.errors.len() == 1 { "" } else { "s" },
+            db.warnings.len(),
+            if db.warnings.len() == 1 { "y" } else { "ies" }
+        );
+        1
+    }
+}
+
+/// What a list-screen key does on the current screen, if anything
+fn screen_message(app: &App, key: &KeyEvent) -> Option<Message> {
+    let action = app.keys.screen_action_for(key)?;
+    Some(match (app.current_screen, action) {
+        (Screen::Breeding, Action::CursorUp) => Message::BreedingCursor(-1),
+        (Screen::Breeding, Action::CursorDown) => Message::BreedingCursor(1),
+        (Screen::Breeding, Action::PickParent) => Message::ToggleBreedingParent,
+        (Screen::Breeding, Action::Confirm) => Message::Breed,
+        (Screen::StrainLibrary, Action::CursorUp) => Message::LibraryCursor(-1),
+        (Screen::StrainLibrary, Action::CursorDown) => Message::LibraryCursor(1),
+        (Screen::StrainLibrary, Action::PageUp) => Message::LibraryCursor(-LIBRARY_PAGE),
+        (Screen::StrainLibrary, Action::PageDown) => Message::LibraryCursor(LIBRARY_PAGE),
+        (Screen::StrainLibrary, Action::Search) => Message::StartLibrarySearch,
+        (Screen::StrainLibrary, Action::CycleSort) => Message::CycleLibrarySort,
+        (Screen::StrainCompare, Action::CursorUp) => Message::CompareCursor(-1),
+        (Screen::StrainCompare, Action::CursorDown) => Message::CompareCursor(1),
+        (Screen::StrainCompare, Action::PageUp) => Message::CompareCursor(-LIBRARY_PAGE),
+        (Screen::StrainCompare, Action::PageDown) => Message::CompareCursor(LIBRARY_PAGE),
+        (Screen::StrainCompare, Action::SwitchSide) => Message::SwapCompareFocus,
+        (Screen::Goals, Action::CursorUp) => Message::GoalCursor(-1),
+        (Screen::Goals, Action::CursorDown) => Message::GoalCursor(1),
+        (Screen::Goals, Action::Confirm) => Message::SetGoal,
+        (Screen::Goals, Action::ClearGoal) => Message::ClearGoal,
+        _ => return None,
+    })
+}
+
 /// Convert keyboard input to messages
 fn key_to_message(key: KeyEvent, app: &App) -> Message {
-    match key.code {
+    let action = app.keys.action_for(&key);
+
+    // A notice stays up until any key is pressed
+    if app.notice.is_some() {
+        return Message::DismissNotice;
+    }
+
+    // Typing a plant code: every key goes into the code
+    if app.code_input.is_some() {
+        return match key.code {
+            KeyCode::Enter => Message::ImportPlantCode,
+            KeyCode::Esc => Message::CancelCodeImport,
+            KeyCode::Backspace => Message::CodeInputBackspace,
+            KeyCode::Char(c) => Message::CodeInput(c),
+            _ => Message::Tick,
+        };
+    }
+
+    // Share code popup: any key closes it
+    if app.share_code.is_some() {
+        return Message::CloseShareCode;
+    }
+
+    // Help overlay swallows input until closed
+    if app.show_help {
+        return match (key.code, action) {
+            (KeyCode::Esc, _) | (_, Some(Action::Help)) => Message::ToggleHelp,
+            (_, Some(Action::Quit)) => Message::Quit,
+            _ => Message::Tick,
+        };
+    }
+
+    // Harvest preview: the harvest key again confirms, anything else cancels
+    if app.pending_harvest.is_some() {
+        return match action {
+            Some(Action::Harvest) => Message::ConfirmHarvest,
+            _ => Message::CancelHarvest,
+        };
+    }
+
+    // Quit with unsaved changes: y saves, n discards, anything else keeps playing
+    if app.confirm_quit {
+        return match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => Message::SaveAndQuit,
+            KeyCode::Char('n') | KeyCode::Char('N') => Message::QuitWithoutSaving,
+            _ => Message::CancelQuit,
+        };
+    }
+
+    // New game confirmation: the same key again starts over, anything else cancels
+    if app.confirm_new_game {
+        return match action {
+            Some(Action::NewGame) => Message::NewGame,
+            _ => Message::CancelNewGame,
+        };
+    }
+
+    // Strain library filter: every key goes into the search until Enter or Esc
+    // (the list keys still move the cursor, as long as they don't type;
+    // Ctrl and Alt chords are shortcuts, not text)
+    if app.current_screen == Screen::StrainLibrary && app.library_typing {
+        let chord = key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+        return match key.code {
+            KeyCode::Enter => Message::FinishLibrarySearch,
+            KeyCode::Esc => Message::CancelLibrarySearch,
+            KeyCode::Backspace => Message::LibrarySearchBackspace,
+            KeyCode::Char(_) if chord => Message::Tick,
+            KeyCode::Char(c) => Message::LibrarySearchInput(c),
+            _ => screen_message(app, &key).unwrap_or(Message::Tick),
+        };
+    }
+    if app.current_screen == Screen::StrainLibrary && key.code == KeyCode::Esc && !app.library_query.is_empty() {
+        return Message::CancelLibrarySearch;
+    }
+
+    // List screens look up their own keys before the global ones
+    if let Some(message) = screen_message(app, &key) {
+        return message;
+    }
+
+    let Some(action) = action else {
+        return Message::Tick; // Ignore unbound keys
+    };
+
+    match action {
         // Global keys
-        KeyCode::Char('q') => Message::Quit,
-        KeyCode::Char('1') => Message::SwitchScreen(Screen::GrowingRoom),
-        KeyCode::Char('s') | KeyCode::Char('2') => Message::SwitchScreen(Screen::Stats),
-        KeyCode::Char('a') => Message::ToggleAutoHarvest,
-        KeyCode::Char('v') => Message::CycleVisualMode,
-
-        // Harvest key (only works when plant is ready)
-        KeyCode::Char('h') => {
+        Action::Quit => Message::Quit,
+        Action::SaveNow => Message::SaveNow,
+        Action::CycleAutosave => Message::CycleAutosave,
+        Action::CycleOfflineGrowth => Message::CycleOfflineGrowth,
+        Action::CycleDifficulty => Message::CycleDifficulty,
+        Action::CycleMedium => Message::CycleMedium,
+        Action::GrowingRoom => Message::SwitchScreen(Screen::GrowingRoom),
+        Action::SwitchStats => Message::SwitchScreen(Screen::Stats),
+        Action::CareLog => Message::SwitchScreen(Screen::CareLog),
+        Action::Breeding => Message::SwitchScreen(Screen::Breeding),
+        Action::StrainLibrary => Message::SwitchScreen(Screen::StrainLibrary),
+        Action::StrainCompare => Message::SwitchScreen(Screen::StrainCompare),
+        Action::Goals => Message::SwitchScreen(Screen::Goals),
+        Action::ToggleAuto => Message::ToggleAutoHarvest,
+        Action::AutoHarvestSooner => Message::AdjustAutoHarvestDelay(-1),
+        Action::AutoHarvestLater => Message::AdjustAutoHarvestDelay(1),
+        Action::CycleMode => Message::CycleVisualMode,
+        Action::CycleModePrev => Message::CycleVisualModePrev,
+        Action::ModeZen => Message::SetVisualMode(VisualMode::Zen),
+        Action::ModeRainbow => Message::SetVisualMode(VisualMode::Rainbow),
+        Action::ModeMatrix => Message::SetVisualMode(VisualMode::Matrix),
+        Action::ModeNormal => Message::SetVisualMode(VisualMode::Normal),
+        Action::CycleRenderStyle => Message::CycleRenderStyle,
+        Action::CycleTheme => Message::CycleTheme,
+        Action::ToggleReducedMotion => Message::ToggleReducedMotion,
+        Action::Help => Message::ToggleHelp,
+
+        // Care keys (light/water/feed only stick with auto-care off)
+        Action::ToggleCare => Message::ToggleAutoCare,
+        Action::Water => Message::WaterPlant,
+        Action::Feed => Message::FeedPlant,
+        Action::LightUp => Message::LightUp,
+        Action::LightDown => Message::LightDown,
+        Action::ToggleCo2 => Message::ToggleCo2,
+        Action::ToggleBell => Message::ToggleBell,
+        Action::TogglePauseWhenReady => Message::TogglePauseWhenReady,
+        Action::ToggleCompression => Message::ToggleCompression,
+        Action::CycleSaveFormat => Message::CycleSaveFormat,
+        Action::ToggleUnits => Message::ToggleWeightUnit,
+        Action::FastForward => Message::FastForward,
+        Action::CycleFrameRate => Message::CycleFrameRate,
+        Action::ReloadStrains => Message::ReloadStrains,
+        Action::PlantFeatured => Message::PlantStrainOfTheDay,
+        Action::NewGame => Message::RequestNewGame,
+        Action::ExportCode => Message::ExportPlantCode,
+        Action::ExportArt => Message::ExportPlantArt,
+        Action::ExportDiary => Message::ExportDiary,
+        Action::ImportCode => Message::StartCodeImport,
+        Action::PartialHarvest => Message::PartialHarvest,
+        Action::UndoHarvest => Message::UndoHarvest,
+        Action::FinishDrying => Message::FinishDrying,
+        Action::BurpJar => Message::BurpJar,
+        Action::FinishCure => Message::FinishCure,
+
+        // List-screen keys are handled by `screen_message`
+        Action::CursorUp
+        | Action::CursorDown
+        | Action::PageUp
+        | Action::PageDown
+        | Action::Confirm
+        | Action::PickParent
+        | Action::Search
+        | Action::CycleSort
+        | Action::ClearGoal
+        | Action::SwitchSide => Message::Tick,
+
+        // Harvest key previews the yield first (flowering or ready plants only)
+        Action::Harvest => {
             if let Some(ref plant) = app.current_plant {
-                if plant.stage == crate::domain::GrowthStage::ReadyToHarvest {
-                    return Message::HarvestPlant;
+                if matches!(plant.stage, GrowthStage::Flowering | GrowthStage::ReadyToHarvest) {
+                    return Message::PreviewHarvest;
                 }
             }
-            Message::Tick // No-op if not ready
+            Message::Tick // No-op before flowering
         },
-
-        _ => Message::Tick, // Ignore other keys
     }
 }
@@ -1,19 +1,139 @@
+use crate::ui::visual_mode::VisualMode;
+
 /// All possible messages/events in the application
 /// Following The Elm Architecture pattern
 #[derive(Debug, Clone)]
 pub enum Message {
     Tick,
     Quit,
+    SaveAndQuit,
+    QuitWithoutSaving,
+    CancelQuit,
+    SaveNow,
+    CycleAutosave,
+    CycleOfflineGrowth,
     HarvestPlant,
+    PreviewHarvest,
+    ConfirmHarvest,
+    CancelHarvest,
+    PartialHarvest,
+    UndoHarvest,
+    RequestNewGame,
+    NewGame,
+    CancelNewGame,
+    FinishDrying,
+    BurpJar,
+    FinishCure,
     ToggleAutoHarvest,
+    AdjustAutoHarvestDelay(i32),
+    ToggleAutoCare,
+    ToggleHelp,
+    WaterPlant,
+    FeedPlant,
+    LightUp,
+    LightDown,
+    ToggleCo2,
+    ToggleBell,
+    TogglePauseWhenReady,
+    ToggleCompression,
+    CycleSaveFormat,
+    ToggleWeightUnit,
+    FastForward,
+    CycleFrameRate,
+    ReloadStrains,
+    PlantStrainOfTheDay,
+    FocusChanged(bool),
     CycleVisualMode,
+    CycleVisualModePrev,
+    SetVisualMode(VisualMode),
+    CycleRenderStyle,
+    CycleTheme,
+    ToggleReducedMotion,
+    CycleDifficulty,
+    CycleMedium,
+    BreedingCursor(isize),
+    ToggleBreedingParent,
+    Breed,
+    ExportPlantCode,
+    ExportPlantArt,
+    ExportDiary,
+    CloseShareCode,
+    StartCodeImport,
+    CodeInput(char),
+    CodeInputBackspace,
+    ImportPlantCode,
+    CancelCodeImport,
+    DismissNotice,
+    LibraryCursor(isize),
+    CycleLibrarySort,
+    StartLibrarySearch,
+    LibrarySearchInput(char),
+    LibrarySearchBackspace,
+    FinishLibrarySearch,
+    CancelLibrarySearch,
+    CompareCursor(isize),
+    SwapCompareFocus,
+    GoalCursor(isize),
+    SetGoal,
+    ClearGoal,
     SwitchScreen(Screen),
 }
 
+impl Message {
+    /// Whether handling this message can change what a save holds. Ticks
+    /// are judged by what they did (see `update`); navigation, popups and
+    /// typing only touch session state
+    pub fn changes_save(&self) -> bool {
+        !matches!(
+            self,
+            Message::Tick
+                | Message::Quit
+                | Message::SaveAndQuit
+                | Message::QuitWithoutSaving
+                | Message::CancelQuit
+                | Message::SaveNow
+                | Message::PreviewHarvest
+                | Message::CancelHarvest
+                | Message::RequestNewGame
+                | Message::CancelNewGame
+                | Message::ToggleHelp
+                | Message::ReloadStrains
+                | Message::FocusChanged(_)
+                | Message::BreedingCursor(_)
+                | Message::ToggleBreedingParent
+                | Message::ExportPlantCode
+                | Message::ExportPlantArt
+                | Message::ExportDiary
+                | Message::CloseShareCode
+                | Message::StartCodeImport
+                | Message::CodeInput(_)
+                | Message::CodeInputBackspace
+                | Message::CancelCodeImport
+                | Message::DismissNotice
+                | Message::LibraryCursor(_)
+                | Message::CycleLibrarySort
+                | Message::StartLibrarySearch
+                | Message::LibrarySearchInput(_)
+                | Message::LibrarySearchBackspace
+                | Message::FinishLibrarySearch
+                | Message::CancelLibrarySearch
+                | Message::CompareCursor(_)
+                | Message::SwapCompareFocus
+                | Message::GoalCursor(_)
+                | Message::SwitchScreen(_)
+        )
+    }
+}
+
 /// Screen selection
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Screen {
     #[default]
     GrowingRoom,
     Stats,
+    CareLog,
+    Breeding,
+    StrainLibrary,
+    StrainCompare,
+    Goals,
 }
@@ -0,0 +1,57 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use chrono::Local;
+use serde_json::json;
+
+use crate::app::App;
+use crate::storage::SaveConfig;
+
+/// Write the run's harvest history to `archive/run-<timestamp>.json` in the
+/// save directory and return the file's path
+pub fn archive_run(app: &App, config: &SaveConfig) -> io::Result<PathBuf> {
+    let dir = config.profile_dir().join("archive");
+    fs::create_dir_all(&dir)?;
+
+    let now = Local::now();
+    let mut path = dir.join(format!("run-{}.json", now.format("%Y%m%d-%H%M%S")));
+    // Two archives in the same second get a counter instead of overwriting
+    let mut n = 1;
+    while path.exists() {
+        path = dir.join(format!("run-{}-{}.json", now.format("%Y%m%d-%H%M%S"), n));
+        n += 1;
+    }
+
+    let archive = json!({
+        "archived_at": now.to_rfc3339(),
+        "total_harvests": app.total_harvests,
+        "harvest_history": app.harvest_history,
+    });
+    let text = serde_json::to_string_pretty(&archive).map_err(io::Error::other)?;
+    fs::write(&path, text)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+    use crate::ui::colors::ColorDepth;
+
+    #[test]
+    fn archives_never_overwrite_each_other() {
+        let dir = std::env::temp_dir().join(format!("ganjatui-archive-{}", std::process::id()));
+        let config = SaveConfig { dir: dir.clone(), profile: "test".to_string(), backups: 0 };
+        let mut app = App::new(ColorDepth::Basic16);
+        app.harvest_history.push(test_support::harvest());
+
+        let first = archive_run(&app, &config).unwrap();
+        let second = archive_run(&app, &config).unwrap();
+        assert_ne!(first, second);
+
+        let saved: serde_json::Value = serde_json::from_str(&fs::read_to_string(&first).unwrap()).unwrap();
+        assert_eq!(saved["harvest_history"].as_array().unwrap().len(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
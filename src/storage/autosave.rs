@@ -0,0 +1,99 @@
+use std::time::{Duration, Instant};
+
+/// Autosave intervals the setting cycles through (0 = only on exit)
+pub const AUTOSAVE_INTERVALS_SECS: [u64; 5] = [10, 30, 60, 300, 0];
+
+/// Unsaved-change tracking for the main loop: what's on disk versus what's
+/// in memory, and when the last save happened
+#[derive(Debug, Clone, Default)]
+pub struct SaveState {
+    dirty: bool,
+    discarded: bool, // The player chose to quit without saving
+    last_save: Option<Instant>,
+}
+
+impl SaveState {
+    /// The game changed since the last save
+    pub fn mark_dirty(&mut self) {
+        self.dirty = true;
+        self.discarded = false;
+    }
+
+    /// Everything in memory was just written (or loaded) at `now`
+    pub fn mark_saved(&mut self, now: Instant) {
+        self.dirty = false;
+        self.last_save = Some(now);
+    }
+
+    /// Drop the unsaved changes (quitting without saving)
+    pub fn forget_changes(&mut self) {
+        self.dirty = false;
+        self.discarded = true;
+    }
+
+    /// Whether there are changes not on disk yet
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Whether quitting should still write the game: always, unless the
+    /// player threw the changes away (growth since the last save is kept)
+    pub fn exit_save_due(&self) -> bool {
+        !self.discarded
+    }
+
+    /// Whether an autosave is due: there are changes, autosave is on and the
+    /// interval has passed since the last save (at once if there never was one)
+    pub fn autosave_due(&self, interval_secs: u64, now: Instant) -> bool {
+        self.dirty
+            && interval_secs > 0
+            && self
+                .last_save
+                .is_none_or(|last| now.saturating_duration_since(last) >= Duration::from_secs(interval_secs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn autosave_waits_for_changes_and_the_interval() {
+        let start = Instant::now();
+        let mut state = SaveState::default();
+        state.mark_saved(start);
+
+        assert!(!state.autosave_due(10, start + Duration::from_secs(60)), "nothing changed");
+        state.mark_dirty();
+        assert!(!state.autosave_due(10, start + Duration::from_secs(9)));
+        assert!(state.autosave_due(10, start + Duration::from_secs(10)));
+        assert!(!state.autosave_due(0, start + Duration::from_secs(3600)), "0 = only on exit");
+
+        // The interval restarts from each save
+        state.mark_saved(start + Duration::from_secs(10));
+        state.mark_dirty();
+        assert!(!state.autosave_due(10, start + Duration::from_secs(15)));
+        assert!(state.autosave_due(10, start + Duration::from_secs(20)));
+    }
+
+    #[test]
+    fn dirty_flag_follows_saves_and_discards() {
+        let mut state = SaveState::default();
+        assert!(!state.is_dirty());
+        assert!(!state.autosave_due(10, Instant::now()));
+
+        state.mark_dirty();
+        assert!(state.is_dirty());
+        assert!(state.autosave_due(10, Instant::now()), "never saved: due at once");
+
+        state.mark_saved(Instant::now());
+        assert!(!state.is_dirty());
+
+        assert!(state.exit_save_due(), "a clean game still saves its clock on exit");
+
+        state.mark_dirty();
+        state.forget_changes();
+        assert!(!state.is_dirty());
+        assert!(!state.exit_save_due());
+    }
+}
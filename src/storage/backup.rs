@@ -0,0 +1,139 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::storage::persistence::{find_save, parses, read_save, write_atomic};
+use crate::storage::{SaveConfig, SaveFormat};
+
+/// Backups kept when no depth is configured
+pub const DEFAULT_BACKUPS: usize = 3;
+
+/// Real time between rotations (saves happen every tick, so not on each one)
+const ROTATE_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// Path of backup `n` (1 = newest), e.g. `save.json.1`
+pub fn backup_path(config: &SaveConfig, n: usize) -> PathBuf {
+    config.profile_dir().join(format!("save.json.{}", n))
+}
+
+/// Whether the newest backup is missing or more than an hour old
+pub fn rotation_due(config: &SaveConfig) -> bool {
+    if config.backups == 0 {
+        return false;
+    }
+    fs::metadata(backup_path(config, 1))
+        .and_then(|meta| meta.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok())
+        .is_none_or(|age| age >= ROTATE_INTERVAL)
+}
+
+/// Shift `save.json.1` → `save.json.2` ... and copy the current save to `save.json.1`
+///
+/// Only a save that parses is rotated in, so the copy dropped off the end is
+/// never the only readable one left. Compressed saves are copied as they are.
+pub fn rotate_backups(config: &SaveConfig) -> io::Result<()> {
+    let Some(current) = find_save(config).filter(|current| parses(current)) else {
+        return Ok(());
+    };
+    if config.backups == 0 {
+        return Ok(());
+    }
+
+    for n in (1..config.backups).rev() {
+        let from = backup_path(config, n);
+        if from.exists() {
+            fs::rename(from, backup_path(config, n + 1))?;
+        }
+    }
+    fs::copy(current, backup_path(config, 1))?;
+    Ok(())
+}
+
+/// Roll the save back to backup `n` (`--restore-backup <n>`)
+pub fn restore_backup(config: &SaveConfig, n: usize) -> io::Result<()> {
+    let backup = backup_path(config, n);
+    if !backup.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("no backup {} in {}", n, config.profile_dir().display()),
+        ));
+    }
+    if !parses(&backup) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{} is damaged", backup.display()),
+        ));
+    }
+    let text = read_save(&backup)?;
+    write_atomic(config, &text, SaveFormat::detect(&text), false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::App;
+    use crate::storage::persistence::{get_save_path, save};
+    use crate::ui::colors::ColorDepth;
+
+    fn temp_config(name: &str, backups: usize) -> SaveConfig {
+        let dir = std::env::temp_dir().join(format!("ganjatui-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        SaveConfig { dir, profile: "test".to_string(), backups }
+    }
+
+    /// Harvest count stored in a save file
+    fn harvests_in(path: PathBuf) -> u64 {
+        let value: serde_json::Value = serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+        value["total_harvests"].as_u64().unwrap()
+    }
+
+    #[test]
+    fn rotation_keeps_the_newest_copies() {
+        let config = temp_config("rotate", 2);
+        let mut app = App::new(ColorDepth::Basic16);
+        for harvests in 1..=4 {
+            app.total_harvests = harvests;
+            save(&app, &config).unwrap();
+            rotate_backups(&config).unwrap();
+        }
+
+        assert_eq!(harvests_in(backup_path(&config, 1)), 4);
+        assert_eq!(harvests_in(backup_path(&config, 2)), 3);
+        assert!(!backup_path(&config, 3).exists(), "depth is capped");
+        assert!(!rotation_due(&config), "just rotated");
+        fs::remove_dir_all(&config.dir).unwrap();
+    }
+
+    #[test]
+    fn damaged_saves_never_push_out_good_backups() {
+        let config = temp_config("rotate-damaged", 2);
+        let app = App::new(ColorDepth::Basic16);
+        save(&app, &config).unwrap();
+        rotate_backups(&config).unwrap();
+        rotate_backups(&config).unwrap();
+
+        fs::write(get_save_path(&config), "{ truncated").unwrap();
+        fs::write(backup_path(&config, 1), "{ truncated").unwrap();
+        rotate_backups(&config).unwrap();
+        assert!(parses(&backup_path(&config, 2)), "the last good copy survives");
+
+        restore_backup(&config, 2).unwrap();
+        assert!(parses(&get_save_path(&config)));
+        assert_eq!(restore_backup(&config, 1).unwrap_err().kind(), io::ErrorKind::InvalidData);
+        assert_eq!(restore_backup(&config, 5).unwrap_err().kind(), io::ErrorKind::NotFound);
+        fs::remove_dir_all(&config.dir).unwrap();
+    }
+
+    #[test]
+    fn no_backups_when_depth_is_zero() {
+        let config = temp_config("rotate-off", 0);
+        save(&App::new(ColorDepth::Basic16), &config).unwrap();
+        assert!(!rotation_due(&config));
+        rotate_backups(&config).unwrap();
+        assert!(!backup_path(&config, 1).exists());
+        fs::remove_dir_all(&config.dir).unwrap();
+    }
+}
@@ -0,0 +1,203 @@
+use std::io;
+
+use serde::{Deserialize, Serialize};
+
+use crate::app::App;
+use crate::storage::migrate::{migrate, newer_version_error, SAVE_VERSION};
+
+/// Text format the save is written in (either can also be gzipped)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SaveFormat {
+    /// `save.json`
+    #[default]
+    Json,
+    /// `save.ron` - Rusty Object Notation, easier to read and hand-edit
+    Ron,
+}
+
+impl SaveFormat {
+    /// Every format, in the order the setting cycles through them
+    pub const ALL: [SaveFormat; 2] = [SaveFormat::Json, SaveFormat::Ron];
+
+    /// Display name
+    pub fn name(self) -> &'static str {
+        match self {
+            SaveFormat::Json => "JSON",
+            SaveFormat::Ron => "RON",
+        }
+    }
+
+    /// Save file name in this format, e.g. `save.ron` or `save.json.gz`
+    pub fn file_name(self, compressed: bool) -> String {
+        let extension = match self {
+            SaveFormat::Json => "json",
+            SaveFormat::Ron => "ron",
+        };
+        format!("save.{}{}", extension, if compressed { ".gz" } else { "" })
+    }
+
+    /// The format after this one
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|&format| format == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// Tell the formats apart by content, not file name: a JSON save is an
+    /// object, a RON save a struct `( ... )`
+    pub fn detect(text: &str) -> Self {
+        if text.trim_start().starts_with('{') {
+            SaveFormat::Json
+        } else {
+            SaveFormat::Ron
+        }
+    }
+
+    /// Serialize `app`; compact output skips the indentation (for gzipped saves)
+    pub fn encode(self, app: &App, compact: bool) -> io::Result<String> {
+        match (self, compact) {
+            (SaveFormat::Json, true) => serde_json::to_string(app).map_err(io::Error::other),
+            (SaveFormat::Json, false) => serde_json::to_string_pretty(app).map_err(io::Error::other),
+            (SaveFormat::Ron, true) => ron::to_string(app).map_err(io::Error::other),
+            (SaveFormat::Ron, false) => {
+                ron::ser::to_string_pretty(app, ron::ser::PrettyConfig::default()).map_err(io::Error::other)
+            }
+        }
+    }
+}
+
+/// Just the version field of a save (everything else is skipped)
+#[derive(Deserialize)]
+struct VersionProbe {
+    #[serde(default)]
+    version: u64,
+}
+
+/// Save format version a save claims, in either format
+pub fn version(text: &str) -> Option<u64> {
+    match SaveFormat::detect(text) {
+        SaveFormat::Json => serde_json::from_str::<VersionProbe>(text).ok().map(|probe| probe.version),
+        SaveFormat::Ron => ron::from_str::<VersionProbe>(text).ok().map(|probe| probe.version),
+    }
+}
+
+/// Parse a save in either format and bring it up to the current version
+///
+/// Both formats go through the migration steps. RON has no untyped value that
+/// keeps enum variants, so it's read into `App` first (serde defaults and
+/// aliases fill in what changed) and the steps run on that, at the version
+/// the file claims.
+pub fn decode(text: &str) -> io::Result<App> {
+    let invalid = |e: &dyn std::fmt::Display| io::Error::new(io::ErrorKind::InvalidData, e.to_string());
+    match SaveFormat::detect(text) {
+        SaveFormat::Json => migrate(serde_json::from_str(text).map_err(|e| invalid(&e))?),
+        SaveFormat::Ron => {
+            let version = ron::from_str::<VersionProbe>(text).map_err(|e| invalid(&e))?.version;
+            if version > SAVE_VERSION as u64 {
+                return Err(newer_version_error(version));
+            }
+            let app: App = ron::from_str(text).map_err(|e| invalid(&e))?;
+            let mut value = serde_json::to_value(&app).map_err(|e| invalid(&e))?;
+            value["version"] = version.into();
+            migrate(value)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::goals::Goal;
+    use crate::ui::colors::ColorDepth;
+
+    /// An app with every optional part of the save filled in
+    fn fully_populated_app() -> App {
+        let mut app = App::with_seed(ColorDepth::Basic16, 7);
+        for _ in 0..3 {
+            app.fast_forward();
+            app.harvest_and_replant();
+        }
+        app.update_time(20.0);
+        app.finish_drying();
+        app.breeding_parents = vec![0, 1];
+        app.breed_selected();
+        app.burp_jar();
+        app.fast_forward();
+        app.partial_harvest();
+        app.set_goal(Goal::Harvests { count: 50 });
+        app.auto_harvest = true;
+        app.co2_enrichment = true;
+        app.enable_bell = true;
+        app.pause_when_ready = true;
+        app.save_format = SaveFormat::Ron;
+
+        assert!(app.curing.is_some() && !app.drying.is_empty() && !app.seed_inventory.is_empty());
+        assert!(!app.harvest_history.is_empty() && !app.events.is_empty());
+        app
+    }
+
+    #[test]
+    fn both_formats_round_trip_a_full_save() {
+        let app = fully_populated_app();
+        let expected = serde_json::to_value(&app).unwrap();
+
+        for format in SaveFormat::ALL {
+            for compact in [false, true] {
+                let text = format.encode(&app, compact).unwrap();
+                assert_eq!(SaveFormat::detect(&text), format);
+                assert_eq!(version(&text), Some(SAVE_VERSION as u64));
+                let loaded = decode(&text).unwrap();
+                assert_eq!(serde_json::to_value(&loaded).unwrap(), expected, "{}", format.name());
+            }
+        }
+    }
+
+    #[test]
+    fn serde_defaults_apply_to_ron_too() {
+        // A hand-trimmed RON save: every field with a default can go
+        let mut value = serde_json::to_value(App::new(ColorDepth::Basic16)).unwrap();
+        let full: App = serde_json::from_value(value.clone()).unwrap();
+        let text = SaveFormat::Ron.encode(&full, false).unwrap();
+        let minimal: String = text
+            .lines()
+            .filter(|line| {
+                !["frame_interval_ms:", "visual_mode:", "auto_care:", "events:"]
+                    .iter()
+                    .any(|field| line.trim_start().starts_with(field))
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let from_ron = decode(&minimal).unwrap();
+
+        for field in ["frame_interval_ms", "visual_mode", "auto_care", "events"] {
+            value.as_object_mut().unwrap().remove(field);
+        }
+        let from_json = decode(&value.to_string()).unwrap();
+        assert_eq!(serde_json::to_value(from_ron).unwrap(), serde_json::to_value(from_json).unwrap());
+    }
+
+    #[test]
+    fn old_ron_saves_migrate_like_json() {
+        let from_json = decode(include_str!("../../tests/fixtures/save_v0.json")).unwrap();
+
+        // The same unversioned save written as RON, terpenes under their old name
+        let text = SaveFormat::Ron
+            .encode(&from_json, false)
+            .unwrap()
+            .replacen(&format!("version: {}", SAVE_VERSION), "version: 0", 1)
+            .replace("\n            dominant_terpenes:", "\n            terpenes:");
+        assert!(text.contains("\n            terpenes:"), "a harvest field is renamed");
+        assert_eq!(version(&text), Some(0));
+        let from_ron = decode(&text).unwrap();
+
+        assert_eq!(from_ron.version, SAVE_VERSION);
+        assert_eq!(from_ron.harvest_history[0].dominant_terpenes.len(), 3);
+        assert_eq!(serde_json::to_value(from_ron).unwrap(), serde_json::to_value(from_json).unwrap());
+    }
+
+    #[test]
+    fn newer_ron_saves_are_refused() {
+        let text = SaveFormat::Ron.encode(&App::new(ColorDepth::Basic16), false).unwrap();
+        let newer = text.replacen(&format!("version: {}", SAVE_VERSION), &format!("version: {}", SAVE_VERSION + 1), 1);
+        assert!(decode(&newer).unwrap_err().to_string().contains("please update"));
+    }
+}
@@ -0,0 +1,78 @@
+use std::io;
+
+use serde_json::Value;
+
+use crate::app::App;
+
+/// Save format version written by this build
+///
+/// Bump it when a change needs more than a `#[serde(default)]` (renamed or
+/// moved fields, changed shapes), add the step to `STEPS` and a fixture for
+/// the old version in `tests/fixtures/`. RON saves reach `STEPS` only after
+/// parsing into `App`, so a renamed field also needs a `#[serde(alias)]`.
+pub const SAVE_VERSION: u32 = 1;
+
+/// Upgrades a save from version `i` to `i + 1`
+type Step = fn(&mut Value);
+
+/// Migration steps in order (index = version being upgraded from)
+const STEPS: [Step; SAVE_VERSION as usize] = [v0_to_v1];
+
+/// Upgrade a parsed save of any supported version and deserialize it
+/// Saves from a newer build are rejected with a readable error
+pub fn migrate(mut value: Value) -> io::Result<App> {
+    let version = value
+        .get("version")
+        .and_then(Value::as_u64)
+        .unwrap_or(0);
+
+    if version > SAVE_VERSION as u64 {
+        return Err(newer_version_error(version));
+    }
+
+    for step in &STEPS[version as usize..] {
+        step(&mut value);
+    }
+    value["version"] = SAVE_VERSION.into();
+
+    serde_json::from_value(value).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Error for a save written by a newer build
+pub(crate) fn newer_version_error(version: u64) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!(
+            "save file is version {} but this build only understands up to version {} - please update ganjatui",
+            version, SAVE_VERSION
+        ),
+    )
+}
+
+/// Version 0 (unversioned saves): harvests stored their terpenes as `terpenes`
+fn v0_to_v1(save: &mut Value) {
+    let Some(history) = save.get_mut("harvest_history").and_then(Value::as_array_mut) else {
+        return;
+    };
+    for harvest in history.iter_mut().filter_map(Value::as_object_mut) {
+        if let Some(terpenes) = harvest.remove("terpenes") {
+            harvest.entry("dominant_terpenes").or_insert(terpenes);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::colors::ColorDepth;
+
+    #[test]
+    fn newer_saves_are_refused_clearly() {
+        let mut value = serde_json::to_value(App::new(ColorDepth::Basic16)).unwrap();
+        value["version"] = (SAVE_VERSION + 1).into();
+
+        let err = migrate(value).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("please update"), "{}", err);
+    }
+}
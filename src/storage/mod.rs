@@ -1,3 +1,15 @@
-pub mod persistence;
+pub(crate) mod archive;
+pub(crate) mod autosave;
+pub(crate) mod backup;
+pub(crate) mod format;
+pub(crate) mod migrate;
+pub(crate) mod persistence;
+pub(crate) mod profiles;
 
-pub use persistence::{load, save};
+pub use archive::archive_run;
+pub use autosave::{SaveState, AUTOSAVE_INTERVALS_SECS};
+pub use backup::restore_backup;
+pub use format::SaveFormat;
+pub use migrate::{migrate, SAVE_VERSION};
+pub use persistence::{get_data_dir, load, save, SaveConfig, DATA_DIR_ENV};
+pub use profiles::{delete_profile, list_profiles, sanitize_profile_name, DEFAULT_PROFILE};
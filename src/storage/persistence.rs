@@ -1,63 +1,581 @@
 use std::fs;
-use std::io;
-use std::path::PathBuf;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
 
 use crate::app::App;
-use crate::ui::colors::create_palette;
+use crate::storage::backup::{self, DEFAULT_BACKUPS};
+use crate::storage::format::{self, SaveFormat};
+use crate::storage::profiles::{migrate_legacy_save, DEFAULT_PROFILE};
+use crate::storage::migrate::SAVE_VERSION;
+use crate::ui::colors::{create_palette, ColorDepth};
+
+/// Environment variable that moves the whole data directory (saves and settings)
+pub const DATA_DIR_ENV: &str = "GANJATUI_DATA_DIR";
+
+/// Get the application data directory, creating it if needed:
+/// GANJATUI_DATA_DIR, then `$XDG_DATA_HOME/ganjatui`, then the platform data directory
+pub fn get_data_dir() -> io::Result<PathBuf> {
+    let env = |name: &str| std::env::var_os(name).filter(|value| !value.is_empty()).map(PathBuf::from);
+    data_dir_from(env(DATA_DIR_ENV), env("XDG_DATA_HOME"), dirs::data_dir())
+}
 
-/// Get the save file path
-pub fn get_save_path() -> io::Result<PathBuf> {
-    let data_dir = dirs::data_dir()
-        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Could not find data directory"))?;
+fn data_dir_from(data_env: Option<PathBuf>, xdg_env: Option<PathBuf>, platform_dir: Option<PathBuf>) -> io::Result<PathBuf> {
+    // The XDG spec says relative paths are invalid and must be ignored
+    let xdg_env = xdg_env.filter(|dir| dir.is_absolute());
+    let app_dir = match (data_env, xdg_env) {
+        (Some(dir), _) => dir,
+        (None, Some(xdg)) => xdg.join("ganjatui"),
+        (None, None) => platform_dir
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Could not find data directory"))?
+            .join("ganjatui"),
+    };
 
-    let app_dir = data_dir.join("ganjatui");
+    fs::create_dir_all(&app_dir)
+        .map_err(|e| io::Error::new(e.kind(), format!("cannot create {}: {}", app_dir.display(), e)))?;
+    Ok(app_dir)
+}
+
+/// Fail up front, with the path in the message, if saves can't be written to `dir`
+fn ensure_writable(dir: &Path) -> io::Result<()> {
+    let probe = dir.join(".ganjatui-write-test");
+    fs::write(&probe, b"")
+        .and_then(|_| fs::remove_file(&probe))
+        .map_err(|e| io::Error::new(e.kind(), format!("{} is not writable: {}", dir.display(), e)))
+}
 
-    // Create directory if it doesn't exist
-    if !app_dir.exists() {
-        fs::create_dir_all(&app_dir)?;
+/// Environment variable that points saves at another directory
+pub const SAVE_DIR_ENV: &str = "GANJATUI_SAVE_DIR";
+
+/// Where saves live, resolved once at startup
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveConfig {
+    /// Root save directory; each profile gets `profiles/<name>/` inside it
+    pub dir: PathBuf,
+    /// Active profile (already sanitized)
+    pub profile: String,
+    /// Rotating backups kept beside the save (`save.json.1` is the newest)
+    pub backups: usize,
+}
+
+impl SaveConfig {
+    /// Resolve the save directory: `--save-dir`, then GANJATUI_SAVE_DIR, then the
+    /// data directory (see `get_data_dir`). The directory must be writable.
+    /// A save from before profiles is moved into the default profile
+    pub fn resolve(cli_dir: Option<PathBuf>) -> io::Result<Self> {
+        let env_dir = std::env::var_os(SAVE_DIR_ENV)
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from);
+        Self::resolve_from(cli_dir, env_dir)
     }
 
-    Ok(app_dir.join("save.json"))
+    fn resolve_from(cli_dir: Option<PathBuf>, env_dir: Option<PathBuf>) -> io::Result<Self> {
+        let dir = match cli_dir.or(env_dir) {
+            Some(dir) => {
+                fs::create_dir_all(&dir)
+                    .map_err(|e| io::Error::new(e.kind(), format!("cannot create {}: {}", dir.display(), e)))?;
+                dir
+            }
+            None => get_data_dir()?,
+        };
+        ensure_writable(&dir)?;
+        migrate_legacy_save(&dir)?;
+        Ok(Self {
+            dir,
+            profile: DEFAULT_PROFILE.to_string(),
+            backups: DEFAULT_BACKUPS,
+        })
+    }
+
+    /// Directory holding the active profile's save, backups and archives
+    pub fn profile_dir(&self) -> PathBuf {
+        self.dir.join("profiles").join(&self.profile)
+    }
 }
 
-/// Save application state to disk
-pub fn save(app: &App) -> io::Result<()> {
-    let path = get_save_path()?;
-    let json = serde_json::to_string_pretty(app)
-        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+/// Get the save file path of the active profile
+#[cfg(test)]
+pub fn get_save_path(config: &SaveConfig) -> PathBuf {
+    save_path_for(config, SaveFormat::Json, false)
+}
 
-    fs::write(path, json)?;
-    Ok(())
+/// Get the compressed save file path of the active profile
+#[cfg(test)]
+pub fn get_compressed_save_path(config: &SaveConfig) -> PathBuf {
+    save_path_for(config, SaveFormat::Json, true)
+}
+
+/// Save file path of the active profile in a given format
+pub fn save_path_for(config: &SaveConfig, format: SaveFormat, compressed: bool) -> PathBuf {
+    config.profile_dir().join(format.file_name(compressed))
+}
+
+/// Every name the save can have (`save.json`, `save.json.gz`, `save.ron`, ...)
+fn all_save_paths(config: &SaveConfig) -> impl Iterator<Item = PathBuf> + '_ {
+    SaveFormat::ALL
+        .into_iter()
+        .flat_map(move |format| [false, true].map(|compressed| save_path_for(config, format, compressed)))
+}
+
+/// The save on disk in whichever format it was written (the newest one if
+/// several are left over from a switch)
+pub fn find_save(config: &SaveConfig) -> Option<PathBuf> {
+    let modified = |path: &Path| fs::metadata(path).and_then(|meta| meta.modified()).ok();
+    all_save_paths(config)
+        .filter_map(|path| Some((modified(&path)?, path)))
+        .max_by_key(|(modified, _)| *modified)
+        .map(|(_, path)| path)
+}
+
+/// Gzip streams start with these bytes; saves are told apart by content, not name
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Save text from raw file bytes, decompressing gzipped saves
+fn decode_save(bytes: &[u8]) -> io::Result<String> {
+    if bytes.starts_with(&GZIP_MAGIC) {
+        let mut text = String::new();
+        GzDecoder::new(bytes).read_to_string(&mut text)?;
+        Ok(text)
+    } else {
+        String::from_utf8(bytes.to_vec()).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
 }
 
-/// Load application state from disk
-pub fn load(supports_truecolor: bool) -> io::Result<App> {
-    let path = get_save_path()?;
+/// Read a save or backup in any format
+pub(crate) fn read_save(path: &Path) -> io::Result<String> {
+    decode_save(&fs::read(path)?)
+}
+
+/// Temporary file a save is written to before it replaces the real one
+fn get_temp_path(config: &SaveConfig) -> PathBuf {
+    config.profile_dir().join("save.json.tmp")
+}
+
+/// Older copies tried, in order, when the main save doesn't parse
+fn recovery_candidates(config: &SaveConfig) -> Vec<PathBuf> {
+    std::iter::once(get_temp_path(config))
+        .chain((1..=config.backups).map(|n| backup::backup_path(config, n)))
+        .collect()
+}
+
+/// Whether the file at `path` holds a loadable save
+pub(crate) fn parses(path: &Path) -> bool {
+    read_save(path).is_ok_and(|text| from_text(&text, ColorDepth::Basic16).is_ok())
+}
 
-    if !path.exists() {
+/// Save application state to disk, rotating backups about once an hour
+/// The file follows the save format setting (`save.json`, `save.ron`), with
+/// `.gz` added and the indentation left out when compressing
+pub fn save(app: &App, config: &SaveConfig) -> io::Result<()> {
+    let text = app.save_format.encode(app, app.compress_saves)?;
+
+    // A failed backup shouldn't stop the save itself
+    if backup::rotation_due(config) {
+        let _ = backup::rotate_backups(config);
+    }
+    write_atomic(config, &text, app.save_format, app.compress_saves)
+}
+
+/// Write `text` as the save: it goes to a synced temporary file that is then
+/// renamed over the old one, so a crash mid-write leaves either save intact.
+/// Saves in other formats, if any, are removed once this one is in place.
+pub(crate) fn write_atomic(config: &SaveConfig, text: &str, format: SaveFormat, compress: bool) -> io::Result<()> {
+    fs::create_dir_all(config.profile_dir())?;
+    let path = save_path_for(config, format, compress);
+
+    let temp_path = get_temp_path(config);
+    let mut file = fs::File::create(&temp_path)?;
+    if compress {
+        let mut encoder = GzEncoder::new(&mut file, Compression::default());
+        encoder.write_all(text.as_bytes())?;
+        encoder.finish()?;
+    } else {
+        file.write_all(text.as_bytes())?;
+    }
+    file.sync_all()?;
+    drop(file);
+    fs::rename(temp_path, &path)?;
+
+    for stale in all_save_paths(config).filter(|stale| *stale != path) {
+        match fs::remove_file(stale) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Load application state from disk in any format, plain or gzipped (both
+/// detected by content). The save format setting follows the file found, so
+/// a save converted by hand stays in its new format.
+///
+/// A save that doesn't parse is moved aside to `<name>.corrupt-<timestamp>`
+/// (never deleted), then the first older copy that still parses is used, or a
+/// new game if none does; either way the player gets a notice saying so.
+/// Unreadable files and saves from a newer build are errors, left untouched.
+pub fn load(config: &SaveConfig, depth: ColorDepth) -> io::Result<App> {
+    let Some(path) = find_save(config) else {
         // No save file, return default app with a new plant
-        return Ok(App::new(supports_truecolor));
+        return Ok(App::new(depth));
+    };
+
+    let bytes = fs::read(&path)?;
+    let error = match decode_save(&bytes) {
+        Ok(text) => match from_text(&text, depth) {
+            Ok(mut app) => {
+                // The file decides the format, so the next save doesn't switch it
+                app.save_format = SaveFormat::detect(&text);
+                app.compress_saves = bytes.starts_with(&GZIP_MAGIC);
+                app.resume(Utc::now());
+                return Ok(app);
+            }
+            Err(_) if from_newer_build(&text) => {
+                return Err(io::Error::new(io::ErrorKind::InvalidData, "save file is from a newer version of ganjatui"))
+            }
+            Err(e) => e,
+        },
+        Err(e) => e,
+    };
+    let kept = quarantine(&path)?;
+
+    let recovered = recovery_candidates(config).into_iter().find_map(|candidate| {
+        let app = read_save(&candidate).and_then(|text| from_text(&text, depth)).ok()?;
+        Some((app, candidate))
+    });
+    let (mut app, outcome) = match recovered {
+        Some((mut app, candidate)) => {
+            app.resume(Utc::now());
+            let name = candidate.file_name().unwrap_or_default().to_string_lossy().into_owned();
+            (app, format!("Recovered from backup {} - anything played after it is lost.", name))
+        }
+        None => (App::new(depth), "No usable backup was found, so a new game was started.".to_string()),
+    };
+    app.notice = Some(format!(
+        "Your save file could not be read ({}).\n\nThe damaged file was kept as:\n{}\n\n{}",
+        error,
+        kept.display(),
+        outcome
+    ));
+    Ok(app)
+}
+
+/// Whether a save was written by a newer build (refused, but not damaged)
+fn from_newer_build(text: &str) -> bool {
+    format::version(text).is_some_and(|version| version > SAVE_VERSION as u64)
+}
+
+/// Rename a damaged save to e.g. `save.json.corrupt-<timestamp>` (with a
+/// counter if that name is taken) and return where it went
+fn quarantine(path: &Path) -> io::Result<PathBuf> {
+    let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+    let name = path.file_name().unwrap_or_default().to_string_lossy().into_owned();
+    let mut kept = path.with_file_name(format!("{}.corrupt-{}", name, stamp));
+    let mut n = 1;
+    while kept.exists() {
+        kept = path.with_file_name(format!("{}.corrupt-{}-{}", name, stamp, n));
+        n += 1;
     }
+    fs::rename(path, &kept)?;
+    Ok(kept)
+}
 
-    let json = fs::read_to_string(path)?;
-    let mut app: App = serde_json::from_str(&json)
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+/// Parse a save (JSON or RON), migrate it to the current version, restore UI
+/// state and sanitize out-of-range values
+pub fn from_text(text: &str, depth: ColorDepth) -> io::Result<App> {
+    let mut app = format::decode(text)?;
 
     // Restore UI state
     app.running = true;
     app.current_screen = crate::message::Screen::GrowingRoom;
     app.animation_frame = 0;
-    app.color_palette = create_palette(supports_truecolor, app.visual_mode);
+    app.color_palette = create_palette(depth, app.visual_mode, None);
 
+    app.sanitize();
     Ok(app)
 }
 
-/// Delete save file (for testing)
+/// Delete save file in any format (for testing)
 #[allow(dead_code)]
-pub fn delete_save() -> io::Result<()> {
-    let path = get_save_path()?;
-    if path.exists() {
-        fs::remove_file(path)?;
+pub fn delete_save(config: &SaveConfig) -> io::Result<()> {
+    for path in all_save_paths(config) {
+        if path.exists() {
+            fs::remove_file(path)?;
+        }
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::GrowthStage;
+    use rand::Rng;
+
+    #[test]
+    fn out_of_range_save_is_clamped() {
+        let mut value = serde_json::to_value(App::new(ColorDepth::Basic16)).unwrap();
+        let plant = &mut value["current_plant"];
+        plant["water_level"] = 500.0.into();
+        plant["nutrient_level"] = (-20.0).into();
+        plant["humidity"] = 180.0.into();
+        plant["co2_level"] = 101.0.into();
+        plant["root_development"] = (-1.0).into();
+        plant["canopy_density"] = 250.0.into();
+        plant["temperature"] = 300.0.into();
+        plant["total_hours_elapsed"] = (100.0 * 24.0).into();
+        plant["days_alive"] = 3.into();
+        plant["stage"] = "Seedling".into();
+        value["frame_interval_ms"] = 7.into();
+
+        let app = from_text(&value.to_string(), ColorDepth::Basic16).unwrap();
+        let plant = app.current_plant.as_ref().unwrap();
+
+        assert_eq!(plant.water_level, 100.0);
+        assert_eq!(plant.nutrient_level, 0.0);
+        assert_eq!(plant.humidity, 100.0);
+        assert_eq!(plant.co2_level, 100.0);
+        assert_eq!(plant.root_development, 0.0);
+        assert_eq!(plant.canopy_density, 100.0);
+        assert!(plant.temperature <= 45.0);
+        assert_eq!(plant.days_alive, 100);
+        assert_eq!(plant.stage, GrowthStage::ReadyToHarvest);
+        assert_eq!(app.frame_interval_ms, 50);
+    }
+
+    #[test]
+    fn truncated_save_recovers_from_backup() {
+        let dir = std::env::temp_dir().join(format!("ganjatui-truncated-{}", std::process::id()));
+        let config = SaveConfig::resolve_from(Some(dir.clone()), None).unwrap();
+        let app = App::new(ColorDepth::Basic16);
+        save(&app, &config).unwrap();
+        assert!(!get_temp_path(&config).exists(), "the temporary file is renamed into place");
+
+        // Killed mid-write: the main file is cut short and only the last copy survives
+        let json = fs::read_to_string(get_save_path(&config)).unwrap();
+        fs::write(get_temp_path(&config), &json).unwrap();
+        fs::write(get_save_path(&config), &json[..json.len() / 2]).unwrap();
+
+        let recovered = load(&config, ColorDepth::Basic16).unwrap();
+        assert_eq!(recovered.current_plant.unwrap().id, app.current_plant.unwrap().id);
+        assert!(recovered.notice.unwrap().contains("Recovered from backup save.json.tmp"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn mangled_save_is_kept_aside_and_a_new_game_starts() {
+        let dir = std::env::temp_dir().join(format!("ganjatui-mangled-{}", std::process::id()));
+        let config = SaveConfig::resolve_from(Some(dir.clone()), None).unwrap();
+        let mangled = r#"{"version": 1, "current_plant": {"strain_name": 42}, "harvest_history": ["#;
+        fs::create_dir_all(config.profile_dir()).unwrap();
+        fs::write(get_save_path(&config), mangled).unwrap();
+
+        let app = load(&config, ColorDepth::Basic16).unwrap();
+        let notice = app.notice.expect("the player is told");
+        assert!(notice.contains("new game was started"), "{}", notice);
+
+        // The original bytes survive under a new name, untouched
+        let kept: Vec<PathBuf> = fs::read_dir(config.profile_dir())
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.to_string_lossy().contains("save.json.corrupt-"))
+            .collect();
+        assert_eq!(kept.len(), 1);
+        assert!(notice.contains(&kept[0].display().to_string()));
+        assert_eq!(fs::read_to_string(&kept[0]).unwrap(), mangled);
+        assert!(!get_save_path(&config).exists());
+
+        // A second damaged save never overwrites the first
+        fs::write(get_save_path(&config), "{").unwrap();
+        load(&config, ColorDepth::Basic16).unwrap();
+        let corrupt = fs::read_dir(config.profile_dir())
+            .unwrap()
+            .filter(|entry| entry.as_ref().unwrap().file_name().to_string_lossy().contains("corrupt"))
+            .count();
+        assert_eq!(corrupt, 2);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn saves_from_newer_builds_are_left_alone() {
+        let dir = std::env::temp_dir().join(format!("ganjatui-newer-{}", std::process::id()));
+        let config = SaveConfig::resolve_from(Some(dir.clone()), None).unwrap();
+        let mut value = serde_json::to_value(App::new(ColorDepth::Basic16)).unwrap();
+        value["version"] = (SAVE_VERSION + 1).into();
+        fs::create_dir_all(config.profile_dir()).unwrap();
+        fs::write(get_save_path(&config), value.to_string()).unwrap();
+
+        assert_eq!(load(&config, ColorDepth::Basic16).unwrap_err().kind(), io::ErrorKind::InvalidData);
+        assert!(get_save_path(&config).exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn both_formats_round_trip_and_switching_cleans_up() {
+        let dir = std::env::temp_dir().join(format!("ganjatui-gzip-{}", std::process::id()));
+        let config = SaveConfig::resolve_from(Some(dir.clone()), None).unwrap();
+        let mut app = App::new(ColorDepth::Basic16);
+        let id = app.current_plant.as_ref().unwrap().id;
+
+        save(&app, &config).unwrap();
+        assert!(fs::read(get_save_path(&config)).unwrap().starts_with(b"{"));
+        assert_eq!(load(&config, ColorDepth::Basic16).unwrap().current_plant.unwrap().id, id);
+
+        // Turning compression on replaces save.json with save.json.gz
+        app.compress_saves = true;
+        save(&app, &config).unwrap();
+        assert!(fs::read(get_compressed_save_path(&config)).unwrap().starts_with(&GZIP_MAGIC));
+        assert!(!get_save_path(&config).exists(), "the stale plain save is removed");
+        let loaded = load(&config, ColorDepth::Basic16).unwrap();
+        assert!(loaded.compress_saves);
+        assert_eq!(loaded.current_plant.unwrap().id, id);
+
+        // ...and back again
+        app.compress_saves = false;
+        save(&app, &config).unwrap();
+        assert!(!get_compressed_save_path(&config).exists(), "the stale gzip save is removed");
+        assert_eq!(load(&config, ColorDepth::Basic16).unwrap().current_plant.unwrap().id, id);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn loaded_games_keep_rolling_where_the_save_left_off() {
+        let dir = std::env::temp_dir().join(format!("ganjatui-rng-{}", std::process::id()));
+        let config = SaveConfig::resolve_from(Some(dir.clone()), None).unwrap();
+        let mut app = App::with_seed(ColorDepth::Basic16, 1313);
+        app.harvest_and_replant();
+
+        for format in [SaveFormat::Json, SaveFormat::Ron] {
+            app.save_format = format;
+            save(&app, &config).unwrap();
+            let mut loaded = load(&config, ColorDepth::Basic16).unwrap();
+            let mut playing = app.clone();
+
+            // The next cut, the next seedling and every roll after them come out the same
+            playing.harvest_and_replant();
+            loaded.harvest_and_replant();
+            let (cut, replayed) = (&playing.drying[1], &loaded.drying[1]);
+            assert_eq!((cut.loss, cut.target_days), (replayed.loss, replayed.target_days), "{:?}", format);
+            let (plant, replanted) = (playing.current_plant.unwrap(), loaded.current_plant.unwrap());
+            assert_eq!(plant.strain_name, replanted.strain_name);
+            assert_eq!(plant.genetics.thc_percent, replanted.genetics.thc_percent);
+            assert_eq!(playing.rng.gen::<u64>(), loaded.rng.gen::<u64>(), "{:?}", format);
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn switching_to_ron_converts_on_the_next_save() {
+        let dir = std::env::temp_dir().join(format!("ganjatui-ron-{}", std::process::id()));
+        let config = SaveConfig::resolve_from(Some(dir.clone()), None).unwrap();
+        let mut app = App::new(ColorDepth::Basic16);
+        let id = app.current_plant.as_ref().unwrap().id;
+        save(&app, &config).unwrap();
+
+        app.cycle_save_format();
+        save(&app, &config).unwrap();
+        let ron_path = save_path_for(&config, SaveFormat::Ron, false);
+        assert!(fs::read_to_string(&ron_path).unwrap().starts_with('('));
+        assert!(!get_save_path(&config).exists(), "the JSON save is replaced");
+        let loaded = load(&config, ColorDepth::Basic16).unwrap();
+        assert_eq!(loaded.save_format, SaveFormat::Ron);
+        assert_eq!(loaded.current_plant.unwrap().id, id);
+
+        // A save converted by hand keeps its format, whatever the setting inside says
+        let mut json = serde_json::to_value(&app).unwrap();
+        json["save_format"] = "Ron".into();
+        fs::remove_file(&ron_path).unwrap();
+        fs::write(get_save_path(&config), json.to_string()).unwrap();
+        assert_eq!(load(&config, ColorDepth::Basic16).unwrap().save_format, SaveFormat::Json);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn gzip_is_detected_by_content_not_name() {
+        let dir = std::env::temp_dir().join(format!("ganjatui-gzip-name-{}", std::process::id()));
+        let config = SaveConfig::resolve_from(Some(dir.clone()), None).unwrap();
+        let app = App::new(ColorDepth::Basic16);
+
+        // A gzipped save renamed to save.json still loads
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(serde_json::to_string(&app).unwrap().as_bytes()).unwrap();
+        fs::create_dir_all(config.profile_dir()).unwrap();
+        fs::write(get_save_path(&config), encoder.finish().unwrap()).unwrap();
+
+        let loaded = load(&config, ColorDepth::Basic16).unwrap();
+        assert!(loaded.notice.is_none());
+        assert!(loaded.compress_saves, "the next save stays gzipped");
+        assert_eq!(loaded.current_plant.unwrap().id, app.current_plant.unwrap().id);
+
+        // A truncated gzip stream is a damaged save like any other
+        let bytes = fs::read(get_save_path(&config)).unwrap();
+        fs::write(get_save_path(&config), &bytes[..bytes.len() / 2]).unwrap();
+        assert!(load(&config, ColorDepth::Basic16).unwrap().notice.is_some());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn data_dir_follows_the_env_vars() {
+        let base = std::env::temp_dir().join(format!("ganjatui-data-dir-{}", std::process::id()));
+        let (data, xdg) = (base.join("data"), base.join("xdg"));
+
+        let platform = base.join("platform");
+        let resolve = |data_env: Option<&Path>, xdg_env: Option<&str>| {
+            data_dir_from(data_env.map(Path::to_path_buf), xdg_env.map(PathBuf::from), Some(platform.clone())).unwrap()
+        };
+
+        assert_eq!(resolve(Some(&data), Some("/xdg")), data);
+        assert!(data.is_dir(), "the directory is created as needed");
+        let xdg_str = xdg.to_str().unwrap();
+        assert_eq!(resolve(None, Some(xdg_str)), xdg.join("ganjatui"));
+        // A relative XDG_DATA_HOME is ignored in favour of the platform directory
+        assert_eq!(resolve(None, Some("relative")), platform.join("ganjatui"));
+        assert_eq!(resolve(None, None), platform.join("ganjatui"));
+        assert!(data_dir_from(None, None, None).is_err(), "no platform directory to fall back on");
+        fs::remove_dir_all(&base).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn unwritable_save_dir_fails_up_front() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = std::env::temp_dir().join(format!("ganjatui-read-only-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::set_permissions(&dir, fs::Permissions::from_mode(0o500)).unwrap();
+
+        let result = SaveConfig::resolve_from(Some(dir.clone()), None);
+        fs::set_permissions(&dir, fs::Permissions::from_mode(0o700)).unwrap();
+        // Root ignores permission bits, so there is nothing to check there
+        if let Err(e) = result {
+            assert!(e.to_string().contains("not writable"), "{}", e);
+            assert!(e.to_string().contains(&dir.display().to_string()), "{}", e);
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn save_dir_prefers_flag_over_env() {
+        let base = std::env::temp_dir().join(format!("ganjatui-save-dir-{}", std::process::id()));
+        let (flag, env) = (base.join("flag"), base.join("env"));
+
+        let config = SaveConfig::resolve_from(Some(flag.clone()), Some(env.clone())).unwrap();
+        assert_eq!(config.dir, flag);
+        assert!(flag.is_dir(), "the directory is created up front");
+        assert_eq!(SaveConfig::resolve_from(None, Some(env.clone())).unwrap().dir, env);
+
+        // Saves round-trip inside the chosen directory only
+        let app = App::new(ColorDepth::Basic16);
+        save(&app, &config).unwrap();
+        assert!(get_save_path(&config).starts_with(&flag));
+        let loaded = load(&config, ColorDepth::Basic16).unwrap();
+        assert_eq!(loaded.current_plant.unwrap().id, app.current_plant.unwrap().id);
+
+        delete_save(&config).unwrap();
+        assert!(!get_save_path(&config).exists());
+        fs::remove_dir_all(&base).unwrap();
+    }
+}
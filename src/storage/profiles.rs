@@ -0,0 +1,108 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::storage::SaveConfig;
+
+/// Profile used when none is picked
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Longest profile name kept after sanitizing
+const MAX_NAME_LEN: usize = 32;
+
+/// Turn user input into a safe directory name: letters, digits, `-` and `_`
+/// (spaces become `-`); None if nothing usable is left
+pub fn sanitize_profile_name(name: &str) -> Option<String> {
+    let clean: String = name
+        .trim()
+        .chars()
+        .filter_map(|c| match c {
+            c if c.is_ascii_alphanumeric() || c == '-' || c == '_' => Some(c.to_ascii_lowercase()),
+            ' ' => Some('-'),
+            _ => None,
+        })
+        .take(MAX_NAME_LEN)
+        .collect();
+    let clean = clean.trim_matches('-').to_string();
+    (!clean.is_empty()).then_some(clean)
+}
+
+/// Profiles with a directory under `profiles/`, sorted by name
+pub fn list_profiles(config: &SaveConfig) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(config.dir.join("profiles")) else {
+        return Vec::new();
+    };
+    let mut names: Vec<String> = entries
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| sanitize_profile_name(name).as_deref() == Some(name.as_str()))
+        .collect();
+    names.sort();
+    names
+}
+
+/// Remove a profile's saves, backups and archives
+pub fn delete_profile(config: &SaveConfig, name: &str) -> io::Result<()> {
+    let Some(clean) = sanitize_profile_name(name).filter(|clean| clean == name) else {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("bad profile name '{}'", name)));
+    };
+    fs::remove_dir_all(config.dir.join("profiles").join(clean))
+}
+
+/// Move a save from before profiles existed into the default profile
+pub(crate) fn migrate_legacy_save(root: &Path) -> io::Result<()> {
+    let default_dir = root.join("profiles").join(DEFAULT_PROFILE);
+    if !root.join("save.json").exists() || default_dir.join("save.json").exists() {
+        return Ok(());
+    }
+
+    fs::create_dir_all(&default_dir)?;
+    for entry in fs::read_dir(root)?.flatten() {
+        let name = entry.file_name();
+        let is_save_file = name.to_string_lossy().starts_with("save.json");
+        if is_save_file || name == "archive" {
+            fs::rename(entry.path(), default_dir.join(name))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_are_safe_directory_names() {
+        assert_eq!(sanitize_profile_name("Alice"), Some("alice".to_string()));
+        assert_eq!(sanitize_profile_name("  my grow "), Some("my-grow".to_string()));
+        assert_eq!(sanitize_profile_name("../../etc"), Some("etc".to_string()));
+        assert_eq!(sanitize_profile_name("a/b\\c"), Some("abc".to_string()));
+        assert_eq!(sanitize_profile_name("..."), None);
+        assert_eq!(sanitize_profile_name(""), None);
+        assert_eq!(sanitize_profile_name(&"x".repeat(100)).unwrap().len(), MAX_NAME_LEN);
+    }
+
+    #[test]
+    fn legacy_save_moves_into_the_default_profile() {
+        let root = std::env::temp_dir().join(format!("ganjatui-profiles-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("archive")).unwrap();
+        fs::write(root.join("save.json"), "{}").unwrap();
+        fs::write(root.join("save.json.1"), "{}").unwrap();
+        fs::write(root.join("keys.json"), "{}").unwrap();
+
+        migrate_legacy_save(&root).unwrap();
+        let config = SaveConfig { dir: root.clone(), profile: DEFAULT_PROFILE.to_string(), backups: 0 };
+        assert!(config.profile_dir().join("save.json").exists());
+        assert!(config.profile_dir().join("save.json.1").exists());
+        assert!(config.profile_dir().join("archive").is_dir());
+        assert!(root.join("keys.json").exists(), "settings stay shared");
+        assert_eq!(list_profiles(&config), ["default"]);
+
+        delete_profile(&config, "default").unwrap();
+        assert!(list_profiles(&config).is_empty());
+        assert!(delete_profile(&config, "../keys.json").is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
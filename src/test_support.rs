@@ -0,0 +1,29 @@
+//! Fixtures shared by the unit tests
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::domain::{HarvestResult, Plant};
+
+/// Seed behind `plant()` and `rng()`; any fixed value keeps the tests repeatable
+const FIXTURE_SEED: u64 = 42;
+
+/// A seeded RNG for tests that need dice beyond the fixture plant
+pub fn rng() -> StdRng {
+    StdRng::seed_from_u64(FIXTURE_SEED)
+}
+
+/// A fresh random plant, the same on every run
+pub fn plant() -> Plant {
+    plant_from(FIXTURE_SEED)
+}
+
+/// A fresh random plant rolled from `seed`, for tests that sweep many plants
+pub fn plant_from(seed: u64) -> Plant {
+    Plant::new_random(&mut StdRng::seed_from_u64(seed))
+}
+
+/// The harvest of `plant()`, cut as it is
+pub fn harvest() -> HarvestResult {
+    HarvestResult::from_plant(&plant())
+}
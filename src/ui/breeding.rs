@@ -0,0 +1,145 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::config::Action;
+use crate::ui::units::format_weight;
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(8),    // Lists
+            Constraint::Length(3), // Footer
+        ])
+        .split(area);
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+        .split(chunks[0]);
+
+    render_harvests(f, app, columns[0]);
+    render_inventory(f, app, columns[1]);
+
+    let keys = &app.keys;
+    let footer = Paragraph::new(format!(
+        "[{}/{}] Select  [{}] Pick parent  [{}] Cross  |  [1] Growing Room  [s] Stats",
+        keys.label(Action::CursorUp),
+        keys.label(Action::CursorDown),
+        keys.label(Action::PickParent),
+        keys.label(Action::Confirm)
+    ))
+        .block(Block::default().borders(Borders::ALL))
+        .alignment(Alignment::Center);
+    f.render_widget(footer, chunks[1]);
+}
+
+/// Harvest history with the cursor and selected parents marked
+fn render_harvests(f: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default().borders(Borders::ALL).title("[ Parents - past harvests ]");
+
+    if app.harvest_history.is_empty() {
+        let text = Paragraph::new(vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                "Harvest some plants to start breeding",
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            )),
+        ])
+        .block(block)
+        .alignment(Alignment::Center);
+        f.render_widget(text, area);
+        return;
+    }
+
+    let lines: Vec<Line> = app
+        .harvest_history
+        .iter()
+        .enumerate()
+        .map(|(i, harvest)| {
+            let cursor = if i == app.breeding_cursor { "> " } else { "  " };
+            let marker = match app.breeding_parents.iter().position(|&p| p == i) {
+                Some(0) => "[♀] ",
+                Some(_) => "[♂] ",
+                None => "    ",
+            };
+
+            let style = if harvest.genetics.is_none() {
+                Style::default().fg(Color::DarkGray)
+            } else if i == app.breeding_cursor {
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+
+            Line::from(vec![
+                Span::raw(cursor),
+                Span::styled(marker, Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD)),
+                Span::styled(
+                    format!(
+                        "{} - {}, {:.0}% quality, THC {:.1}%",
+                        harvest.strain_name,
+                        format_weight(harvest.weight_grams, app.weight_unit),
+                        harvest.quality_score,
+                        harvest.thc_percent
+                    ),
+                    style,
+                ),
+            ])
+        })
+        .collect();
+
+    // Keep the cursor in view
+    let visible = area.height.saturating_sub(2) as usize;
+    let scroll = app.breeding_cursor.saturating_sub(visible.saturating_sub(1));
+
+    let list = Paragraph::new(lines)
+        .block(block)
+        .scroll((scroll as u16, 0));
+    f.render_widget(list, area);
+}
+
+/// Seeds waiting to be planted, next one first
+fn render_inventory(f: &mut Frame, app: &App, area: Rect) {
+    let mut lines = Vec::new();
+
+    if app.seed_inventory.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from("No bred seeds yet"));
+        lines.push(Line::from("New plants use random genetics"));
+    } else {
+        for (i, seed) in app.seed_inventory.iter().enumerate() {
+            let label = if i == 0 { "Next: " } else { "      " };
+            lines.push(Line::from(vec![
+                Span::styled(label, Style::default().fg(Color::Yellow)),
+                Span::styled(
+                    seed.strain_name.clone(),
+                    Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+                ),
+            ]));
+            if let Some(ref lineage) = seed.lineage {
+                lines.push(Line::from(format!("      {}", lineage)));
+            }
+            lines.push(Line::from(format!(
+                "      THC {:.1}%  CBD {:.1}%  Yield {}",
+                seed.genetics.thc_percent,
+                seed.genetics.cbd_percent,
+                format_weight(seed.genetics.yield_potential, app.weight_unit)
+            )));
+        }
+    }
+
+    let inventory = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("[ Seed Inventory ({}) ]", app.seed_inventory.len())),
+        );
+    f.render_widget(inventory, area);
+}
@@ -0,0 +1,149 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    symbols::Marker,
+    text::{Line, Span},
+    widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::domain::{CareBands, Plant};
+
+// Minimum number of days shown on the x-axis so early grows aren't squashed
+const MIN_DAY_SPAN: f64 = 10.0;
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(10),   // Chart
+            Constraint::Length(3), // Footer
+        ])
+        .split(area);
+
+    match app.current_plant {
+        Some(ref plant) if !plant.resource_history.is_empty() => {
+            render_chart(f, plant, &app.care_bands(), chunks[0]);
+        }
+        _ => {
+            let text = Paragraph::new(vec![
+                Line::from(""),
+                Line::from(Span::styled(
+                    "No care history recorded yet",
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                )),
+            ])
+            .block(Block::default().borders(Borders::ALL).title("[ Care History ]"))
+            .alignment(Alignment::Center);
+            f.render_widget(text, chunks[0]);
+        }
+    }
+
+    let bands = app.care_bands();
+    let footer = Paragraph::new(format!(
+        "Shaded: optimal bands (Water {:.0}-{:.0}%, NPK {:.0}-{:.0}%)  |  [1] Growing Room  [s] Stats",
+        bands.water_optimal.0, bands.water_optimal.1, bands.nutrient_optimal.0, bands.nutrient_optimal.1
+    ))
+        .block(Block::default().borders(Borders::ALL))
+        .alignment(Alignment::Center);
+    f.render_widget(footer, chunks[1]);
+}
+
+fn render_chart(f: &mut Frame, plant: &Plant, bands: &CareBands, area: Rect) {
+    let water: Vec<(f64, f64)> = plant.resource_history.iter()
+        .map(|s| (s.day as f64, s.water_level as f64))
+        .collect();
+    let nutrients: Vec<(f64, f64)> = plant.resource_history.iter()
+        .map(|s| (s.day as f64, s.nutrient_level as f64))
+        .collect();
+
+    let first_day = water.first().map(|p| p.0).unwrap_or(0.0);
+    let last_day = water.last().map(|p| p.0).unwrap_or(0.0);
+    let x_bounds = [first_day, last_day.max(first_day + MIN_DAY_SPAN)];
+
+    // Shade each optimal band with a sparse dot grid, interleaving columns so
+    // the overlapping part of both bands stays readable
+    let inner_width = area.width.saturating_sub(10).max(1) as f64;
+    let inner_height = area.height.saturating_sub(4).max(1) as f64;
+    let as_f64 = |band: (f32, f32)| (band.0 as f64, band.1 as f64);
+    let water_band = band_grid(x_bounds, as_f64(bands.water_optimal), inner_width, inner_height, 0);
+    let nutrient_band = band_grid(x_bounds, as_f64(bands.nutrient_optimal), inner_width, inner_height, 1);
+
+    let datasets = vec![
+        Dataset::default()
+            .marker(Marker::Dot)
+            .graph_type(GraphType::Scatter)
+            .style(Style::default().fg(Color::Blue).add_modifier(Modifier::DIM))
+            .data(&water_band),
+        Dataset::default()
+            .marker(Marker::Dot)
+            .graph_type(GraphType::Scatter)
+            .style(Style::default().fg(Color::Green).add_modifier(Modifier::DIM))
+            .data(&nutrient_band),
+        Dataset::default()
+            .name("Water")
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(Color::Cyan))
+            .data(&water),
+        Dataset::default()
+            .name("NPK")
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(Color::LightGreen))
+            .data(&nutrients),
+    ];
+
+    let x_labels = vec![
+        Span::raw(format!("{:.0}", x_bounds[0])),
+        Span::raw(format!("{:.0}", (x_bounds[0] + x_bounds[1]) / 2.0)),
+        Span::raw(format!("{:.0}", x_bounds[1])),
+    ];
+
+    let chart = Chart::new(datasets)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("[ Care History - {} ]", plant.strain_name)),
+        )
+        .x_axis(
+            Axis::default()
+                .title("Day")
+                .style(Style::default().fg(Color::Gray))
+                .bounds(x_bounds)
+                .labels(x_labels),
+        )
+        .y_axis(
+            Axis::default()
+                .title("%")
+                .style(Style::default().fg(Color::Gray))
+                .bounds([0.0, 100.0])
+                .labels(["0", "50", "100"]),
+        );
+    f.render_widget(chart, area);
+}
+
+/// Build a grid of points covering a horizontal band, one point per cell.
+/// `column_offset` selects even (0) or odd (1) columns.
+fn band_grid(
+    x_bounds: [f64; 2],
+    band: (f64, f64),
+    width_cells: f64,
+    height_cells: f64,
+    column_offset: usize,
+) -> Vec<(f64, f64)> {
+    let x_step = (x_bounds[1] - x_bounds[0]) / width_cells;
+    let y_step = 100.0 / height_cells;
+
+    let mut points = Vec::new();
+    for col in (column_offset..width_cells as usize).step_by(2) {
+        let x = x_bounds[0] + col as f64 * x_step;
+        let mut y = band.0;
+        while y <= band.1 {
+            points.push((x, y));
+            y += y_step;
+        }
+    }
+    points
+}
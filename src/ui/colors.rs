@@ -1,5 +1,8 @@
 use ratatui::style::Color;
-use crate::domain::GrowthStage;
+use crate::domain::{Grade, GrowthStage};
+use crate::domain::genetics::Rarity;
+use crate::domain::plant::OVERWATER_LEVEL;
+use crate::ui::themes::CustomPalette;
 use std::fmt::Debug;
 
 /// Flower color intensity based on growth stage
@@ -34,8 +37,49 @@ pub trait ColorPalette: Debug + Send + Sync {
     /// Get background tint for current stage (returns None if not supported)
     fn background_tint(&self, stage: GrowthStage) -> Option<Color>;
 
+    /// Colors the palette draws with
+    fn depth(&self) -> ColorDepth;
+
     /// Check if palette supports RGB colors
-    fn supports_rgb(&self) -> bool;
+    fn supports_rgb(&self) -> bool {
+        self.depth() == ColorDepth::TrueColor
+    }
+
+    /// The RGB palette behind a quantizing one, for effects that blend
+    /// colors before they are drawn
+    fn rgb_source(&self) -> Option<&dyn ColorPalette> {
+        None
+    }
+
+    /// Snap a color built from `rgb_source` to what this palette can draw
+    fn quantize(&self, color: Color) -> Color {
+        color
+    }
+}
+
+/// How many colors the terminal can show
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorDepth {
+    #[default]
+    Basic16,
+    Indexed256,
+    TrueColor,
+}
+
+impl ColorDepth {
+    /// Depth from what the terminal reports (`supports_color`'s levels)
+    pub fn from_levels(has_256: bool, has_16m: bool) -> Self {
+        match (has_256, has_16m) {
+            (_, true) => ColorDepth::TrueColor,
+            (true, false) => ColorDepth::Indexed256,
+            (false, false) => ColorDepth::Basic16,
+        }
+    }
+
+    /// Visual modes need more than the 16 ANSI colors
+    pub fn has_visual_modes(self) -> bool {
+        self != ColorDepth::Basic16
+    }
 }
 
 /// Basic 16-color ANSI palette (fallback, current system)
@@ -140,8 +184,8 @@ impl ColorPalette for Basic16Palette {
         None // Not supported in 16-color mode
     }
 
-    fn supports_rgb(&self) -> bool {
-        false
+    fn depth(&self) -> ColorDepth {
+        ColorDepth::Basic16
     }
 }
 
@@ -151,63 +195,63 @@ impl Default for Basic16Palette {
     }
 }
 
-/// 256-color indexed palette (FUTURE IMPLEMENTATION)
-///
-/// This palette is reserved for terminals that support 256-color mode but not RGB.
-/// Currently falls back to Basic16Palette for all operations.
-///
-/// TODO: Implement 256-color indexed mapping from RGB values
-/// TODO: Add terminal detection for 256-color support (via supports-color crate)
-/// TODO: Create lookup table mapping RGB -> nearest 256-color index
+/// 256-color indexed palette: the colors of an RGB palette, snapped to
+/// the nearest xterm-256 entry
 #[derive(Debug)]
-#[allow(dead_code)] // Intentionally unused - reserved for future implementation
-pub struct Color256Palette;
+pub struct Color256Palette {
+    rgb: Box<dyn ColorPalette>,
+}
 
 impl Color256Palette {
-    #[allow(dead_code)] // Intentionally unused - reserved for future implementation
     pub fn new() -> Self {
-        Color256Palette
+        Self::wrapping(Box::new(TrueColorPalette::new()))
+    }
+
+    /// Quantize any RGB palette (the visual modes included)
+    pub fn wrapping(rgb: Box<dyn ColorPalette>) -> Self {
+        Color256Palette { rgb }
     }
 }
 
 impl ColorPalette for Color256Palette {
-    fn flower_color(&self, variant: u8, intensity: FlowerIntensity, _stage: GrowthStage) -> Color {
-        // TODO: Implement 256-color indexed mapping from RGB values
-        // For now, fallback to Basic16
-        Basic16Palette.flower_color(variant, intensity, _stage)
+    fn flower_color(&self, variant: u8, intensity: FlowerIntensity, stage: GrowthStage) -> Color {
+        quantize(self.rgb.flower_color(variant, intensity, stage))
     }
 
     fn foliage_color(&self, variant: u8, health: f32, water: f32) -> Color {
-        // TODO: Implement environmental modifiers with 256 colors
-        Basic16Palette.foliage_color(variant, health, water)
+        quantize(self.rgb.foliage_color(variant, health, water))
     }
 
     fn trunk_color(&self, variant: u8, age_days: u32) -> Color {
-        // TODO: Implement age-based color progression
-        Basic16Palette.trunk_color(variant, age_days)
+        quantize(self.rgb.trunk_color(variant, age_days))
     }
 
     fn soil_color(&self, moisture: f32) -> Color {
-        // TODO: Implement moisture-reactive soil colors
-        Basic16Palette.soil_color(moisture)
+        quantize(self.rgb.soil_color(moisture))
     }
 
     fn water_color(&self, level: f32) -> Color {
-        // TODO: Implement 256-color gradients
-        Basic16Palette.water_color(level)
+        quantize(self.rgb.water_color(level))
     }
 
     fn nutrient_color(&self, level: f32) -> Color {
-        // TODO: Implement 256-color gradients
-        Basic16Palette.nutrient_color(level)
+        quantize(self.rgb.nutrient_color(level))
     }
 
     fn background_tint(&self, _stage: GrowthStage) -> Option<Color> {
-        None // Not supported in 256-color mode
+        None // The faint tints all snap to black
     }
 
-    fn supports_rgb(&self) -> bool {
-        false
+    fn depth(&self) -> ColorDepth {
+        ColorDepth::Indexed256
+    }
+
+    fn rgb_source(&self) -> Option<&dyn ColorPalette> {
+        Some(self.rgb.as_ref())
+    }
+
+    fn quantize(&self, color: Color) -> Color {
+        quantize(color)
     }
 }
 
@@ -217,6 +261,44 @@ impl Default for Color256Palette {
     }
 }
 
+/// Channel levels of the xterm 6x6x6 color cube (indexes 16-231)
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Nearest xterm-256 index to an RGB color: the closer of the best cube
+/// entry and the best step on the grayscale ramp (232-255)
+pub fn rgb_to_256(r: u8, g: u8, b: u8) -> u8 {
+    let level = |c: u8| {
+        (0..CUBE_LEVELS.len())
+            .min_by_key(|&i| CUBE_LEVELS[i].abs_diff(c))
+            .unwrap_or(0)
+    };
+    let (ri, gi, bi) = (level(r), level(g), level(b));
+    let cube = (CUBE_LEVELS[ri], CUBE_LEVELS[gi], CUBE_LEVELS[bi]);
+
+    // Gray ramp steps are 8, 18, ... 238
+    let mean = (r as u16 + g as u16 + b as u16) / 3;
+    let step = (mean.saturating_sub(3) / 10).min(23) as u8;
+    let gray = 8 + step * 10;
+
+    let distance = |(cr, cg, cb): (u8, u8, u8)| {
+        let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+        d(r, cr) + d(g, cg) + d(b, cb)
+    };
+    if distance((gray, gray, gray)) < distance(cube) {
+        232 + step
+    } else {
+        16 + 36 * ri as u8 + 6 * gi as u8 + bi as u8
+    }
+}
+
+/// RGB colors become their nearest xterm-256 index; named colors pass through
+pub fn quantize(color: Color) -> Color {
+    match color {
+        Color::Rgb(r, g, b) => Color::Indexed(rgb_to_256(r, g, b)),
+        other => other,
+    }
+}
+
 /// True RGB 24-bit color palette (future implementation)
 #[derive(Debug)]
 pub struct TrueColorPalette;
@@ -274,73 +356,25 @@ impl ColorPalette for TrueColorPalette {
 
     fn foliage_color(&self, variant: u8, health: f32, water: f32) -> Color {
         // Base green colors (4 variants)
-        let (mut r, mut g, mut b) = match variant % 4 {
+        let base = match variant % 4 {
             0 => (60, 140, 60),   // Forest green
             1 => (80, 180, 80),   // Bright green
             2 => (100, 200, 100), // Lime green
             3 => (40, 120, 70),   // Dark green
             _ => (60, 140, 60),   // Fallback
         };
-
-        // Health-based modifications
-        if health < 40.0 {
-            // Critical: Brown tint (dying plant)
-            r = 120;
-            g = 100;
-            b = 60;
-        } else if health < 60.0 {
-            // Poor: Reduce green, add yellow (stress)
-            g = (g as f32 * 0.7) as u8;
-            r = (r as f32 * 1.3).min(255.0) as u8;
-        } else if health < 80.0 {
-            // Fair: Slightly reduce green
-            g = (g as f32 * 0.8) as u8;
-        }
-        // Good/Excellent: No modification
-
-        // Water level modifications
-        if water < 30.0 {
-            // Drought: Reduce saturation (wilting)
-            let avg = ((r as u16 + g as u16 + b as u16) / 3) as u8;
-            r = (r as f32 * 0.6 + avg as f32 * 0.4) as u8;
-            g = (g as f32 * 0.6 + avg as f32 * 0.4) as u8;
-            b = (b as f32 * 0.6 + avg as f32 * 0.4) as u8;
-        } else if water > 80.0 {
-            // Well-hydrated: Increase brightness
-            r = (r as f32 * 1.1).min(255.0) as u8;
-            g = (g as f32 * 1.1).min(255.0) as u8;
-            b = (b as f32 * 1.1).min(255.0) as u8;
-        }
-
-        Color::Rgb(r, g, b)
+        condition_foliage(base, health, water)
     }
 
     fn trunk_color(&self, variant: u8, age_days: u32) -> Color {
         // Base trunk colors (realistic wood tones)
-        let (mut r, mut g, mut b) = match variant % 3 {
+        let base = match variant % 3 {
             0 => (139, 90, 60),  // Light wood (tan/beige)
             1 => (101, 67, 33),  // Medium wood (brown)
             2 => (70, 50, 30),   // Dark wood (dark brown)
             _ => (101, 67, 33),  // Fallback
         };
-
-        // Age-based weathering (young stem → mature woody bark)
-        if age_days <= 20 {
-            // Days 1-20: Young green stem
-            // Add green tint to make it look like fresh stem
-            g = (g as f32 * 1.3).min(255.0) as u8;
-            b = (b as f32 * 0.9) as u8;
-        } else if age_days <= 50 {
-            // Days 21-50: Maturing (transition to wood)
-            r = (r as f32 + 10.0).min(255.0) as u8;
-            g = (g as f32 - 10.0).max(0.0) as u8;
-        } else {
-            // Days 51+: Mature woody bark
-            r = (r as f32 + 20.0).min(255.0) as u8;
-            g = (g as f32 - 20.0).max(0.0) as u8;
-        }
-
-        Color::Rgb(r, g, b)
+        weather_trunk(base, age_days)
     }
 
     fn soil_color(&self, moisture: f32) -> Color {
@@ -450,8 +484,8 @@ impl ColorPalette for TrueColorPalette {
         })
     }
 
-    fn supports_rgb(&self) -> bool {
-        true
+    fn depth(&self) -> ColorDepth {
+        ColorDepth::TrueColor
     }
 }
 
@@ -461,6 +495,68 @@ impl Default for TrueColorPalette {
     }
 }
 
+/// A foliage base color as the plant's health and watering leave it
+pub(crate) fn condition_foliage((mut r, mut g, mut b): (u8, u8, u8), health: f32, water: f32) -> Color {
+    // Health-based modifications
+    if health < 40.0 {
+        // Critical: Brown tint (dying plant)
+        r = 120;
+        g = 100;
+        b = 60;
+    } else if health < 60.0 {
+        // Poor: Reduce green, add yellow (stress)
+        g = (g as f32 * 0.7) as u8;
+        r = (r as f32 * 1.3).min(255.0) as u8;
+    } else if health < 80.0 {
+        // Fair: Slightly reduce green
+        g = (g as f32 * 0.8) as u8;
+    }
+    // Good/Excellent: No modification
+
+    // Water level modifications
+    if water < 30.0 {
+        // Drought: Reduce saturation (wilting)
+        let avg = ((r as u16 + g as u16 + b as u16) / 3) as u8;
+        r = (r as f32 * 0.6 + avg as f32 * 0.4) as u8;
+        g = (g as f32 * 0.6 + avg as f32 * 0.4) as u8;
+        b = (b as f32 * 0.6 + avg as f32 * 0.4) as u8;
+    } else if water > OVERWATER_LEVEL {
+        // Overwatered: dull, yellowing leaves (drowning roots)
+        let avg = ((r as u16 + g as u16 + b as u16) / 3) as u8;
+        r = (r as f32 * 0.5 + avg as f32 * 0.2 + 150.0 * 0.3) as u8;
+        g = (g as f32 * 0.6 + avg as f32 * 0.2 + 140.0 * 0.2) as u8;
+        b = (b as f32 * 0.5 + avg as f32 * 0.2) as u8;
+    } else if water > 80.0 {
+        // Well-hydrated: Increase brightness
+        r = (r as f32 * 1.1).min(255.0) as u8;
+        g = (g as f32 * 1.1).min(255.0) as u8;
+        b = (b as f32 * 1.1).min(255.0) as u8;
+    }
+
+    Color::Rgb(r, g, b)
+}
+
+/// A trunk base color weathered from green stem to woody bark with age
+pub(crate) fn weather_trunk((mut r, mut g, mut b): (u8, u8, u8), age_days: u32) -> Color {
+    // Age-based weathering (young stem → mature woody bark)
+    if age_days <= 20 {
+        // Days 1-20: Young green stem
+        // Add green tint to make it look like fresh stem
+        g = (g as f32 * 1.3).min(255.0) as u8;
+        b = (b as f32 * 0.9) as u8;
+    } else if age_days <= 50 {
+        // Days 21-50: Maturing (transition to wood)
+        r = (r as f32 + 10.0).min(255.0) as u8;
+        g = (g as f32 - 10.0).max(0.0) as u8;
+    } else {
+        // Days 51+: Mature woody bark
+        r = (r as f32 + 20.0).min(255.0) as u8;
+        g = (g as f32 - 20.0).max(0.0) as u8;
+    }
+
+    Color::Rgb(r, g, b)
+}
+
 /// Convert HSV to RGB color
 fn hsv_to_rgb(h: f32, s: f32, v: f32) -> Color {
     let c = v * s;
@@ -549,8 +645,8 @@ impl ColorPalette for RainbowPalette {
         Some(Color::Rgb(15, 10, 20))  // Subtle purple tint
     }
 
-    fn supports_rgb(&self) -> bool {
-        true
+    fn depth(&self) -> ColorDepth {
+        ColorDepth::TrueColor
     }
 }
 
@@ -616,8 +712,8 @@ impl ColorPalette for ZenPalette {
         Some(Color::Rgb(10, 12, 10))  // Very subtle gray-green
     }
 
-    fn supports_rgb(&self) -> bool {
-        true
+    fn depth(&self) -> ColorDepth {
+        ColorDepth::TrueColor
     }
 }
 
@@ -670,23 +766,118 @@ impl ColorPalette for MatrixPalette {
         Some(Color::Rgb(0, 5, 0))  // Very dark green
     }
 
-    fn supports_rgb(&self) -> bool {
-        true
+    fn depth(&self) -> ColorDepth {
+        ColorDepth::TrueColor
     }
 }
 
 /// Create appropriate color palette based on terminal capabilities and visual mode
-pub fn create_palette(supports_truecolor: bool, visual_mode: crate::ui::visual_mode::VisualMode) -> Box<dyn ColorPalette> {
-    if !supports_truecolor {
+/// A custom palette, if one is loaded, stands in for the Normal mode colors
+pub fn create_palette(
+    depth: ColorDepth,
+    visual_mode: crate::ui::visual_mode::VisualMode,
+    custom: Option<&CustomPalette>,
+) -> Box<dyn ColorPalette> {
+    match depth {
         // 16-color mode - only Normal mode available
-        return Box::new(Basic16Palette::new());
+        ColorDepth::Basic16 => return Box::new(Basic16Palette::new()),
+        // 256-color mode - the truecolor palettes, quantized
+        ColorDepth::Indexed256 => {
+            return Box::new(Color256Palette::wrapping(create_palette(ColorDepth::TrueColor, visual_mode, custom)))
+        }
+        ColorDepth::TrueColor => {}
     }
 
     // TrueColor mode - return palette based on visual mode
     match visual_mode {
-        crate::ui::visual_mode::VisualMode::Normal => Box::new(TrueColorPalette::new()),
+        crate::ui::visual_mode::VisualMode::Normal => match custom {
+            Some(palette) => Box::new(palette.clone()),
+            None => Box::new(TrueColorPalette::new()),
+        },
         crate::ui::visual_mode::VisualMode::Zen => Box::new(ZenPalette),
         crate::ui::visual_mode::VisualMode::Rainbow => Box::new(RainbowPalette),
         crate::ui::visual_mode::VisualMode::Matrix => Box::new(MatrixPalette),
     }
 }
+
+/// Color of a harvest grade letter
+pub fn grade_color(grade: Grade) -> Color {
+    match grade {
+        Grade::S => Color::LightMagenta,
+        Grade::A => Color::LightGreen,
+        Grade::B => Color::Green,
+        Grade::C => Color::Yellow,
+        Grade::D => Color::Red,
+    }
+}
+
+/// Tag color for a seed rarity tier
+pub fn rarity_color(rarity: Rarity) -> Color {
+    match rarity {
+        Rarity::Common => Color::Gray,
+        Rarity::Uncommon => Color::Green,
+        Rarity::Rare => Color::LightBlue,
+        Rarity::Legendary => Color::LightMagenta,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::visual_mode::VisualMode;
+
+    #[test]
+    fn rgb_snaps_to_known_xterm_indexes() {
+        for (rgb, index) in [
+            ((0, 0, 0), 16),
+            ((255, 255, 255), 231),
+            ((255, 0, 0), 196),
+            ((0, 255, 0), 46),
+            ((0, 0, 255), 21),
+            ((95, 135, 175), 67),
+            ((255, 175, 0), 214),
+            ((8, 8, 8), 232),
+            ((128, 128, 128), 244),
+            ((238, 238, 238), 255),
+            ((100, 100, 100), 241),
+        ] {
+            assert_eq!(rgb_to_256(rgb.0, rgb.1, rgb.2), index, "{:?}", rgb);
+        }
+    }
+
+    #[test]
+    fn near_misses_round_to_the_closest_entry() {
+        assert_eq!(rgb_to_256(100, 140, 170), 67, "cube colors round per channel");
+        assert_eq!(rgb_to_256(130, 127, 125), 244, "near-grays take the finer gray ramp");
+        assert_eq!(quantize(Color::Green), Color::Green, "named colors pass through");
+    }
+
+    #[test]
+    fn every_visual_mode_quantizes_in_256_colors() {
+        for mode in [VisualMode::Normal, VisualMode::Zen, VisualMode::Rainbow, VisualMode::Matrix] {
+            let palette = create_palette(ColorDepth::Indexed256, mode, None);
+            assert_eq!(palette.depth(), ColorDepth::Indexed256);
+            for level in [0.0, 50.0, 100.0] {
+                assert!(!matches!(palette.water_color(level), Color::Rgb(..)), "{:?}", mode);
+                assert!(!matches!(palette.foliage_color(0, level, level), Color::Rgb(..)), "{:?}", mode);
+            }
+        }
+    }
+
+    #[test]
+    fn a_custom_palette_replaces_normal_mode_only() {
+        let custom = crate::ui::themes::parse("red", r##"water = ["#ff0000"]"##).unwrap();
+        let draw = |depth, mode| create_palette(depth, mode, Some(&custom)).water_color(50.0);
+        assert_eq!(draw(ColorDepth::TrueColor, VisualMode::Normal), Color::Rgb(255, 0, 0));
+        assert_eq!(draw(ColorDepth::Indexed256, VisualMode::Normal), Color::Indexed(196));
+        assert_ne!(draw(ColorDepth::TrueColor, VisualMode::Zen), Color::Rgb(255, 0, 0));
+        assert_eq!(draw(ColorDepth::Basic16, VisualMode::Normal), Basic16Palette::new().water_color(50.0));
+    }
+
+    #[test]
+    fn depth_follows_the_best_reported_level() {
+        assert_eq!(ColorDepth::from_levels(true, true), ColorDepth::TrueColor);
+        assert_eq!(ColorDepth::from_levels(true, false), ColorDepth::Indexed256);
+        assert_eq!(ColorDepth::from_levels(false, false), ColorDepth::Basic16);
+    }
+}
@@ -0,0 +1,148 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::config::Action;
+use crate::domain::genetics::StrainInfo;
+use crate::domain::strains;
+
+/// One compared field, e.g. ("THC", "18-24%", "12-16%")
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompareRow {
+    pub label: &'static str,
+    pub left: String,
+    pub right: String,
+}
+
+impl CompareRow {
+    pub fn differs(&self) -> bool {
+        self.left != self.right
+    }
+}
+
+/// The planning-relevant fields of two strains, side by side
+pub fn compare_rows(left: &StrainInfo, right: &StrainInfo) -> Vec<CompareRow> {
+    let row = |label, value: fn(&StrainInfo) -> String| CompareRow {
+        label,
+        left: value(left),
+        right: value(right),
+    };
+    vec![
+        row("Type", |s| s.strain_type.clone()),
+        row("THC", |s| format!("{:.0}-{:.0}%", s.thc_min, s.thc_max)),
+        row("CBD", |s| format!("{:.1}-{:.1}%", s.cbd_min, s.cbd_max)),
+        row("Flowering", |s| format!("{} days", s.flowering_time)),
+        row("Difficulty", |s| s.difficulty.clone()),
+        row("Yield", |s| s.yield_potential.clone()),
+        row("Terpenes", |s| s.dominant_terpenes.join(", ")),
+    ]
+}
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(6),     // Strain pickers
+            Constraint::Length(10), // Comparison
+            Constraint::Length(3),  // Footer
+        ])
+        .split(area);
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chunks[0]);
+
+    let db = strains::database();
+    let last = db.strains.len().saturating_sub(1);
+    let [left, right] = app.compare_cursors.map(|c| c.min(last));
+
+    render_picker(f, app, &db.strains, 0, left, columns[0]);
+    render_picker(f, app, &db.strains, 1, right, columns[1]);
+
+    let comparison = match (db.strains.get(left), db.strains.get(right)) {
+        (Some(a), Some(b)) => comparison_lines(a, b),
+        _ => vec![Line::from("No strains loaded")],
+    };
+    let comparison = Paragraph::new(comparison)
+        .block(Block::default().borders(Borders::ALL).title("[ Side by Side ]"));
+    f.render_widget(comparison, chunks[1]);
+
+    let keys = &app.keys;
+    let footer = Paragraph::new(format!(
+        "[{}/{}] Pick strain  [{}] Switch side  |  [1] Growing Room  [3] Library",
+        keys.label(Action::CursorUp),
+        keys.label(Action::CursorDown),
+        keys.label(Action::SwitchSide)
+    ))
+        .block(Block::default().borders(Borders::ALL))
+        .alignment(Alignment::Center);
+    f.render_widget(footer, chunks[2]);
+}
+
+/// Strain list for one side; the focused side gets a highlighted border
+fn render_picker(f: &mut Frame, app: &App, strains: &[StrainInfo], side: usize, cursor: usize, area: Rect) {
+    let focused = app.compare_focus == side;
+    let items: Vec<ListItem> = strains.iter().map(|s| ListItem::new(s.name.clone())).collect();
+    let border = if focused {
+        Style::default().fg(Color::Cyan)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+    let title = if side == 0 { "[ Left ]" } else { "[ Right ]" };
+
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title).border_style(border))
+        .highlight_style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+        .highlight_symbol(if focused { "> " } else { "  " });
+
+    let mut state = ListState::default().with_selected(Some(cursor));
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+/// Two value columns under the strain names; differing values are tinted
+fn comparison_lines(left: &StrainInfo, right: &StrainInfo) -> Vec<Line<'static>> {
+    let heading = Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+    let mut lines = vec![Line::from(vec![
+        Span::raw(format!("{:<12}", "")),
+        Span::styled(format!("{:<32}", left.name), heading),
+        Span::styled(right.name.clone(), heading),
+    ])];
+
+    for row in compare_rows(left, right) {
+        let value = if row.differs() {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::Gray)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{:<12}", row.label), Style::default().fg(Color::DarkGray)),
+            Span::styled(format!("{:<32}", row.left), value),
+            Span::styled(row.right, value),
+        ]));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_differing_fields_are_flagged() {
+        let db = strains::database();
+        let a = &db.strains[0];
+        let mut b = a.clone();
+        b.name = "Copy".to_string();
+        b.flowering_time += 7;
+
+        assert!(compare_rows(a, a).iter().all(|r| !r.differs()));
+        let changed: Vec<_> = compare_rows(a, &b).into_iter().filter(|r| r.differs()).map(|r| r.label).collect();
+        assert_eq!(changed, ["Flowering"]);
+    }
+}
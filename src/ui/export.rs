@@ -0,0 +1,327 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use ratatui::style::Color;
+use ratatui::text::Line;
+
+use crate::app::App;
+use crate::ascii::Canvas;
+use crate::domain::{GrowthStage, HarvestResult, Plant};
+use crate::ui::growing::plant_art;
+use crate::ui::units::{format_weight, WeightUnit};
+
+/// Files the player can write out for the current plant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Export {
+    /// Colored plant picture (`.ans`) and its plain-text twin (`.txt`)
+    Art,
+    /// Markdown write-up of the grow
+    Diary,
+}
+
+impl Export {
+    pub fn name(self) -> &'static str {
+        match self {
+            Export::Art => "Plant art",
+            Export::Diary => "Grow diary",
+        }
+    }
+}
+
+/// Size the art is exported at: the classic canvas plus a few rows of roots
+const EXPORT_CANVAS: Canvas = Canvas { width: 70, height: 32, roots: 4 };
+
+/// Files written for one export
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportedArt {
+    pub ansi: PathBuf,
+    pub plain: PathBuf,
+}
+
+/// Write this frame's plant to `art/<strain>-day<N>.ans` (with color escapes)
+/// and `.txt` (without) in `dir`
+pub fn write_plant_art(app: &App, plant: &Plant, dir: &Path) -> io::Result<ExportedArt> {
+    let dir = dir.join("art");
+    fs::create_dir_all(&dir)?;
+
+    let mut lines = vec![Line::from(format!(
+        "{} - Day {} - {}",
+        plant.strain_name,
+        plant.days_alive,
+        plant.stage.as_str()
+    ))];
+    lines.extend(plant_art(app, plant, EXPORT_CANVAS, app.animation_frame));
+
+    let stem = format!("{}-day{}", file_stem(&plant.strain_name), plant.days_alive);
+    let exported = ExportedArt {
+        ansi: dir.join(format!("{}.ans", stem)),
+        plain: dir.join(format!("{}.txt", stem)),
+    };
+    fs::write(&exported.ansi, to_ansi(&lines))?;
+    fs::write(&exported.plain, to_plain(&lines))?;
+    Ok(exported)
+}
+
+/// Write a markdown grow diary to `diaries/<strain>-day<N>.md` in `dir`
+pub fn write_diary(app: &App, plant: &Plant, dir: &Path) -> io::Result<PathBuf> {
+    let dir = dir.join("diaries");
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("{}-day{}.md", file_stem(&plant.strain_name), plant.days_alive));
+    fs::write(&path, diary_markdown(plant, app.weight_unit))?;
+    Ok(path)
+}
+
+/// Stages after the seed, in the order the plant reaches them
+const DIARY_STAGES: [GrowthStage; 6] = [
+    GrowthStage::Germination,
+    GrowthStage::Seedling,
+    GrowthStage::Vegetative,
+    GrowthStage::PreFlower,
+    GrowthStage::Flowering,
+    GrowthStage::ReadyToHarvest,
+];
+
+/// The grow so far: strain, planting, stage days, stress timeline and the
+/// harvest it is heading for
+pub fn diary_markdown(plant: &Plant, unit: WeightUnit) -> String {
+    let mut md = format!("# Grow diary: {}\n\n## Strain\n\n", plant.strain_name);
+    match plant.genetics.strain_info {
+        Some(ref strain) => {
+            md.push_str(&format!("- Type: {}\n", strain.strain_type));
+            md.push_str(&format!("- Genetics: {}\n", strain.genetics));
+            md.push_str(&format!("- THC: {:.0}-{:.0}%, CBD: {:.1}-{:.1}%\n", strain.thc_min, strain.thc_max, strain.cbd_min, strain.cbd_max));
+            md.push_str(&format!("- Flowering time: {} days\n", strain.flowering_time));
+            md.push_str(&format!("- Terpenes: {}\n", strain.dominant_terpenes.join(", ")));
+        }
+        None => md.push_str("- No strain info recorded\n"),
+    }
+
+    md.push_str("\n## Planting\n\n");
+    md.push_str(&format!("- Planted: {}\n", plant.planted_at.format("%Y-%m-%d")));
+    md.push_str(&format!("- Medium: {}\n", plant.medium.name()));
+    md.push_str(&format!("- Now: day {}, {}\n", plant.days_alive, plant.stage.as_str()));
+
+    // Stages already reached show the day the plant actually got there
+    // (saves from before that was recorded fall back to the schedule)
+    md.push_str("\n## Stages\n\n");
+    let current = DIARY_STAGES.iter().position(|&stage| stage == plant.stage);
+    for (index, stage) in DIARY_STAGES.into_iter().enumerate() {
+        let recorded = plant.stage_days.iter().find(|(reached, _)| *reached == stage).map(|&(_, day)| day);
+        let day = recorded.unwrap_or_else(|| plant.stage_start_day(stage));
+        let expected = if current.is_some_and(|current| index <= current) { "" } else { " (expected)" };
+        md.push_str(&format!("- Day {}: {}{}\n", day, stage.as_str(), expected));
+    }
+
+    md.push_str("\n## Stress timeline\n\n");
+    let stress = &plant.care_history.stress_events;
+    if stress.is_empty() {
+        md.push_str("- No stress recorded\n");
+    }
+    for event in stress {
+        md.push_str(&format!("- Day {}: {} stress, {}\n", event.day, event.severity.as_str(), event.cause.as_str()));
+    }
+
+    let harvest = HarvestResult::from_plant(plant);
+    let heading = if plant.stage == GrowthStage::ReadyToHarvest { "Harvest" } else { "Projected harvest" };
+    md.push_str(&format!("\n## {}\n\n", heading));
+    md.push_str(&format!(
+        "- Yield: {} ({} bud, {} trim)\n",
+        format_weight(harvest.weight_grams, unit),
+        format_weight(harvest.bud_grams, unit),
+        format_weight(harvest.trim_grams, unit)
+    ));
+    md.push_str(&format!("- Quality: {:.0} (grade {})\n", harvest.quality_score, harvest.grade().as_str()));
+    md.push_str(&format!("- THC: {:.1}%, CBD: {:.1}%\n", harvest.thc_percent, harvest.cbd_percent));
+    md
+}
+
+/// Lowercase strain name safe for a file name, e.g. "OG Kush #1" -> "og-kush-1"
+fn file_stem(strain: &str) -> String {
+    let words: Vec<String> = strain
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect();
+    if words.is_empty() {
+        "plant".to_string()
+    } else {
+        words.join("-")
+    }
+}
+
+/// Lines with their foreground colors as ANSI escapes. The palette already
+/// picked RGB, 256 or 16 colors, so the escapes match what the terminal
+/// can show
+pub fn to_ansi(lines: &[Line]) -> String {
+    let mut out = String::new();
+    for line in lines {
+        for span in &line.spans {
+            match span.style.fg.and_then(sgr) {
+                Some(code) => out.push_str(&format!("\x1b[{}m{}\x1b[0m", code, span.content)),
+                None => out.push_str(&span.content),
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Lines as plain text, trailing spaces trimmed
+pub fn to_plain(lines: &[Line]) -> String {
+    lines
+        .iter()
+        .map(|line| {
+            let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+            text.trim_end().to_string() + "\n"
+        })
+        .collect()
+}
+
+/// SGR parameters that set `color` as the foreground
+fn sgr(color: Color) -> Option<String> {
+    let basic = match color {
+        Color::Reset => return None,
+        Color::Rgb(r, g, b) => return Some(format!("38;2;{};{};{}", r, g, b)),
+        Color::Indexed(i) => return Some(format!("38;5;{}", i)),
+        Color::Black => 30,
+        Color::Red => 31,
+        Color::Green => 32,
+        Color::Yellow => 33,
+        Color::Blue => 34,
+        Color::Magenta => 35,
+        Color::Cyan => 36,
+        Color::Gray => 37,
+        Color::DarkGray => 90,
+        Color::LightRed => 91,
+        Color::LightGreen => 92,
+        Color::LightYellow => 93,
+        Color::LightBlue => 94,
+        Color::LightMagenta => 95,
+        Color::LightCyan => 96,
+        Color::White => 97,
+    };
+    Some(basic.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::Style;
+    use ratatui::text::Span;
+
+    use crate::ui::colors::ColorDepth;
+
+    /// Split exported text back into (char, color) cells per line
+    fn parse_ansi(text: &str) -> Vec<Vec<(char, Option<Color>)>> {
+        let colors: Vec<(String, Color)> = [
+            Color::Black, Color::Red, Color::Green, Color::Yellow, Color::Blue, Color::Magenta, Color::Cyan,
+            Color::Gray, Color::DarkGray, Color::LightRed, Color::LightGreen, Color::LightYellow,
+            Color::LightBlue, Color::LightMagenta, Color::LightCyan, Color::White,
+        ]
+        .into_iter()
+        .map(|c| (sgr(c).unwrap(), c))
+        .collect();
+
+        text.lines()
+            .map(|line| {
+                let mut cells = vec![];
+                let mut color = None;
+                let mut rest = line;
+                while let Some(c) = rest.chars().next() {
+                    if let Some(escape) = rest.strip_prefix("\x1b[") {
+                        let end = escape.find('m').unwrap();
+                        let params = &escape[..end];
+                        let numbers: Vec<u8> = params.split(';').map(|n| n.parse().unwrap()).collect();
+                        color = match numbers.as_slice() {
+                            [0] => None,
+                            [38, 2, r, g, b] => Some(Color::Rgb(*r, *g, *b)),
+                            [38, 5, i] => Some(Color::Indexed(*i)),
+                            _ => Some(colors.iter().find(|(code, _)| code == params).unwrap().1),
+                        };
+                        rest = &escape[end + 1..];
+                    } else {
+                        cells.push((c, color));
+                        rest = &rest[c.len_utf8()..];
+                    }
+                }
+                cells
+            })
+            .collect()
+    }
+
+    #[test]
+    fn ansi_escapes_round_trip() {
+        let lines = vec![
+            Line::from("Header - Day 3"),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled("@@", Style::default().fg(Color::Rgb(200, 40, 180))),
+                Span::styled("|", Style::default().fg(Color::Yellow)),
+                Span::styled("~~", Style::default().fg(Color::Indexed(94))),
+            ]),
+        ];
+
+        let cells = parse_ansi(&to_ansi(&lines));
+        let expected: Vec<Vec<(char, Option<Color>)>> = lines
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .flat_map(|s| s.content.chars().map(move |c| (c, s.style.fg)))
+                    .collect()
+            })
+            .collect();
+        assert_eq!(cells, expected);
+        assert_eq!(to_plain(&lines), "Header - Day 3\n  @@|~~\n");
+    }
+
+    #[test]
+    fn the_diary_walks_through_the_grow() {
+        use crate::domain::{StressCause, StressEvent, StressSeverity};
+
+        let mut plant = crate::test_support::plant();
+        plant.days_alive = 30;
+        plant.stage = plant.calculate_stage(30);
+        let clean = diary_markdown(&plant, WeightUnit::Grams);
+        assert!(clean.contains("- No stress recorded"));
+        assert!(clean.contains("## Projected harvest"));
+        assert!(clean.contains(&format!("- Day {}: Flowering (expected)", plant.stage_start_day(GrowthStage::Flowering))));
+        assert!(clean.contains(&format!("- Day {}: Vegetative\n", plant.stage_start_day(GrowthStage::Vegetative))));
+
+        // A slow grow reached veg late; the diary keeps the day it really did
+        let late = plant.stage_start_day(GrowthStage::Vegetative) + 3;
+        plant.stage_days = vec![(GrowthStage::Germination, 1), (GrowthStage::Seedling, 4), (GrowthStage::Vegetative, late)];
+        let slow = diary_markdown(&plant, WeightUnit::Grams);
+        assert!(slow.contains(&format!("- Day {}: Vegetative\n", late)));
+
+        plant.care_history.stress_events.push(StressEvent { day: 12, severity: StressSeverity::Moderate, cause: StressCause::LowWater });
+        let stressed = diary_markdown(&plant, WeightUnit::Grams);
+        assert!(stressed.contains("- Day 12: Moderate stress, thirsty"));
+        assert!(stressed.starts_with(&format!("# Grow diary: {}\n", plant.strain_name)));
+    }
+
+    #[test]
+    fn exports_land_in_the_art_folder() {
+        let dir = std::env::temp_dir().join(format!("ganjatui-art-{}", std::process::id()));
+        let app = App::new(ColorDepth::Basic16);
+        let mut plant = crate::test_support::plant();
+        plant.strain_name = "OG Kush #1".to_string();
+        plant.days_alive = 12;
+
+        let exported = write_plant_art(&app, &plant, &dir).unwrap();
+        assert_eq!(exported.plain, dir.join("art").join("og-kush-1-day12.txt"));
+
+        let plain = fs::read_to_string(&exported.plain).unwrap();
+        assert!(plain.starts_with("OG Kush #1 - Day 12 - "));
+        assert!(plain.contains('~'), "the soil line is in the picture");
+        let ansi = fs::read_to_string(&exported.ansi).unwrap();
+        assert!(ansi.contains("\x1b["));
+        let stripped: Vec<String> = parse_ansi(&ansi)
+            .into_iter()
+            .map(|cells| cells.into_iter().map(|(c, _)| c).collect::<String>().trim_end().to_string())
+            .collect();
+        assert_eq!(stripped.join("\n") + "\n", plain);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,103 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Gauge, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::config::Action;
+use crate::domain::goals::Goal;
+use crate::ui::units::{format_weight, WeightUnit};
+
+/// Goal text, e.g. "Harvest 500.0g total"
+pub fn description(goal: &Goal, unit: WeightUnit) -> String {
+    match *goal {
+        Goal::TotalYield { grams } => format!("Harvest {} total", format_weight(grams, unit)),
+        Goal::QualityGrow { quality } => format!("Finish a {:.0}% quality grow", quality),
+        Goal::Harvests { count } => format!("Harvest {} plants", count),
+    }
+}
+
+/// Current standing, e.g. "320.0g / 500.0g"
+fn progress_label(app: &App, goal: &Goal) -> String {
+    let (current, target) = goal.standing(&app.lifetime);
+    match goal {
+        Goal::TotalYield { .. } => format!(
+            "{} / {}",
+            format_weight(current, app.weight_unit),
+            format_weight(target, app.weight_unit)
+        ),
+        Goal::QualityGrow { .. } => format!("best {:.0}% / {:.0}%", current, target),
+        Goal::Harvests { .. } => format!("{:.0} / {:.0}", current, target),
+    }
+}
+
+/// Gauge for the active goal, e.g. on the stats screen
+pub fn goal_gauge(app: &App, goal: &Goal) -> Gauge<'static> {
+    let progress = goal.progress(&app.lifetime);
+    let (title, color) = if app.goal_completed {
+        (format!("Goal complete: {}", description(goal, app.weight_unit)), Color::LightGreen)
+    } else {
+        (format!("Goal: {}", description(goal, app.weight_unit)), Color::Yellow)
+    };
+    Gauge::default()
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .gauge_style(Style::default().fg(color))
+        .ratio(progress as f64)
+        .label(progress_label(app, goal))
+}
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Active goal
+            Constraint::Min(5),    // Presets
+            Constraint::Length(3), // Footer
+        ])
+        .split(area);
+
+    match app.goal {
+        Some(ref goal) => f.render_widget(goal_gauge(app, goal), chunks[0]),
+        None => {
+            let none = Paragraph::new("No goal set - pick one below")
+                .block(Block::default().borders(Borders::ALL).title("Goal"))
+                .alignment(Alignment::Center);
+            f.render_widget(none, chunks[0]);
+        }
+    }
+
+    let lines: Vec<Line> = Goal::PRESETS
+        .iter()
+        .enumerate()
+        .map(|(i, goal)| {
+            let cursor = if i == app.goal_cursor { "> " } else { "  " };
+            let active = if app.goal.as_ref() == Some(goal) { " (active)" } else { "" };
+            let style = if i == app.goal_cursor {
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            Line::from(vec![
+                Span::raw(cursor),
+                Span::styled(format!("{}{}", description(goal, app.weight_unit), active), style),
+            ])
+        })
+        .collect();
+    let presets = Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title("[ Season Goals ]"));
+    f.render_widget(presets, chunks[1]);
+
+    let keys = &app.keys;
+    let footer = Paragraph::new(format!(
+        "[{}/{}] Select  [{}] Set goal  [{}] Clear goal  |  [1] Growing Room  [s] Stats",
+        keys.label(Action::CursorUp),
+        keys.label(Action::CursorDown),
+        keys.label(Action::Confirm),
+        keys.label(Action::ClearGoal)
+    ))
+        .block(Block::default().borders(Borders::ALL))
+        .alignment(Alignment::Center);
+    f.render_widget(footer, chunks[2]);
+}
@@ -1,3 +1,4 @@
+use chrono::Utc;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -8,28 +9,49 @@ use ratatui::{
 
 use crate::app::App;
 use crate::ascii::{
-    get_border_decoration, get_nutrient_sparkles, get_plant_ascii, get_water_drops,
+    falling_leaves, frost_score, get_border_decoration, get_jar_ascii, get_nutrient_sparkles, get_plant_art,
+    get_water_drops, hires, Appearance, Canvas, CellKind, HeightClass, LeafDrop, Phenotype, PlantTraits, Stretch,
 };
-use crate::domain::Plant;
-use crate::ui::colors::FlowerIntensity;
+use crate::domain::curing::{CuringJar, JAR_SPIKE_HUMIDITY};
+use crate::domain::{Plant, StressCause, StressEvent, StressSeverity};
+use crate::domain::genetics::{potency_label, Mutation};
+use crate::domain::harvest;
+use crate::domain::plant::{
+    HUMIDITY_OPTIMAL_MAX, HUMIDITY_OPTIMAL_MIN, OVERWATER_LEVEL, TEMP_OPTIMAL_MAX, TEMP_OPTIMAL_MIN,
+};
+use crate::config::Action;
+use crate::ui::colors::{rarity_color, ColorPalette, FlowerIntensity};
+use crate::ui::strains::{characteristics_lines, profile_lines, section_heading};
+use crate::ui::units::{format_ago, format_weight};
 
-// Environmental thresholds for visual feedback
-const TEMP_OPTIMAL_MIN: f32 = 20.0;
-const TEMP_OPTIMAL_MAX: f32 = 28.0;
+// Environmental thresholds for visual feedback (optimal bands live in domain::plant)
 const TEMP_ACCEPTABLE_MIN: f32 = 18.0;
 const TEMP_ACCEPTABLE_MAX: f32 = 30.0;
 
-const HUMIDITY_OPTIMAL_MIN: f32 = 50.0;
-const HUMIDITY_OPTIMAL_MAX: f32 = 70.0;
 const HUMIDITY_ACCEPTABLE_MIN: f32 = 40.0;
 const HUMIDITY_ACCEPTABLE_MAX: f32 = 80.0;
 
 const GROWTH_GOOD_THRESHOLD: f32 = 60.0;
 const GROWTH_FAIR_THRESHOLD: f32 = 30.0;
 
-// Flower intensity day thresholds
-const FLOWER_DEVELOPING_DAY: u32 = 61;
-const FLOWER_PEAK_DAY: u32 = 71;
+// Bottom canvas rows that count as lower leaves (yellow first when overwatered)
+const LOWER_LEAF_ROWS: usize = 9;
+
+// Rows below the soil given to the roots in large layouts
+const ROOT_ROWS: usize = 4;
+
+// Brightness of the roots relative to the trunk
+const ROOT_DIMMING: f32 = 0.6;
+
+// Every Nth bud cell sparkles on Extra Resinous plants
+const RESIN_SPARKLE_SPACING: usize = 4;
+
+// Flower color variant whose harvest white colors trichome frost
+const FROSTY_FLOWER_VARIANT: u8 = 5;
+
+// Flower intensity thresholds, as bud ripeness through the flowering stage
+const FLOWER_DEVELOPING_RIPENESS: f32 = 0.33;
+const FLOWER_PEAK_RIPENESS: f32 = 0.6;
 
 /// Applies a breathing effect to a color by adjusting brightness
 /// In RGB mode, multiplies RGB values by the factor (0.8-1.0 range for subtle effect)
@@ -49,15 +71,275 @@ fn apply_breathing(color: Color, factor: f32) -> Color {
     }
 }
 
+/// Bleaches a color toward white (light-burned bud tips)
+/// In RGB mode blends 60% toward white, in 16-color mode uses plain white
+fn apply_bleaching(color: Color) -> Color {
+    match color {
+        Color::Rgb(r, g, b) => Color::Rgb(
+            (r as f32 + (255.0 - r as f32) * 0.6) as u8,
+            (g as f32 + (255.0 - g as f32) * 0.6) as u8,
+            (b as f32 + (255.0 - b as f32) * 0.6) as u8,
+        ),
+        _ => Color::White,
+    }
+}
+
+/// Yellows a leaf color (overwatering)
+/// In RGB mode blends 50% toward a sickly yellow, in 16-color mode uses plain yellow
+fn apply_yellowing(color: Color) -> Color {
+    match color {
+        Color::Rgb(r, g, b) => Color::Rgb(
+            (r as f32 * 0.5 + 200.0 * 0.5) as u8,
+            (g as f32 * 0.5 + 180.0 * 0.5) as u8,
+            (b as f32 * 0.5 + 60.0 * 0.5) as u8,
+        ),
+        _ => Color::Yellow,
+    }
+}
+
+/// Foliage color for the Albino mutation
+fn albino_foliage(supports_rgb: bool) -> Color {
+    if supports_rgb {
+        Color::Rgb(235, 235, 225)
+    } else {
+        Color::White
+    }
+}
+
+/// Colors for leaves knocked off by stress: yellow on the way down, brown on the soil
+fn dying_leaf(landed: bool, supports_rgb: bool) -> Color {
+    match (landed, supports_rgb) {
+        (false, true) => Color::Rgb(200, 170, 60),
+        (true, true) => Color::Rgb(130, 90, 40),
+        (false, false) => Color::Yellow,
+        (true, false) => Color::DarkGray,
+    }
+}
+
+/// Leaves a stress event knocks off; drought and nutrient burn hit hardest
+fn leaf_drop(event: &StressEvent) -> LeafDrop {
+    let leaves = match event.severity {
+        StressSeverity::Minor => 1,
+        StressSeverity::Moderate => 2,
+        StressSeverity::Severe => 4,
+    } + match event.cause {
+        StressCause::LowWater | StressCause::NutrientBurn => 2,
+        _ => 0,
+    };
+    LeafDrop { day: event.day, leaves }
+}
+
+/// Tints a foliage color toward purple (Purple Pheno mutation)
+/// In 16-color mode the leaves stay green; only the buds turn purple
+fn tint_purple(color: Color) -> Color {
+    match color {
+        Color::Rgb(r, g, b) => Color::Rgb(
+            (r as f32 * 0.6 + 140.0 * 0.4) as u8,
+            (g as f32 * 0.6 + 60.0 * 0.4) as u8,
+            (b as f32 * 0.6 + 160.0 * 0.4) as u8,
+        ),
+        other => other,
+    }
+}
+
+/// Foliage color before breathing, with the mutation's look applied
+fn mutated_foliage(palette: &dyn ColorPalette, mutation: Option<Mutation>, variant: u8, health: f32, water: f32) -> Color {
+    match mutation {
+        Some(Mutation::Albino) => albino_foliage(palette.supports_rgb()),
+        Some(Mutation::PurplePheno) => tint_purple(palette.foliage_color(variant, health, water)),
+        _ => palette.foliage_color(variant, health, water),
+    }
+}
+
 pub fn render(f: &mut Frame, app: &App, area: Rect) {
     if let Some(ref plant) = app.current_plant {
-        render_plant(f, plant, area, app.animation_frame, app);
+        render_plant(f, plant, area, motion_frame(app), app);
     } else {
         render_no_plant(f, area);
     }
 }
 
+/// Frame that drives the animations; reduced motion holds it at the first
+/// one so nothing on screen moves
+fn motion_frame(app: &App) -> usize {
+    if app.reduced_motion {
+        0
+    } else {
+        app.animation_frame
+    }
+}
+
+/// Border style for a resource gauge: blinks red while the level is critical
+/// (steady red when `still`, for reduced motion)
+fn critical_blink(critical: bool, frame: usize, still: bool) -> Style {
+    if critical && (still || frame % 8 < 4) {
+        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default()
+    }
+}
+
+/// One gauge on the resources dashboard
+struct GaugeSpec {
+    title: String,
+    border: Style,
+    percent: u16,
+    color: Color,
+    label: String,
+}
+
+impl GaugeSpec {
+    /// A gauge with an ordinary border
+    fn plain(title: impl Into<String>, percent: u16, color: Color, label: String) -> Self {
+        Self { title: title.into(), border: Style::default(), percent, color, label }
+    }
+}
+
+/// Bordered gauge with a title, bar color and label
+fn labeled_gauge(title: String, border: Style, percent: u16, color: Color, label: String) -> Gauge<'static> {
+    Gauge::default()
+        .block(Block::default().borders(Borders::ALL).border_style(border).title(title))
+        .gauge_style(Style::default().fg(color))
+        .percent(percent)
+        .label(label)
+}
+
+/// Gauges in rows of three, each row three lines tall, from the top of `area`
+fn render_dashboard<const ROWS: usize>(f: &mut Frame, rows: [[GaugeSpec; 3]; ROWS], area: Rect) {
+    let row_areas = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3); ROWS])
+        .split(area);
+
+    for (row, row_area) in rows.into_iter().zip(row_areas.iter()) {
+        let cells = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(33),
+                Constraint::Percentage(33),
+                Constraint::Percentage(34),
+            ])
+            .split(*row_area);
+        for (spec, cell) in row.into_iter().zip(cells.iter()) {
+            f.render_widget(labeled_gauge(spec.title, spec.border, spec.percent, spec.color, spec.label), *cell);
+        }
+    }
+}
+
+/// Scroll recent grow-room events right to left, newest first
+fn render_event_ticker(f: &mut Frame, app: &App, area: Rect) {
+    if app.events.is_empty() || area.width == 0 {
+        return;
+    }
+
+    let feed: String = app
+        .events
+        .iter()
+        .rev()
+        .map(|event| format!("Day {}: {}", event.day, event.text))
+        .collect::<Vec<_>>()
+        .join("  •  ");
+    let feed = format!("{}  •  ", feed);
+    let chars: Vec<char> = feed.chars().collect();
+
+    // Advance one column every other frame so the text stays readable
+    let offset = (motion_frame(app) / 2) % chars.len();
+    let visible: String = chars
+        .iter()
+        .cycle()
+        .skip(offset)
+        .take(area.width as usize)
+        .collect();
+
+    let ticker = Paragraph::new(visible).style(Style::default().fg(Color::DarkGray));
+    f.render_widget(ticker, area);
+}
+
+/// One-line progress for hanging batches, e.g. "Drying: Northern Lights - day 4/9"
+fn render_drying(f: &mut Frame, app: &App, area: Rect) {
+    if app.drying.is_empty() || area.height == 0 {
+        return;
+    }
+
+    let mut spans = vec![Span::styled("Drying: ", Style::default().fg(Color::Yellow))];
+    for (i, batch) in app.drying.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::raw("  |  "));
+        }
+        let (status, color) = if batch.is_dry() {
+            (format!("dry! [{}] take down", app.keys.label(Action::FinishDrying)), Color::LightGreen)
+        } else {
+            (batch.day_label(), Color::Gray)
+        };
+        spans.push(Span::styled(
+            format!(
+                "{} - {} ({})",
+                batch.strain_name,
+                status,
+                format_weight(batch.current_grams(), app.weight_unit)
+            ),
+            Style::default().fg(color),
+        ));
+    }
+
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+/// Curing jar art with its humidity, quality and cure progress
+fn render_jar(f: &mut Frame, app: &App, jar: &CuringJar, area: Rect) {
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(12), Constraint::Min(10)])
+        .split(area);
+
+    let humidity_color = if jar.humidity > JAR_SPIKE_HUMIDITY {
+        Color::Red
+    } else if jar.is_overdue() {
+        Color::Yellow
+    } else {
+        Color::Green
+    };
+    let art: Vec<Line> = get_jar_ascii(jar.fill(), jar.is_overdue(), motion_frame(app))
+        .into_iter()
+        .map(|row| Line::from(Span::styled(row, Style::default().fg(Color::LightGreen))))
+        .collect();
+    f.render_widget(Paragraph::new(art), columns[0]);
+
+    let status = if jar.is_cured() {
+        Span::styled(
+            format!("Cured! [{}] open", app.keys.label(Action::FinishCure)),
+            Style::default().fg(Color::LightGreen).add_modifier(Modifier::BOLD),
+        )
+    } else if jar.is_overdue() {
+        Span::styled(
+            format!("Needs a burp! [{}]", app.keys.label(Action::BurpJar)),
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        )
+    } else {
+        Span::styled(
+            format!("Day {}/{}", jar.elapsed_days as u32 + 1, jar.target_days.round() as u32),
+            Style::default().fg(Color::Gray),
+        )
+    };
+    let info = vec![
+        Line::from(Span::styled(
+            jar.harvest.strain_name.clone(),
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(format_weight(jar.harvest.weight_grams, app.weight_unit)),
+        Line::from(vec![
+            Span::raw("RH: "),
+            Span::styled(format!("{:.0}%", jar.humidity), Style::default().fg(humidity_color)),
+        ]),
+        Line::from(format!("Quality: {:.0}%", jar.harvest.quality_score)),
+        Line::from(format!("Cure: {:.0}%", jar.progress() * 100.0)),
+        Line::from(status),
+    ];
+    f.render_widget(Paragraph::new(info), columns[1]);
+}
+
 fn render_plant(f: &mut Frame, plant: &Plant, area: Rect, frame: usize, app: &App) {
+    let drying_rows = if app.drying.is_empty() { 0 } else { 1 };
     let main_chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
@@ -70,6 +352,8 @@ fn render_plant(f: &mut Frame, plant: &Plant, area: Rect, frame: usize, app: &Ap
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3),  // Header
+            Constraint::Length(1),  // Event ticker
+            Constraint::Length(drying_rows), // Drying batches
             Constraint::Min(10),    // Plant display
             Constraint::Length(11), // Resources (3 rows)
             Constraint::Length(3),  // Controls
@@ -82,12 +366,17 @@ fn render_plant(f: &mut Frame, plant: &Plant, area: Rect, frame: usize, app: &Ap
     // Animated header with speed indicator
     let decoration = get_border_decoration(frame);
     let speed_indicator = if frame % 4 < 2 { ">" } else { "<" };
+    let stage_label = if plant.is_stretching() {
+        format!("{} (Stretching!)", plant.stage.as_str())
+    } else {
+        plant.stage.as_str().to_string()
+    };
     let header = Paragraph::new(format!(
         "{} GanjaTUI [{}] - Day {} | {} | {} {} [By ZeD {}]",
         decoration,
         layout_mode.indicator(),
         plant.days_alive,
-        plant.stage.as_str(),
+        stage_label,
         app.visual_mode.name(),
         decoration,
         speed_indicator
@@ -101,168 +390,21 @@ fn render_plant(f: &mut Frame, plant: &Plant, area: Rect, frame: usize, app: &Ap
     );
     f.render_widget(header, chunks[0]);
 
-    // Animated plant display - procedurally generated based on plant ID
-    let seed = plant.id.as_u128() as u64;
-    let plant_ascii = get_plant_ascii(plant.stage, plant.days_alive, seed, frame);
-
-    // Determine color variants based on genetics (seed) - each plant has unique colors!
-    let flower_color_variant = (seed % 6) as u8;
-    let foliage_color_variant = ((seed / 6) % 4) as u8;
-    let trunk_color_variant = ((seed / 24) % 3) as u8;
-
-    // Calculate flower intensity based on growth stage AND days alive for progression
-    // Days 49-60: Early, 61-70: Developing, 71-85: Peak, 86+: Harvest
-    let (flower_intensity_1, flower_intensity_2, flower_intensity_3) = match plant.stage {
-        crate::domain::GrowthStage::Flowering => {
-            if plant.days_alive < FLOWER_DEVELOPING_DAY {
-                (FlowerIntensity::Early, FlowerIntensity::Early, FlowerIntensity::Developing)
-            } else if plant.days_alive < FLOWER_PEAK_DAY {
-                (FlowerIntensity::Developing, FlowerIntensity::Developing, FlowerIntensity::Peak)
-            } else {
-                // Late flowering (Peak intensity)
-                (FlowerIntensity::Peak, FlowerIntensity::Peak, FlowerIntensity::Peak)
-            }
-        }
-        crate::domain::GrowthStage::ReadyToHarvest => {
-            (FlowerIntensity::Harvest, FlowerIntensity::Harvest, FlowerIntensity::Harvest)
-        }
-        _ => {
-            // PreFlower or earlier
-            (FlowerIntensity::Early, FlowerIntensity::Early, FlowerIntensity::Early)
-        }
-    };
-
-    // Get colors from palette (uses RGB in truecolor mode, 16-color fallback otherwise)
-    let palette = &app.color_palette;
-
-    // Foliage color with environmental modifiers (health, water level)
-    let health_percent = match plant.health {
-        crate::domain::HealthStatus::Excellent => 100.0,
-        crate::domain::HealthStatus::Good => 80.0,
-        crate::domain::HealthStatus::Fair => 60.0,
-        crate::domain::HealthStatus::Poor => 40.0,
-        crate::domain::HealthStatus::Critical => 20.0,
-    };
-    let base_foliage_color = palette.foliage_color(foliage_color_variant, health_percent, plant.water_level);
-
-    // Apply breathing effect to foliage and flowers (12.5% amplitude for visible pulsing)
-    // Mode-specific breathing speeds for different aesthetics
-    let breath_speed = match app.visual_mode {
-        crate::ui::visual_mode::VisualMode::Normal => 0.05,   // Normal speed
-        crate::ui::visual_mode::VisualMode::Zen => 0.02,      // Slower (calming)
-        crate::ui::visual_mode::VisualMode::Rainbow => 0.08,  // Faster (energetic)
-        crate::ui::visual_mode::VisualMode::Matrix => 0.06,   // Medium-fast (digital)
-    };
-    let breath_factor = 0.875 + ((frame as f32 * breath_speed).sin() * 0.125); // 0.75-1.00 range (12.5% amplitude)
-    let foliage_color = apply_breathing(base_foliage_color, breath_factor);
-
-    // Flower colors with intensity progression + breathing effect
-    let base_flower_color_1 = palette.flower_color(flower_color_variant, flower_intensity_1, plant.stage);
-    let base_flower_color_2 = palette.flower_color(flower_color_variant, flower_intensity_2, plant.stage);
-    let base_flower_color_3 = palette.flower_color(flower_color_variant, flower_intensity_3, plant.stage);
-
-    let flower_color_1 = apply_breathing(base_flower_color_1, breath_factor);
-    let flower_color_2 = apply_breathing(base_flower_color_2, breath_factor);
-    let flower_color_3 = apply_breathing(base_flower_color_3, breath_factor);
-
-    // Trunk color with age progression
-    let trunk_color = palette.trunk_color(trunk_color_variant, plant.days_alive);
-
-    // Soil color (moisture-reactive)
-    let soil_color = palette.soil_color(plant.water_level);
-
-    // Build content lines first with colorization
-    let mut content_lines = vec![];
-    for line in plant_ascii {
-        // Colorize each character based on type and growth stage
-        let mut spans = vec![];
-        let mut current_chars = String::new();
-        let mut current_color = None;
-
-        for ch in line.chars() {
-            let color = match ch {
-                // Trunk characters - varied wood tones
-                '|' | '!' | 'I' | '║' => Some(trunk_color),
-
-                // Branch characters - varied green tones
-                '/' | '\\' | '_' | '=' => match plant.stage {
-                    crate::domain::GrowthStage::Seed | crate::domain::GrowthStage::Germination => {
-                        Some(Color::DarkGray)
-                    }
-                    crate::domain::GrowthStage::Seedling => Some(Color::Green),
-                    _ => Some(foliage_color),
-                },
+    render_event_ticker(f, app, chunks[1]);
+    render_drying(f, app, chunks[2]);
 
-                // Flower/bud characters - SUPER VIBRANT when ready!
-                '*' => {
-                    match plant.stage {
-                        crate::domain::GrowthStage::Flowering => Some(flower_color_1),
-                        crate::domain::GrowthStage::ReadyToHarvest => Some(flower_color_3), // VIBRANT!
-                        _ => Some(foliage_color),
-                    }
-                }
-                'o' => {
-                    match plant.stage {
-                        crate::domain::GrowthStage::PreFlower => Some(Color::Yellow),
-                        crate::domain::GrowthStage::Flowering => Some(flower_color_1),
-                        crate::domain::GrowthStage::ReadyToHarvest => Some(flower_color_3), // VIBRANT!
-                        _ => Some(foliage_color),
-                    }
-                }
-                'O' => {
-                    match plant.stage {
-                        crate::domain::GrowthStage::Flowering => Some(flower_color_2),
-                        crate::domain::GrowthStage::ReadyToHarvest => Some(flower_color_3), // VIBRANT!
-                        _ => Some(foliage_color),
-                    }
-                }
-                '@' | '#' => {
-                    match plant.stage {
-                        crate::domain::GrowthStage::Flowering => Some(flower_color_2),
-                        crate::domain::GrowthStage::ReadyToHarvest => Some(flower_color_3), // VIBRANT!
-                        _ => Some(foliage_color),
-                    }
-                }
-
-                // Foliage - varied greens
-                ':' => Some(foliage_color),
-
-                // Soil - moisture-reactive
-                '~' => Some(soil_color),
-
-                // Spaces and other characters - no color
-                _ => None,
-            };
-
-            // If color changed, flush current buffer
-            if current_color != color && !current_chars.is_empty() {
-                if let Some(c) = current_color {
-                    spans.push(Span::styled(current_chars.clone(), Style::default().fg(c)));
-                } else {
-                    spans.push(Span::raw(current_chars.clone()));
-                }
-                current_chars.clear();
-            }
-
-            current_chars.push(ch);
-            current_color = color;
-        }
-
-        // Flush remaining characters
-        if !current_chars.is_empty() {
-            if let Some(c) = current_color {
-                spans.push(Span::styled(current_chars, Style::default().fg(c)));
-            } else {
-                spans.push(Span::raw(current_chars));
-            }
-        }
-
-        content_lines.push(Line::from(spans));
+    // Fill the plant panel inside its borders; large layouts keep a few rows for the roots
+    let plant_area = Block::default().borders(Borders::ALL).inner(chunks[3]);
+    let mut display = Canvas::fit(plant_area.width, plant_area.height);
+    if layout_mode == crate::ui::layout::LayoutMode::Large {
+        display = display.with_roots(ROOT_ROWS);
     }
+    let content_lines = plant_art(app, plant, display, frame);
+    let palette = &app.color_palette;
 
     // Fixed positioning - add padding at TOP to push plant to bottom
     // This keeps the soil line always at the same position
-    let available_height = chunks[1].height.saturating_sub(2) as usize; // Subtract borders
+    let available_height = chunks[3].height.saturating_sub(2) as usize; // Subtract borders
     let content_height = content_lines.len();
     let padding_top = available_height.saturating_sub(content_height);
 
@@ -278,131 +420,113 @@ fn render_plant(f: &mut Frame, plant: &Plant, area: Rect, frame: usize, app: &Ap
         plant_style = plant_style.bg(bg_color);
     }
 
+    // Flash the border when the plant has just become ready to harvest
+    let mut plant_block = Block::default().borders(Borders::ALL).title("[ Plant ]");
+    if app.harvest_flash_frames > 0 && frame % 4 < 2 {
+        plant_block = plant_block
+            .title("[ Plant - READY! ]")
+            .border_style(Style::default().fg(Color::LightYellow).add_modifier(Modifier::BOLD));
+    }
+
     let plant_display = Paragraph::new(plant_lines)
-        .block(Block::default().borders(Borders::ALL).title("[ Plant ]"))
+        .block(plant_block)
         .alignment(Alignment::Center)
         .style(plant_style);
-    f.render_widget(plant_display, chunks[1]);
-
-    // Dynamic metrics - 3 rows of gauges (things that change frequently)
-    let resources_rows = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(3), // Row 1: Water, Nutrients, Growth Progress
-            Constraint::Length(3), // Row 2: Temperature, Humidity, Roots/Canopy
-            Constraint::Length(3), // Row 3: Health
-        ])
-        .split(chunks[2]);
+    f.render_widget(plant_display, chunks[3]);
 
-    let row1_chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage(33),
-            Constraint::Percentage(33),
-            Constraint::Percentage(34),
-        ])
-        .split(resources_rows[0]);
-
-    let row2_chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage(33),
-            Constraint::Percentage(33),
-            Constraint::Percentage(34),
-        ])
-        .split(resources_rows[1]);
+    // Gauges draw eased readings so big time jumps don't make them snap
+    let water_level = app.gauges.water.or(plant.water_level);
+    let nutrient_level = app.gauges.nutrients.or(plant.nutrient_level);
+    let temperature = app.gauges.temperature.or(plant.temperature);
+    let humidity = app.gauges.humidity.or(plant.humidity);
 
     // Water gauge with animated drops - RGB gradient in truecolor mode
-    let water_color = palette.water_color(plant.water_level);
-
     let water_drops = get_water_drops(frame);
-    let water_gauge = Gauge::default()
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(format!("Water{}", water_drops)),
-        )
-        .gauge_style(Style::default().fg(water_color))
-        .percent(plant.water_level as u16)
-        .label(format!("{:.0}%", plant.water_level));
-    f.render_widget(water_gauge, row1_chunks[0]);
+    let bands = app.care_bands();
+    let water_band = format!("({:.0}-{:.0}%)", bands.water_optimal.0, bands.water_optimal.1);
+    let water_title = if plant.is_overwatered() {
+        format!("Water {}{} - Overwatered!", water_band, water_drops)
+    } else {
+        format!("Water {}{}", water_band, water_drops)
+    };
+    let water = GaugeSpec {
+        title: water_title,
+        border: critical_blink(water_level < bands.water_critical, frame, app.reduced_motion),
+        percent: water_level as u16,
+        color: palette.water_color(water_level),
+        label: format!("{:.0}%", water_level),
+    };
 
     // Nutrient gauge with animated sparkles - RGB gradient in truecolor mode
-    let nutrient_color = palette.nutrient_color(plant.nutrient_level);
-
-    let sparkles = get_nutrient_sparkles(frame);
-    let nutrient_gauge = Gauge::default()
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(format!("NPK{}", sparkles)),
-        )
-        .gauge_style(Style::default().fg(nutrient_color))
-        .percent(plant.nutrient_level as u16)
-        .label(format!("{:.0}%", plant.nutrient_level));
-    f.render_widget(nutrient_gauge, row1_chunks[1]);
+    let nutrients = GaugeSpec {
+        title: format!(
+            "NPK ({:.0}-{:.0}%){}",
+            bands.nutrient_optimal.0, bands.nutrient_optimal.1, get_nutrient_sparkles(frame)
+        ),
+        border: critical_blink(nutrient_level < bands.nutrient_critical, frame, app.reduced_motion),
+        percent: nutrient_level as u16,
+        color: palette.nutrient_color(nutrient_level),
+        label: format!("{:.0}%", nutrient_level),
+    };
 
     // Growth Progress gauge - % to next stage (changes every day!)
-    let (current_day, next_stage_day, stage_name): (u32, u32, &str) = match plant.stage {
-        crate::domain::GrowthStage::Seed | crate::domain::GrowthStage::Germination => {
-            (plant.days_alive, 11, "Vegetative")
+    // Stage boundaries come from the plant so fast/slow genetics stay accurate
+    use crate::domain::GrowthStage;
+    let (next_stage, stage_name) = match plant.stage {
+        GrowthStage::Seed | GrowthStage::Germination | GrowthStage::Seedling => {
+            (GrowthStage::Vegetative, "Vegetative")
         }
-        crate::domain::GrowthStage::Seedling => (plant.days_alive, 11, "Vegetative"),
-        crate::domain::GrowthStage::Vegetative => (plant.days_alive, 41, "Pre-Flower"),
-        crate::domain::GrowthStage::PreFlower => (plant.days_alive, 49, "Flowering"),
-        crate::domain::GrowthStage::Flowering => (plant.days_alive, 86, "Harvest"),
-        crate::domain::GrowthStage::ReadyToHarvest => (86, 86, "Ready!"),
+        GrowthStage::Vegetative => (GrowthStage::PreFlower, "Pre-Flower"),
+        GrowthStage::PreFlower => (GrowthStage::Flowering, "Flowering"),
+        GrowthStage::Flowering | GrowthStage::ReadyToHarvest => (GrowthStage::ReadyToHarvest, "Harvest"),
+    };
+    let next_stage_day = plant.stage_start_day(next_stage);
+    let (current_day, stage_name) = if plant.stage == GrowthStage::ReadyToHarvest {
+        (next_stage_day, "Ready!")
+    } else {
+        (plant.days_alive, stage_name)
     };
     let progress_percent = if plant.stage == crate::domain::GrowthStage::ReadyToHarvest {
         100
     } else {
         ((current_day as f32 / next_stage_day as f32) * 100.0).min(100.0) as u16
     };
-    let days_left = next_stage_day.saturating_sub(current_day);
-    let progress_gauge = Gauge::default()
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(format!("→ {}", stage_name)),
-        )
-        .gauge_style(Style::default().fg(Color::Cyan))
-        .percent(progress_percent)
-        .label(format!("{}d left", days_left));
-    f.render_widget(progress_gauge, row1_chunks[2]);
+    let progress = GaugeSpec::plain(
+        format!("→ {}", stage_name),
+        progress_percent,
+        Color::Cyan,
+        format!("{}d left", next_stage_day.saturating_sub(current_day)),
+    );
 
     // Temperature gauge - oscillates realistically (changes visibly!)
-    let temp_percent = ((plant.temperature - TEMP_OPTIMAL_MIN) / (TEMP_OPTIMAL_MAX - TEMP_OPTIMAL_MIN) * 100.0)
-        .max(0.0)
-        .min(100.0) as u16;
-    let temp_color = if plant.temperature >= TEMP_OPTIMAL_MIN && plant.temperature <= TEMP_OPTIMAL_MAX {
+    let temp_color = if (TEMP_OPTIMAL_MIN..=TEMP_OPTIMAL_MAX).contains(&temperature) {
         Color::Green
-    } else if plant.temperature >= TEMP_ACCEPTABLE_MIN && plant.temperature <= TEMP_ACCEPTABLE_MAX {
+    } else if (TEMP_ACCEPTABLE_MIN..=TEMP_ACCEPTABLE_MAX).contains(&temperature) {
         Color::Yellow
     } else {
         Color::Red
     };
-    let temp_gauge = Gauge::default()
-        .block(Block::default().borders(Borders::ALL).title("Temperature"))
-        .gauge_style(Style::default().fg(temp_color))
-        .percent(temp_percent)
-        .label(format!("{:.1}°C", plant.temperature));
-    f.render_widget(temp_gauge, row2_chunks[0]);
+    let temperature_gauge = GaugeSpec::plain(
+        format!("Temperature ({:.0}-{:.0}°C)", TEMP_OPTIMAL_MIN, TEMP_OPTIMAL_MAX),
+        ((temperature - TEMP_OPTIMAL_MIN) / (TEMP_OPTIMAL_MAX - TEMP_OPTIMAL_MIN) * 100.0).clamp(0.0, 100.0) as u16,
+        temp_color,
+        format!("{:.1}°C", temperature),
+    );
 
     // Humidity gauge - varies with watering (dynamic!)
-    let humid_percent = plant.humidity as u16;
-    let humid_color = if plant.humidity >= HUMIDITY_OPTIMAL_MIN && plant.humidity <= HUMIDITY_OPTIMAL_MAX {
+    let humid_color = if (HUMIDITY_OPTIMAL_MIN..=HUMIDITY_OPTIMAL_MAX).contains(&humidity) {
         Color::Cyan
-    } else if plant.humidity >= HUMIDITY_ACCEPTABLE_MIN && plant.humidity <= HUMIDITY_ACCEPTABLE_MAX {
+    } else if (HUMIDITY_ACCEPTABLE_MIN..=HUMIDITY_ACCEPTABLE_MAX).contains(&humidity) {
         Color::Yellow
     } else {
         Color::Red
     };
-    let humid_gauge = Gauge::default()
-        .block(Block::default().borders(Borders::ALL).title("Humidity"))
-        .gauge_style(Style::default().fg(humid_color))
-        .percent(humid_percent)
-        .label(format!("{:.0}%", plant.humidity));
-    f.render_widget(humid_gauge, row2_chunks[1]);
+    let humidity_gauge = GaugeSpec::plain(
+        format!("Humidity ({:.0}-{:.0}%)", HUMIDITY_OPTIMAL_MIN, HUMIDITY_OPTIMAL_MAX),
+        humidity as u16,
+        humid_color,
+        format!("{:.0}%", humidity),
+    );
 
     // Roots & Canopy development
     let growth_color = if plant.root_development >= GROWTH_GOOD_THRESHOLD {
@@ -412,19 +536,12 @@ fn render_plant(f: &mut Frame, plant: &Plant, area: Rect, frame: usize, app: &Ap
     } else {
         Color::Red
     };
-    let growth_gauge = Gauge::default()
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(format!("Root/Canopy")),
-        )
-        .gauge_style(Style::default().fg(growth_color))
-        .percent(((plant.root_development + plant.canopy_density) / 2.0) as u16)
-        .label(format!(
-            "R{:.0}/C{:.0}",
-            plant.root_development, plant.canopy_density
-        ));
-    f.render_widget(growth_gauge, row2_chunks[2]);
+    let growth = GaugeSpec::plain(
+        "Root/Canopy",
+        ((plant.root_development + plant.canopy_density) / 2.0) as u16,
+        growth_color,
+        format!("R{:.0}/C{:.0}", plant.root_development, plant.canopy_density),
+    );
 
     // Health gauge - overall plant health
     let (health_percent, health_color, health_label) = match plant.health {
@@ -434,25 +551,74 @@ fn render_plant(f: &mut Frame, plant: &Plant, area: Rect, frame: usize, app: &Ap
         crate::domain::HealthStatus::Poor => (25, Color::LightRed, "Poor ⚠"),
         crate::domain::HealthStatus::Critical => (10, Color::Red, "CRITICAL ⚠⚠"),
     };
+    let health = GaugeSpec::plain("Health", health_percent, health_color, health_label.to_string());
+
+    // Light gauge - absorption bar, intensity vs stage optimum in the label
+    let (light_min, light_max) = plant.stage.optimal_light();
+    let light_color = if plant.light_intensity > light_max {
+        Color::Red
+    } else if plant.light_intensity < light_min {
+        Color::Yellow
+    } else {
+        Color::LightYellow
+    };
+    let light = GaugeSpec::plain(
+        format!("Light ({:.0}-{:.0})", light_min, light_max),
+        plant.light_absorption.clamp(0.0, 100.0) as u16,
+        light_color,
+        format!("I{:.0}/A{:.0}", plant.light_intensity, plant.light_absorption),
+    );
+
+    // CO2 gauge - hints at what's missing when enrichment isn't paying off
+    let (co2_title, co2_color) = if !app.co2_enrichment {
+        ("CO2".to_string(), Color::Gray)
+    } else if let Some(blocker) = plant.co2_blocker() {
+        (format!("CO2 enriched - {}", blocker), Color::Yellow)
+    } else {
+        ("CO2 enriched ✓".to_string(), Color::LightGreen)
+    };
+    let co2_multiplier = plant.co2_growth_multiplier(app.co2_enrichment);
+    let co2 = GaugeSpec::plain(
+        co2_title,
+        plant.co2_level.clamp(0.0, 100.0) as u16,
+        co2_color,
+        format!("{:.0}% (+{:.0}% growth)", plant.co2_level, (co2_multiplier - 1.0) * 100.0),
+    );
 
-    let health_gauge = Gauge::default()
-        .block(Block::default().borders(Borders::ALL).title("Health"))
-        .gauge_style(Style::default().fg(health_color))
-        .percent(health_percent)
-        .label(health_label);
-    f.render_widget(health_gauge, resources_rows[2]);
+    // Dynamic metrics - 3 rows of gauges (things that change frequently)
+    render_dashboard(
+        f,
+        [
+            [water, nutrients, progress],
+            [temperature_gauge, humidity_gauge, growth],
+            [health, light, co2],
+        ],
+        chunks[4],
+    );
 
     // Controls with auto-harvest mode indicator
     let auto_mode_indicator = if app.auto_harvest {
-        " | AUTO ✓ "
+        format!(" | AUTO ✓ +{}d [{}/{}] ", app.auto_harvest_delay_days, app.keys.label(Action::AutoHarvestSooner), app.keys.label(Action::AutoHarvestLater))
     } else {
+        String::new()
+    };
+    let care_indicator = if app.auto_care {
         ""
+    } else {
+        " | MANUAL "
     };
 
-    let controls = if plant.stage == crate::domain::GrowthStage::ReadyToHarvest {
-        format!("** [h] HARVEST **  [a] Auto{}  [v] Mode  [s] Stats  [q] Quit", auto_mode_indicator)
+    let controls = if app.paused_for_harvest() {
+        format!(
+            "⏸ Paused — plant ready, press {} to harvest  [a] Auto{}  [{}] Keep aging  [?] Help  [q] Quit",
+            app.keys.label(Action::Harvest),
+            auto_mode_indicator,
+            app.keys.label(Action::TogglePauseWhenReady)
+        )
+    } else if plant.stage == crate::domain::GrowthStage::ReadyToHarvest {
+        format!("** [h] HARVEST **  [a] Auto{}  [m] Auto-care{}  [+/-] Light  [v] Mode  [c] Care  [s] Stats  [?] Help  [q] Quit", auto_mode_indicator, care_indicator)
     } else {
-        format!("[h] Harvest (ready)  [a] Auto{}  [v] Mode  [s] Stats  [q] Quit", auto_mode_indicator)
+        format!("[h] Harvest  [a] Auto{}  [m] Auto-care{}  [+/-] Light  [v] Mode  [c] Care  [s] Stats  [?] Help  [q] Quit", auto_mode_indicator, care_indicator)
     };
 
     let controls_style = if plant.stage == crate::domain::GrowthStage::ReadyToHarvest {
@@ -467,74 +633,55 @@ fn render_plant(f: &mut Frame, plant: &Plant, area: Rect, frame: usize, app: &Ap
         .block(Block::default().borders(Borders::ALL).title("Controls"))
         .style(controls_style)
         .alignment(Alignment::Center);
-    f.render_widget(controls_widget, chunks[3]);
+    f.render_widget(controls_widget, chunks[5]);
 
     // Strain Info Panel (right side)
     let strain_info_lines = if let Some(ref strain_info) = plant.genetics.strain_info {
-        vec![
+        let mut lines = vec![
             Line::from(Span::styled(
                 strain_info.name.clone(),
                 Style::default()
                     .fg(Color::Cyan)
                     .add_modifier(Modifier::BOLD),
             )),
+            rarity_line(plant),
+            planted_line(plant),
+            medium_line(plant),
             Line::from(""),
             Line::from(Span::styled(
                 format!("Type: {}", strain_info.strain_type),
                 Style::default().fg(Color::Yellow),
             )),
             Line::from(""),
-            Line::from(Span::styled(
-                "Genetics:",
-                Style::default()
-                    .fg(Color::Green)
-                    .add_modifier(Modifier::BOLD),
-            )),
-            Line::from(strain_info.genetics.clone()),
+            section_heading("Genetics:"),
+            Line::from(match plant.lineage {
+                Some(ref lineage) => lineage.to_string(),
+                None => strain_info.genetics.clone(),
+            }),
             Line::from(""),
-            Line::from(Span::styled(
-                "Cannabinoids:",
-                Style::default()
-                    .fg(Color::Green)
-                    .add_modifier(Modifier::BOLD),
-            )),
+            section_heading("Cannabinoids:"),
             Line::from(format!("THC: {:.1}%", plant.genetics.thc_percent)),
             Line::from(format!("CBD: {:.1}%", plant.genetics.cbd_percent)),
-            Line::from(""),
-            Line::from(Span::styled(
-                "Characteristics:",
-                Style::default()
-                    .fg(Color::Green)
-                    .add_modifier(Modifier::BOLD),
-            )),
-            Line::from(format!("Difficulty: {}", strain_info.difficulty)),
-            Line::from(format!("Yield: {}", strain_info.yield_potential)),
-            Line::from(format!("Flowering: {} days", strain_info.flowering_time)),
-            Line::from(""),
             Line::from(Span::styled(
-                "Terpenes:",
-                Style::default()
-                    .fg(Color::Green)
-                    .add_modifier(Modifier::BOLD),
+                potency_label(plant.genetics.thc_percent, plant.genetics.cbd_percent),
+                Style::default().fg(Color::Gray),
             )),
-            Line::from(strain_info.dominant_terpenes.join(", ")),
             Line::from(""),
-            Line::from(Span::styled(
-                "Aroma:",
-                Style::default()
-                    .fg(Color::Green)
-                    .add_modifier(Modifier::BOLD),
-            )),
-            Line::from(strain_info.aroma.join(", ")),
-            Line::from(""),
-            Line::from(Span::styled(
-                "Effects:",
-                Style::default()
-                    .fg(Color::Green)
-                    .add_modifier(Modifier::BOLD),
-            )),
-            Line::from(strain_info.effects.join(", ")),
-        ]
+        ];
+        lines.extend(characteristics_lines(strain_info));
+        lines.push(Line::from(""));
+        // Terpenes only build in flower; the aroma is what a harvest now would give
+        let terpene_heading = if plant.terpene_intensity > 0.0 {
+            format!(
+                "Terpenes ({:.0}%, {} aroma):",
+                plant.terpene_intensity,
+                harvest::aroma_descriptor(harvest::projected_terpene_intensity(plant)).to_lowercase()
+            )
+        } else {
+            "Terpenes:".to_string()
+        };
+        lines.extend(profile_lines(strain_info, terpene_heading));
+        lines
     } else {
         vec![
             Line::from(Span::styled(
@@ -543,17 +690,19 @@ fn render_plant(f: &mut Frame, plant: &Plant, area: Rect, frame: usize, app: &Ap
                     .fg(Color::Cyan)
                     .add_modifier(Modifier::BOLD),
             )),
+            rarity_line(plant),
+            planted_line(plant),
+            medium_line(plant),
             Line::from(""),
             Line::from("No strain data available"),
             Line::from(""),
-            Line::from(Span::styled(
-                "Cannabinoids:",
-                Style::default()
-                    .fg(Color::Green)
-                    .add_modifier(Modifier::BOLD),
-            )),
+            section_heading("Cannabinoids:"),
             Line::from(format!("THC: {:.1}%", plant.genetics.thc_percent)),
             Line::from(format!("CBD: {:.1}%", plant.genetics.cbd_percent)),
+            Line::from(Span::styled(
+                potency_label(plant.genetics.thc_percent, plant.genetics.cbd_percent),
+                Style::default().fg(Color::Gray),
+            )),
         ]
     };
 
@@ -565,7 +714,292 @@ fn render_plant(f: &mut Frame, plant: &Plant, area: Rect, frame: usize, app: &Ap
         )
         .alignment(Alignment::Left)
         .style(Style::default());
-    f.render_widget(strain_info_widget, main_chunks[1]);
+    // Curing jar sits under the strain info while one is open
+    let jar_rows = if app.curing.is_some() { 10 } else { 0 };
+    let side_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(jar_rows)])
+        .split(main_chunks[1]);
+    f.render_widget(strain_info_widget, side_chunks[0]);
+
+    if let Some(ref jar) = app.curing {
+        let block = Block::default().borders(Borders::ALL).title("[ Curing Jar ]");
+        let inner = block.inner(side_chunks[1]);
+        f.render_widget(block, side_chunks[1]);
+        render_jar(f, app, jar, inner);
+    }
+}
+
+/// Colored rarity tag, e.g. "★ Rare (Hardy) - Albino"
+fn rarity_line(plant: &Plant) -> Line<'static> {
+    let rarity = plant.genetics.rarity;
+    let mut text = format!("★ {}", rarity.as_str());
+    if plant.genetics.hardy {
+        text.push_str(" (Hardy)");
+    }
+    if let Some(mutation) = plant.genetics.mutation {
+        text.push_str(&format!(" - {} mutation", mutation.as_str()));
+    }
+    Line::from(Span::styled(
+        text,
+        Style::default().fg(rarity_color(rarity)).add_modifier(Modifier::BOLD),
+    ))
+}
+
+/// Real time since planting next to the game day, e.g. "Planted: 3 days ago (day 42)"
+fn planted_line(plant: &Plant) -> Line<'static> {
+    Line::from(Span::styled(
+        format!(
+            "Planted: {} (day {})",
+            format_ago(Utc::now().signed_duration_since(plant.planted_at)),
+            plant.days_alive
+        ),
+        Style::default().fg(Color::Gray),
+    ))
+}
+
+fn medium_line(plant: &Plant) -> Line<'static> {
+    Line::from(Span::styled(
+        format!("Medium: {} ({})", plant.medium.name(), plant.medium.description()),
+        Style::default().fg(Color::Gray),
+    ))
+}
+
+/// The colored plant for this frame, exactly `display` in size
+pub fn plant_art(app: &App, plant: &Plant, display: Canvas, frame: usize) -> Vec<Line<'static>> {
+    // Animated plant display - procedurally generated based on plant ID
+    let seed = plant.id.as_u128() as u64;
+    let growth_day = plant.growth_day();
+    let stretch = Stretch {
+        // Shift by the CO2 bonus so the stretch window lines up with the drawing day
+        flowering_start_day: plant.stage_start_day(crate::domain::GrowthStage::Flowering)
+            + (growth_day - plant.days_alive),
+        factor: plant.genetics.stretch_factor(),
+    };
+    let mutation = plant.genetics.mutation;
+    let strain_info = plant.genetics.strain_info.as_ref();
+    let traits = PlantTraits {
+        phenotype: strain_info.map(|s| Phenotype::from_strain(&s.phenotype)),
+        height_class: strain_info.and_then(|s| HeightClass::from_strain(&s.height)),
+        height_percent: mutation.map_or(100, |m| m.height_percent()),
+        topped: plant.partial_harvest_day.is_some(),
+    };
+    // Determine color variants based on genetics (seed) - each plant has unique colors!
+    // Purple Pheno always gets the purple flower variant (0)
+    let flower_color_variant = if mutation == Some(Mutation::PurplePheno) {
+        0
+    } else {
+        (seed % 6) as u8
+    };
+    // Unicode styles draw on a finer canvas and pack several pixels into each cell
+    let style = app.render_style.effective(app.unicode);
+    let look = Appearance {
+        flower_variant: flower_color_variant,
+        canopy_density: plant.canopy_density,
+        canvas: style.fine_canvas(display),
+        sway: if app.reduced_motion { 0.0 } else { 1.0 },
+        wilt: plant.wilt(),
+        frost: frost_score(plant.genetics.thc_percent, mutation == Some(Mutation::ExtraResinous)),
+        roots: plant.root_development,
+    };
+    let lower_leaf_row = (display.soil_row() + 1).saturating_sub(LOWER_LEAF_ROWS);
+    let plant_art = get_plant_art(plant.stage, growth_day, seed, traits, look, frame, stretch);
+    let plant_cells = hires::downsample(&plant_art, style);
+    let leaf_drops: Vec<LeafDrop> = plant.care_history.stress_events.iter().map(leaf_drop).collect();
+    let fallen = falling_leaves(&leaf_drops, plant.total_hours_elapsed / 24.0, seed, frame, display);
+
+    let foliage_color_variant = ((seed / 6) % 4) as u8;
+    let trunk_color_variant = ((seed / 24) % 3) as u8;
+
+    // Calculate flower intensity based on growth stage AND days alive for progression
+    // First third of flowering: Early, then Developing, Peak, and Harvest once ready
+    let (flower_intensity_1, flower_intensity_2, flower_intensity_3) = match plant.stage {
+        crate::domain::GrowthStage::Flowering => {
+            if plant.ripeness() < FLOWER_DEVELOPING_RIPENESS {
+                (FlowerIntensity::Early, FlowerIntensity::Early, FlowerIntensity::Developing)
+            } else if plant.ripeness() < FLOWER_PEAK_RIPENESS {
+                (FlowerIntensity::Developing, FlowerIntensity::Developing, FlowerIntensity::Peak)
+            } else {
+                // Late flowering (Peak intensity)
+                (FlowerIntensity::Peak, FlowerIntensity::Peak, FlowerIntensity::Peak)
+            }
+        }
+        crate::domain::GrowthStage::ReadyToHarvest => {
+            (FlowerIntensity::Harvest, FlowerIntensity::Harvest, FlowerIntensity::Harvest)
+        }
+        _ => {
+            // PreFlower or earlier
+            (FlowerIntensity::Early, FlowerIntensity::Early, FlowerIntensity::Early)
+        }
+    };
+
+    // Get colors from palette (RGB in truecolor mode, 16 colors otherwise). In 256-color
+    // mode the effects below blend the RGB colors, and each cell is quantized when drawn
+    let palette = app.color_palette.rgb_source().unwrap_or(app.color_palette.as_ref());
+
+    // Foliage color with environmental modifiers (health, water level)
+    let health_percent = match plant.health {
+        crate::domain::HealthStatus::Excellent => 100.0,
+        crate::domain::HealthStatus::Good => 80.0,
+        crate::domain::HealthStatus::Fair => 60.0,
+        crate::domain::HealthStatus::Poor => 40.0,
+        crate::domain::HealthStatus::Critical => 20.0,
+    };
+    let base_foliage_color = mutated_foliage(palette, mutation, foliage_color_variant, health_percent, plant.water_level);
+
+    // Apply breathing effect to foliage and flowers (12.5% amplitude for visible pulsing)
+    // Mode-specific breathing speeds for different aesthetics
+    let breath_speed = match app.visual_mode {
+        crate::ui::visual_mode::VisualMode::Normal => 0.05,   // Normal speed
+        crate::ui::visual_mode::VisualMode::Zen => 0.02,      // Slower (calming)
+        crate::ui::visual_mode::VisualMode::Rainbow => 0.08,  // Faster (energetic)
+        crate::ui::visual_mode::VisualMode::Matrix => 0.06,   // Medium-fast (digital)
+    };
+    let breath_factor = 0.875 + ((frame as f32 * breath_speed).sin() * 0.125); // 0.75-1.00 range (12.5% amplitude)
+    let foliage_color = apply_breathing(base_foliage_color, breath_factor);
+
+    // Flower colors with intensity progression + breathing effect
+    let base_flower_color_1 = palette.flower_color(flower_color_variant, flower_intensity_1, plant.stage);
+    let base_flower_color_2 = palette.flower_color(flower_color_variant, flower_intensity_2, plant.stage);
+    let base_flower_color_3 = palette.flower_color(flower_color_variant, flower_intensity_3, plant.stage);
+
+    let mut flower_color_1 = apply_breathing(base_flower_color_1, breath_factor);
+    let mut flower_color_2 = apply_breathing(base_flower_color_2, breath_factor);
+    let mut flower_color_3 = apply_breathing(base_flower_color_3, breath_factor);
+
+    // Light burn bleaches the bud tips
+    if plant.is_light_burned() {
+        flower_color_1 = apply_bleaching(flower_color_1);
+        flower_color_2 = apply_bleaching(flower_color_2);
+        flower_color_3 = apply_bleaching(flower_color_3);
+    }
+
+    // Trunk color with age progression
+    let trunk_color = palette.trunk_color(trunk_color_variant, plant.days_alive);
+
+    // Soil color (moisture-reactive)
+    let soil_color = palette.soil_color(plant.water_level);
+
+    // Roots take the trunk's wood tone, dimmed under the soil
+    let root_color = if palette.supports_rgb() {
+        apply_breathing(trunk_color, ROOT_DIMMING)
+    } else {
+        Color::DarkGray
+    };
+
+    // Overwatered plants yellow from the bottom up
+    let lower_leaf_color = if plant.water_level > OVERWATER_LEVEL {
+        apply_yellowing(foliage_color)
+    } else {
+        foliage_color
+    };
+
+    // Hungry plants yellow their fan leaves
+    let leaf_color = if plant.nutrient_level < app.care_bands().nutrient_critical {
+        apply_yellowing(foliage_color)
+    } else {
+        foliage_color
+    };
+
+    // Extra Resinous buds sparkle with trichomes (every RESIN_SPARKLE_SPACING-th bud cell)
+    let resin_sparkle = mutation == Some(Mutation::ExtraResinous)
+        && matches!(plant.stage, crate::domain::GrowthStage::Flowering | crate::domain::GrowthStage::ReadyToHarvest);
+
+    // Potent plants frost over; the trichomes take the frosty flower white whatever the variant
+    let frost_color = if palette.supports_rgb() {
+        palette.flower_color(FROSTY_FLOWER_VARIANT, FlowerIntensity::Harvest, plant.stage)
+    } else {
+        Color::White
+    };
+
+    // Build content lines first with colorization
+    let mut content_lines = vec![];
+    for (row, cells) in plant_cells.into_iter().enumerate() {
+        // Colorize each cell by the part of the plant it shows and the growth stage
+        let mut spans = vec![];
+        let mut current_chars = String::new();
+        let mut current_color = None;
+
+        for (col, cell) in cells.into_iter().enumerate() {
+            let bud_tone = |tip: bool| match plant.stage {
+                crate::domain::GrowthStage::Flowering if tip => Some(flower_color_1),
+                crate::domain::GrowthStage::Flowering => Some(flower_color_2),
+                crate::domain::GrowthStage::ReadyToHarvest => Some(flower_color_3), // VIBRANT!
+                _ => Some(foliage_color),
+            };
+            let color = match cell.kind {
+                CellKind::Empty => None,
+                CellKind::Root => Some(root_color),
+
+                // Trunk - varied wood tones
+                CellKind::Trunk => Some(trunk_color),
+
+                // Branches - varied green tones
+                CellKind::Branch => match plant.stage {
+                    crate::domain::GrowthStage::Seed | crate::domain::GrowthStage::Germination => {
+                        Some(Color::DarkGray)
+                    }
+                    crate::domain::GrowthStage::Seedling => Some(Color::Green),
+                    _ if row >= lower_leaf_row => Some(lower_leaf_color),
+                    _ => Some(foliage_color),
+                },
+
+                // Trichome frost
+                CellKind::Trichome => Some(frost_color),
+
+                // Flowers/buds - SUPER VIBRANT when ready!
+                CellKind::Bud => bud_tone(true),
+                CellKind::BudCluster => bud_tone(false),
+
+                // Foliage, and the seed and sprout - varied greens
+                CellKind::Foliage | CellKind::Seed => Some(foliage_color),
+
+                // Fan leaves - yellow low on the plant when overwatered or all over when hungry
+                CellKind::FanLeaf if row >= lower_leaf_row && plant.water_level > OVERWATER_LEVEL => {
+                    Some(lower_leaf_color)
+                }
+                CellKind::FanLeaf => Some(leaf_color),
+
+                // Soil - moisture-reactive
+                CellKind::Soil => Some(soil_color),
+            };
+
+            // Resin sparkle overrides bud color on a shifting diagonal
+            let sparkle = resin_sparkle
+                && matches!(cell.kind, CellKind::Bud | CellKind::BudCluster)
+                && (row + col + frame / 2).is_multiple_of(RESIN_SPARKLE_SPACING);
+            let color = if sparkle { Some(Color::White) } else { color };
+
+            // Leaves knocked off by stress drift down in front of everything
+            let leaf = fallen.iter().find(|l| (l.x, l.y) == (col, row));
+            let color = leaf.map_or(color, |l| Some(dying_leaf(l.landed, palette.supports_rgb())));
+
+            // If color changed, flush current buffer
+            if current_color != color && !current_chars.is_empty() {
+                if let Some(c) = current_color {
+                    spans.push(Span::styled(current_chars.clone(), Style::default().fg(app.color_palette.quantize(c))));
+                } else {
+                    spans.push(Span::raw(current_chars.clone()));
+                }
+                current_chars.clear();
+            }
+
+            current_chars.push(leaf.map_or(cell.glyph, |l| l.glyph));
+            current_color = color;
+        }
+
+        // Flush remaining characters
+        if !current_chars.is_empty() {
+            if let Some(c) = current_color {
+                spans.push(Span::styled(current_chars, Style::default().fg(app.color_palette.quantize(c))));
+            } else {
+                spans.push(Span::raw(current_chars));
+            }
+        }
+
+        content_lines.push(Line::from(spans));
+    }
+    content_lines
 }
 
 fn render_no_plant(f: &mut Frame, area: Rect) {
@@ -592,3 +1026,64 @@ fn render_no_plant(f: &mut Frame, area: Rect) {
         .alignment(Alignment::Center);
     f.render_widget(paragraph, area);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::colors::{create_palette, ColorDepth};
+    use crate::ui::visual_mode::VisualMode;
+
+    #[test]
+    fn foliage_effects_survive_256_colors() {
+        let palette = create_palette(ColorDepth::Indexed256, VisualMode::Normal, None);
+        let rgb = palette.rgb_source().expect("256 colors quantize an RGB palette");
+        let draw = |color: Color| palette.quantize(color);
+
+        let green = mutated_foliage(rgb, None, 1, 100.0, 60.0);
+        let purple = mutated_foliage(rgb, Some(Mutation::PurplePheno), 1, 100.0, 60.0);
+        assert!(matches!(draw(purple), Color::Indexed(_)));
+        assert_ne!(draw(purple), draw(green), "the purple pheno keeps its tint");
+        assert_ne!(draw(apply_yellowing(green)), Color::Yellow);
+        assert_ne!(draw(apply_breathing(green, 0.75)), draw(green), "breathing still dims the leaves");
+        assert_ne!(draw(albino_foliage(rgb.supports_rgb())), Color::White);
+    }
+
+    #[test]
+    fn reduced_motion_holds_every_frame_still() {
+        use crate::domain::GrowthStage;
+        use ratatui::{backend::TestBackend, buffer::Buffer, Terminal};
+
+        let mut app = App::new(ColorDepth::TrueColor);
+        let mut plant = crate::test_support::plant();
+        plant.total_hours_elapsed = plant.stage_start_day(GrowthStage::ReadyToHarvest) as f32 * 24.0;
+        plant.days_alive = plant.stage_start_day(GrowthStage::ReadyToHarvest);
+        plant.stage = GrowthStage::ReadyToHarvest;
+        plant.genetics.mutation = Some(Mutation::ExtraResinous);
+        plant.water_level = 5.0;
+        plant.care_history.stress_events.push(StressEvent {
+            day: plant.days_alive,
+            severity: StressSeverity::Severe,
+            cause: StressCause::LowWater,
+        });
+        app.current_plant = Some(plant);
+        app.harvest_flash_frames = 10;
+
+        let draw = |app: &App| -> Buffer {
+            let mut terminal = Terminal::new(TestBackend::new(120, 50)).unwrap();
+            terminal.draw(|f| render(f, app, f.area())).unwrap();
+            terminal.backend().buffer().clone()
+        };
+        let frames = |app: &mut App| -> Vec<Buffer> {
+            (0..8)
+                .map(|frame| {
+                    app.animation_frame = frame;
+                    draw(app)
+                })
+                .collect()
+        };
+
+        assert!(frames(&mut app).windows(2).any(|pair| pair[0] != pair[1]), "the grow room animates");
+        app.reduced_motion = true;
+        assert!(frames(&mut app).windows(2).all(|pair| pair[0] == pair[1]));
+    }
+}
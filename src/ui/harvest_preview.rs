@@ -0,0 +1,89 @@
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::config::Action;
+use crate::domain::harvest::aroma_descriptor;
+use crate::domain::{GrowthStage, HarvestResult};
+use crate::ui::colors::grade_color;
+use crate::ui::help::centered_rect;
+use crate::ui::units::format_weight;
+
+/// Render the projected harvest over the current screen, waiting for confirmation
+pub fn render(f: &mut Frame, app: &App, harvest: &HarvestResult, area: Rect) {
+    let grade = harvest.grade();
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::raw("Yield: "),
+            Span::styled(
+                format_weight(harvest.weight_grams, app.weight_unit),
+                Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(
+                " (bud {} + trim {})",
+                format_weight(harvest.bud_grams, app.weight_unit),
+                format_weight(harvest.trim_grams, app.weight_unit)
+            )),
+        ]),
+        Line::from(vec![
+            Span::raw(format!("Quality: {:.0}% ", harvest.quality_score)),
+            Span::styled(
+                format!("[{}]", grade.as_str()),
+                Style::default().fg(grade_color(grade)).add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(format!("THC {:.1}% | CBD {:.1}%", harvest.thc_percent, harvest.cbd_percent)),
+        Line::from(format!(
+            "Aroma: {} ({:.0})",
+            aroma_descriptor(harvest.terpene_intensity),
+            harvest.terpene_intensity
+        )),
+        Line::from(""),
+    ];
+
+    // Tell the player whether waiting would pay off
+    if let Some(ref plant) = app.current_plant {
+        if plant.stage == GrowthStage::Flowering {
+            let days_left = plant
+                .stage_start_day(GrowthStage::ReadyToHarvest)
+                .saturating_sub(plant.days_alive);
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "Buds are {:.0}% ripe - waiting {} more days improves yield and quality",
+                    plant.ripeness() * 100.0,
+                    days_left
+                ),
+                Style::default().fg(Color::Yellow),
+            )));
+        } else {
+            lines.push(Line::from(Span::styled(
+                "Buds are ripe - harvesting now gets the full yield",
+                Style::default().fg(Color::LightGreen),
+            )));
+        }
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        format!("[{}] Harvest   any other key: cancel", app.keys.label(Action::Harvest)),
+        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+    )));
+
+    let popup = centered_rect(70, (lines.len() + 2) as u16, area);
+    let preview = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("[ Harvest {}? ]", harvest.strain_name)),
+        )
+        .alignment(Alignment::Center);
+
+    f.render_widget(Clear, popup);
+    f.render_widget(preview, popup);
+}
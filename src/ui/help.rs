@@ -0,0 +1,125 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::config::Action;
+
+/// Actions shown in the help overlay (action, description)
+const BINDINGS: &[(Action, &str)] = &[
+    (Action::Harvest, "Harvest preview (press again to confirm)"),
+    (Action::PartialHarvest, "Cut the top colas, leave the rest to ripen"),
+    (Action::UndoHarvest, "Undo the harvest you just made (a few seconds)"),
+    (Action::FinishDrying, "Take down dry batches"),
+    (Action::BurpJar, "Jar the latest harvest / burp the jar"),
+    (Action::FinishCure, "Open the jar and finish the cure"),
+    (Action::ToggleAuto, "Toggle auto-harvest"),
+    (Action::AutoHarvestSooner, "Auto-harvest a day sooner (auto mode)"),
+    (Action::AutoHarvestLater, "Auto-harvest a day later (auto mode)"),
+    (Action::ToggleCare, "Toggle auto-care / manual care"),
+    (Action::Water, "Water plant (manual care)"),
+    (Action::Feed, "Feed nutrients (manual care)"),
+    (Action::LightUp, "Raise light intensity (manual care)"),
+    (Action::LightDown, "Lower light intensity (manual care)"),
+    (Action::ToggleCo2, "Toggle CO2 enrichment"),
+    (Action::ToggleBell, "Toggle bell when a plant is ready"),
+    (Action::TogglePauseWhenReady, "Toggle pausing ready plants until harvested"),
+    (Action::ToggleCompression, "Toggle compressed saves (.gz)"),
+    (Action::CycleSaveFormat, "Switch save format (JSON / RON)"),
+    (Action::ToggleUnits, "Toggle grams / ounces"),
+    (Action::FastForward, "Fast-forward to harvest"),
+    (Action::CycleFrameRate, "Cycle frame rate (eco modes save CPU)"),
+    (Action::CycleAutosave, "Cycle autosave interval (or off: save on exit)"),
+    (Action::CycleOfflineGrowth, "Cycle growth caught up while the game is closed"),
+    (Action::CycleDifficulty, "Cycle difficulty (width of the water / nutrient bands)"),
+    (Action::CycleMedium, "Cycle grow medium: soil, coco or hydro (next plant)"),
+    (Action::ReloadStrains, "Reload strain files"),
+    (Action::CycleMode, "Cycle visual mode (256-color terminals and up)"),
+    (Action::CycleModePrev, "Cycle visual mode backwards (256-color terminals and up)"),
+    (Action::ModeZen, "Zen Garden visual mode"),
+    (Action::ModeRainbow, "Rainbow visual mode"),
+    (Action::ModeMatrix, "Matrix visual mode"),
+    (Action::ModeNormal, "Normal visual mode"),
+    (Action::CycleRenderStyle, "Cycle plant glyphs (ASCII / half blocks / braille)"),
+    (Action::CycleTheme, "Cycle custom palettes (Normal mode, from palettes/*.toml)"),
+    (Action::ToggleReducedMotion, "Toggle reduced motion (holds every animation still)"),
+    (Action::GrowingRoom, "Growing room"),
+    (Action::SwitchStats, "Statistics"),
+    (Action::CareLog, "Care history chart"),
+    (Action::Breeding, "Breeding"),
+    (Action::StrainLibrary, "Strain library"),
+    (Action::StrainCompare, "Compare two strains"),
+    (Action::Goals, "Season goals"),
+    (Action::CursorUp, "Move up a list"),
+    (Action::CursorDown, "Move down a list"),
+    (Action::PageUp, "Page up the strain list"),
+    (Action::PageDown, "Page down the strain list"),
+    (Action::Confirm, "Cross the picked parents / set the goal"),
+    (Action::PickParent, "Pick a parent (breeding)"),
+    (Action::Search, "Search the strain library"),
+    (Action::CycleSort, "Change the strain library sort"),
+    (Action::ClearGoal, "Clear the season goal"),
+    (Action::SwitchSide, "Switch the compared side"),
+    (Action::PlantFeatured, "Plant the strain of the day next"),
+    (Action::ExportCode, "Show a share code for this plant"),
+    (Action::ExportArt, "Save the plant as ANSI and plain-text art"),
+    (Action::ExportDiary, "Write a markdown grow diary for this plant"),
+    (Action::ImportCode, "Import a friend's plant code as a seed"),
+    (Action::NewGame, "New game (press twice; archives this run)"),
+    (Action::SaveNow, "Save now"),
+    (Action::Help, "Toggle this help"),
+    (Action::Quit, "Quit"),
+];
+
+/// Render the help overlay centered on top of the current screen
+/// Key labels come from the active key config so remapped keys show correctly
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let popup = centered_rect(60, (BINDINGS.len() + 4) as u16, area);
+
+    let mut lines = vec![Line::from("")];
+    for (action, description) in BINDINGS {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{:>9}  ", app.keys.label(*action)),
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(*description),
+        ]));
+    }
+
+    let help = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("[ Help - press ? or Esc to close ]"),
+        )
+        .alignment(Alignment::Left);
+
+    f.render_widget(Clear, popup);
+    f.render_widget(help, popup);
+}
+
+/// Create a rect of the given width percentage and fixed height, centered in `area`
+pub fn centered_rect(percent_x: u16, height: u16, area: Rect) -> Rect {
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Fill(1),
+            Constraint::Length(height.min(area.height)),
+            Constraint::Fill(1),
+        ])
+        .split(area);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(vertical[1])[1]
+}
@@ -1,8 +1,31 @@
-pub mod colors;
-pub mod growing;
-pub mod layout;
-pub mod stats;
-pub mod visual_mode;
+pub(crate) mod breeding;
+pub(crate) mod care_log;
+pub(crate) mod colors;
+pub(crate) mod compare;
+pub(crate) mod export;
+pub(crate) mod goals;
+pub(crate) mod growing;
+pub(crate) mod harvest_preview;
+pub(crate) mod help;
+pub(crate) mod layout;
+pub(crate) mod notice;
+pub(crate) mod profiles;
+pub(crate) mod share;
+pub(crate) mod smoothing;
+pub(crate) mod stats;
+pub(crate) mod status_line;
+pub(crate) mod strains;
+pub(crate) mod themes;
+pub(crate) mod toast;
+pub(crate) mod units;
+pub(crate) mod visual_mode;
+
+pub use colors::ColorDepth;
+pub use export::{write_diary, write_plant_art, Export};
+pub use profiles::{render as render_profile_picker, PickerMode, ProfilePicker};
+pub use strains::LIBRARY_PAGE;
+pub use themes::CUSTOM_PREFIX;
+pub use visual_mode::VisualMode;
 
 use ratatui::Frame;
 
@@ -16,5 +39,39 @@ pub fn view(f: &mut Frame, app: &App) {
     match app.current_screen {
         Screen::GrowingRoom => growing::render(f, app, area),
         Screen::Stats => stats::render(f, app, area),
+        Screen::CareLog => care_log::render(f, app, area),
+        Screen::Breeding => breeding::render(f, app, area),
+        Screen::StrainLibrary => strains::render(f, app, area),
+        Screen::StrainCompare => compare::render(f, app, area),
+        Screen::Goals => goals::render(f, app, area),
+    }
+
+    if let Some(ref error) = app.status_error {
+        status_line::render(f, error, area);
+    }
+    status_line::render_save_state(f, app, area);
+
+    if let Some(ref toast) = app.toast {
+        toast::render(f, toast, area);
+    }
+
+    if let Some(ref harvest) = app.pending_harvest {
+        harvest_preview::render(f, app, harvest, area);
+    }
+
+    if let Some(ref code) = app.share_code {
+        share::render_code(f, app, code, area);
+    }
+
+    if let Some(ref typed) = app.code_input {
+        share::render_import(f, typed, area);
+    }
+
+    if app.show_help {
+        help::render(f, app, area);
+    }
+
+    if let Some(ref text) = app.notice {
+        notice::render(f, text, area);
     }
 }
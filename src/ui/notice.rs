@@ -0,0 +1,31 @@
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::ui::help::centered_rect;
+
+/// Render an important notice over everything until a key is pressed
+pub fn render(f: &mut Frame, text: &str, area: Rect) {
+    let mut lines = vec![Line::from("")];
+    lines.extend(text.lines().map(|line| Line::from(line.to_string())));
+    lines.push(Line::from(""));
+
+    // Leave room for long paths to wrap
+    let popup = centered_rect(70, (lines.len() + 6) as u16, area);
+    let notice = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
+                .title("[ Notice - press any key to continue ]"),
+        )
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: false });
+
+    f.render_widget(Clear, popup);
+    f.render_widget(notice, popup);
+}
@@ -0,0 +1,107 @@
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::storage::sanitize_profile_name;
+use crate::ui::help::centered_rect;
+
+/// What the picker is waiting for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PickerMode {
+    Browse,
+    Naming(String),  // Typing a new profile name
+    ConfirmDelete,   // Delete the highlighted profile? (y/n)
+}
+
+/// Startup popup for choosing which save profile to play
+#[derive(Debug, Clone)]
+pub struct ProfilePicker {
+    pub profiles: Vec<String>,
+    pub cursor: usize,
+    pub mode: PickerMode,
+}
+
+impl ProfilePicker {
+    pub fn new(profiles: Vec<String>) -> Self {
+        Self {
+            profiles,
+            cursor: 0,
+            mode: PickerMode::Browse,
+        }
+    }
+
+    /// Profile under the cursor
+    pub fn selected(&self) -> Option<&str> {
+        self.profiles.get(self.cursor).map(String::as_str)
+    }
+
+    pub fn move_cursor(&mut self, delta: isize) {
+        let last = self.profiles.len().saturating_sub(1);
+        self.cursor = self.cursor.saturating_add_signed(delta).min(last);
+    }
+
+    /// Name typed so far, sanitized (None while it has no usable characters)
+    pub fn new_name(&self) -> Option<String> {
+        match self.mode {
+            PickerMode::Naming(ref typed) => sanitize_profile_name(typed),
+            _ => None,
+        }
+    }
+
+    /// Drop a deleted profile from the list
+    pub fn remove_selected(&mut self) {
+        if self.cursor < self.profiles.len() {
+            self.profiles.remove(self.cursor);
+        }
+        self.move_cursor(0);
+        self.mode = PickerMode::Browse;
+    }
+}
+
+pub fn render(f: &mut Frame, picker: &ProfilePicker, area: Rect) {
+    let mut lines = vec![Line::from("")];
+    for (i, name) in picker.profiles.iter().enumerate() {
+        let (cursor, style) = if i == picker.cursor {
+            ("> ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+        } else {
+            ("  ", Style::default())
+        };
+        lines.push(Line::from(vec![Span::raw(cursor), Span::styled(name.clone(), style)]));
+    }
+    lines.push(Line::from(""));
+
+    let prompt = match picker.mode {
+        PickerMode::Browse => Span::styled(
+            "[Enter] Play  [n] New profile  [d] Delete  [q] Quit",
+            Style::default().fg(Color::Yellow),
+        ),
+        PickerMode::Naming(ref typed) => Span::styled(
+            format!(
+                "New profile name: {}_  -> {}  [Enter] Create  [Esc] Back",
+                typed,
+                picker.new_name().unwrap_or_else(|| "?".to_string())
+            ),
+            Style::default().fg(Color::Yellow),
+        ),
+        PickerMode::ConfirmDelete => Span::styled(
+            format!(
+                "Delete profile '{}' and all its saves? [y] Yes  [n] No",
+                picker.selected().unwrap_or_default()
+            ),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        ),
+    };
+    lines.push(Line::from(prompt));
+
+    let popup = centered_rect(70, (lines.len() + 2) as u16, area);
+    let list = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title("[ Choose a profile ]"))
+        .alignment(Alignment::Center);
+
+    f.render_widget(Clear, popup);
+    f.render_widget(list, popup);
+}
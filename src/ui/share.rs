@@ -0,0 +1,71 @@
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::config::Action;
+use crate::ui::help::centered_rect;
+
+/// Characters per line of a displayed code
+const CODE_WIDTH: usize = 48;
+
+/// Show an exported plant code, split into lines a terminal can copy
+/// No side borders, so selecting the code doesn't pick up box characters
+pub fn render_code(f: &mut Frame, app: &App, code: &str, area: Rect) {
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(format!(
+            "Send this code to a friend - they press [{}] to import it:",
+            app.keys.label(Action::ImportCode)
+        )),
+        Line::from(""),
+    ];
+    for chunk in code.as_bytes().chunks(CODE_WIDTH) {
+        lines.push(Line::from(Span::styled(
+            String::from_utf8_lossy(chunk).into_owned(),
+            Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled("Any key to close", Style::default().fg(Color::DarkGray))));
+
+    let popup = centered_rect(80, (lines.len() + 2) as u16, area);
+    let text = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::TOP | Borders::BOTTOM).title("[ Share Plant ]"))
+        .alignment(Alignment::Center);
+
+    f.render_widget(Clear, popup);
+    f.render_widget(text, popup);
+}
+
+/// Prompt for a pasted plant code
+pub fn render_import(f: &mut Frame, typed: &str, area: Rect) {
+    // Long pastes show their tail so the cursor stays visible
+    let shown: String = {
+        let chars: Vec<char> = typed.chars().collect();
+        chars[chars.len().saturating_sub(CODE_WIDTH)..].iter().collect()
+    };
+    let lines = vec![
+        Line::from(""),
+        Line::from("Paste or type a plant code:"),
+        Line::from(""),
+        Line::from(Span::styled(format!("{}_", shown), Style::default().fg(Color::Cyan))),
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("{} characters  [Enter] Import  [Esc] Cancel", typed.chars().count()),
+            Style::default().fg(Color::Yellow),
+        )),
+    ];
+
+    let popup = centered_rect(70, (lines.len() + 2) as u16, area);
+    let prompt = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title("[ Import Plant Code ]"))
+        .alignment(Alignment::Center);
+
+    f.render_widget(Clear, popup);
+    f.render_widget(prompt, popup);
+}
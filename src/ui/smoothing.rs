@@ -0,0 +1,80 @@
+use uuid::Uuid;
+
+use crate::domain::Plant;
+
+/// Share of the remaining gap a displayed value closes each frame
+const EASE_FACTOR: f32 = 0.5;
+
+/// Gaps smaller than this snap straight to the true value
+const SNAP_DISTANCE: f32 = 0.1;
+
+/// A displayed number that eases toward the value it tracks
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Smoothed(Option<f32>);
+
+impl Smoothed {
+    /// Step toward `target`; the first value is taken as-is
+    pub fn ease(&mut self, target: f32) {
+        self.0 = Some(match self.0 {
+            Some(shown) if (target - shown).abs() > SNAP_DISTANCE => shown + (target - shown) * EASE_FACTOR,
+            _ => target,
+        });
+    }
+
+    /// Value to draw, falling back to the true value before the first frame
+    pub fn or(&self, actual: f32) -> f32 {
+        self.0.unwrap_or(actual)
+    }
+}
+
+/// Gauge values shown in the growing room; the plant stays authoritative for logic
+#[derive(Debug, Clone, Default)]
+pub struct DisplayedGauges {
+    plant_id: Option<Uuid>,
+    pub water: Smoothed,
+    pub nutrients: Smoothed,
+    pub temperature: Smoothed,
+    pub humidity: Smoothed,
+}
+
+impl DisplayedGauges {
+    /// Advance one frame toward the plant's readings (a new plant snaps instantly)
+    pub fn ease_toward(&mut self, plant: &Plant) {
+        if self.plant_id != Some(plant.id) {
+            *self = Self {
+                plant_id: Some(plant.id),
+                ..Self::default()
+            };
+        }
+        self.water.ease(plant.water_level);
+        self.nutrients.ease(plant.nutrient_level);
+        self.temperature.ease(plant.temperature);
+        self.humidity.ease(plant.humidity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eases_within_a_percent_in_a_few_frames() {
+        let mut value = Smoothed::default();
+        value.ease(80.0);
+        assert_eq!(value.or(0.0), 80.0);
+
+        value.ease(20.0);
+        let first = value.or(0.0);
+        assert!(first > 20.0 && first < 80.0, "should move part of the way, got {}", first);
+
+        for _ in 0..7 {
+            value.ease(20.0);
+        }
+        assert!((value.or(0.0) - 20.0).abs() < 1.0);
+
+        for _ in 0..5 {
+            value.ease(20.0);
+        }
+        assert_eq!(value.or(0.0), 20.0);
+    }
+}
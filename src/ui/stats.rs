@@ -1,5 +1,5 @@
 use ratatui::{
-    layout::{Alignment, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
@@ -7,6 +7,13 @@ use ratatui::{
 };
 
 use crate::app::App;
+use crate::config::Action;
+use crate::domain::genetics::Rarity;
+use crate::domain::harvest::HarvestRecords;
+use crate::domain::strains;
+use crate::ui::colors::{grade_color, rarity_color};
+use crate::ui::goals;
+use crate::ui::units::format_weight;
 
 pub fn render(f: &mut Frame, app: &App, area: Rect) {
     let mut lines = vec![
@@ -23,13 +30,67 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
         Line::from(""),
         Line::from(format!("Total Harvests: {}", app.total_harvests)),
     ];
+    if let Some(ref profile) = app.profile {
+        lines.push(Line::from(vec![
+            Span::raw("Profile: "),
+            Span::styled(profile.clone(), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+        ]));
+    }
+    let save_file = match app.save_dir {
+        Some(ref dir) => dir.join(app.save_format.file_name(app.compress_saves)).display().to_string(),
+        None => "not saved this session".to_string(),
+    };
+    lines.push(Line::from(vec![
+        Span::raw("Save file: "),
+        Span::styled(save_file, Style::default().fg(Color::DarkGray)),
+    ]));
+    lines.push(Line::from(vec![
+        Span::raw("Difficulty: "),
+        Span::styled(app.difficulty.name(), Style::default().fg(Color::Yellow)),
+    ]));
+
+    // Lifetime totals outlast the history below (and new games)
+    let lifetime = app.lifetime;
+    if lifetime.harvests > 0 || lifetime.days_simulated > 0.0 {
+        let value = Style::default().fg(Color::LightCyan).add_modifier(Modifier::BOLD);
+        lines.push(Line::from(vec![
+            Span::styled("Lifetime: ", Style::default().fg(Color::LightCyan)),
+            Span::styled(lifetime.harvests.to_string(), value),
+            Span::raw(" harvests | "),
+            Span::styled(format_weight(lifetime.bud_grams, app.weight_unit), value),
+            Span::raw(" bud | best "),
+            Span::styled(format!("{:.0}%", lifetime.best_quality), value),
+            Span::raw(" quality | "),
+            Span::styled(format!("{:.0}", lifetime.days_simulated), value),
+            Span::raw(" days grown"),
+        ]));
+    }
+
+    // Harvest counts per rarity tier (only tiers above Common that occurred)
+    let rarity_spans: Vec<Span> = Rarity::ALL
+        .iter()
+        .skip(1)
+        .filter_map(|&rarity| {
+            let count = app.harvest_history.iter().filter(|h| h.rarity == rarity).count();
+            (count > 0).then(|| {
+                Span::styled(
+                    format!(" ★ {} {} ", count, rarity.as_str()),
+                    Style::default().fg(rarity_color(rarity)).add_modifier(Modifier::BOLD),
+                )
+            })
+        })
+        .collect();
+    if !rarity_spans.is_empty() {
+        lines.push(Line::from(rarity_spans));
+    }
 
     // Calculate and show aggregate statistics
     if !app.harvest_history.is_empty() {
         let total_count = app.harvest_history.len() as f32;
 
+        // Headline yields count bud only; trim is listed on its own
         let avg_yield: f32 = app.harvest_history.iter()
-            .map(|h| h.weight_grams)
+            .map(|h| h.bud_grams)
             .sum::<f32>() / total_count;
 
         let avg_quality: f32 = app.harvest_history.iter()
@@ -45,14 +106,18 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
             .sum::<f32>() / total_count;
 
         let total_yield: f32 = app.harvest_history.iter()
-            .map(|h| h.weight_grams)
+            .map(|h| h.bud_grams)
+            .sum();
+
+        let total_trim: f32 = app.harvest_history.iter()
+            .map(|h| h.trim_grams)
             .sum();
 
         lines.push(Line::from(""));
         lines.push(Line::from(vec![
             Span::raw("Average Yield: "),
             Span::styled(
-                format!("{:.1}g", avg_yield),
+                format_weight(avg_yield, app.weight_unit),
                 Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
             ),
             Span::raw(" | Quality: "),
@@ -76,16 +141,75 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
         ]));
 
         lines.push(Line::from(vec![
-            Span::raw("Total Yield All-Time: "),
+            Span::raw("Total Yield In History: "),
             Span::styled(
-                format!("{:.1}g", total_yield),
+                format_weight(total_yield, app.weight_unit),
                 Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
             ),
+            Span::raw(" bud | Trim: "),
+            Span::styled(
+                format_weight(total_trim, app.weight_unit),
+                Style::default().fg(Color::Gray),
+            ),
+        ]));
+    }
+
+    // Personal bests, with the best grow up front
+    if let Some(records) = HarvestRecords::from_history(&app.harvest_history) {
+        let best = records.best_yield;
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled("★ Best Grow: ", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::styled(
+                best.strain_name.clone(),
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(
+                " - {} on day {}",
+                format_weight(best.bud_grams, app.weight_unit),
+                best.harvest_day
+            )),
         ]));
+        lines.push(Line::from(format!(
+            "Best Quality: {:.0}% ({}) | Highest THC: {:.1}% ({})",
+            records.best_quality.quality_score,
+            records.best_quality.strain_name,
+            records.highest_thc.thc_percent,
+            records.highest_thc.strain_name
+        )));
+        lines.push(Line::from(Span::styled(
+            format!(
+                "Worst Yield: {} ({}, day {})",
+                format_weight(records.worst_yield.bud_grams, app.weight_unit),
+                records.worst_yield.strain_name,
+                records.worst_yield.harvest_day
+            ),
+            Style::default().fg(Color::Gray),
+        )));
     }
 
     lines.push(Line::from(""));
 
+    // Featured strain (hidden when no strains are loaded)
+    let strain_db = strains::database();
+    if let Some(strain) = strains::strain_of_the_day(app.session_date, &strain_db.strains) {
+        lines.push(Line::from(vec![
+            Span::raw("Strain of the day: "),
+            Span::styled(
+                strain.name.clone(),
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(
+                " ({}, THC {:.0}-{:.0}%) - press [{}] to plant it next",
+                strain.strain_type,
+                strain.thc_min,
+                strain.thc_max,
+                app.keys.label(Action::PlantFeatured)
+            )),
+        ]));
+        lines.push(Line::from(""));
+    }
+
     // Show last 5 harvests with detailed info
     if !app.harvest_history.is_empty() {
         lines.push(Line::from(Span::styled(
@@ -96,14 +220,38 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
 
         let recent = app.harvest_history.iter().rev().take(5);
         for (i, harvest) in recent.enumerate() {
-            // Harvest number and strain name
-            lines.push(Line::from(vec![
+            // Harvest number, grade and strain name (plus mutation, if any)
+            let grade = harvest.grade();
+            let mut name_spans = vec![
                 Span::raw(format!("{}. ", app.harvest_history.len() - i)),
+                Span::styled(
+                    format!("[{}] ", grade.as_str()),
+                    Style::default().fg(grade_color(grade)).add_modifier(Modifier::BOLD),
+                ),
                 Span::styled(
                     &harvest.strain_name,
                     Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
                 ),
-            ]));
+            ];
+            if let Some(mutation) = harvest.mutation {
+                name_spans.push(Span::styled(
+                    format!(" [{}]", mutation.as_str()),
+                    Style::default().fg(Color::LightMagenta),
+                ));
+            }
+            if !harvest.strain_type.is_empty() {
+                name_spans.push(Span::styled(
+                    format!(" ({})", harvest.strain_type),
+                    Style::default().fg(Color::Yellow),
+                ));
+            }
+            if harvest.partial {
+                name_spans.push(Span::styled(" [Top colas]", Style::default().fg(Color::LightGreen)));
+            }
+            if harvest.moldy {
+                name_spans.push(Span::styled(" [Moldy]", Style::default().fg(Color::Red)));
+            }
+            lines.push(Line::from(name_spans));
 
             // Yield and quality on one line
             let quality_color = if harvest.quality_score >= 90.0 {
@@ -115,11 +263,16 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
             };
 
             lines.push(Line::from(vec![
-                Span::raw("   Yield: "),
+                Span::raw("   Bud: "),
                 Span::styled(
-                    format!("{:.1}g", harvest.weight_grams),
+                    format_weight(harvest.bud_grams, app.weight_unit),
                     Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
                 ),
+                Span::raw(" + trim "),
+                Span::styled(
+                    format_weight(harvest.trim_grams, app.weight_unit),
+                    Style::default().fg(Color::Gray),
+                ),
                 Span::raw(" | Quality: "),
                 Span::styled(
                     format!("{:.0}%", harvest.quality_score),
@@ -128,6 +281,38 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
                 Span::raw(format!(" | Day {}", harvest.harvest_day)),
             ]));
 
+            // Genetic potential versus what the grow achieved
+            if let Some((potential_grams, ceiling)) = harvest.potential() {
+                lines.push(Line::from(Span::styled(
+                    format!(
+                        "   Potential {} / {:.0}% - achieved {} / {:.0}%",
+                        format_weight(potential_grams, app.weight_unit),
+                        ceiling,
+                        format_weight(harvest.weight_grams, app.weight_unit),
+                        harvest.quality_score
+                    ),
+                    Style::default().fg(Color::Gray),
+                )));
+            }
+
+            // Drying record (harvests from before drying have none)
+            if let (Some(wet), Some(days)) = (harvest.wet_weight_grams, harvest.dry_days) {
+                lines.push(Line::from(Span::styled(
+                    format!(
+                        "   Dried {} wet over {:.0} days",
+                        format_weight(wet, app.weight_unit),
+                        days
+                    ),
+                    Style::default().fg(Color::Gray),
+                )));
+            }
+            if let Some(days) = harvest.cure_days {
+                lines.push(Line::from(Span::styled(
+                    format!("   Cured {:.0} days in a jar", days),
+                    Style::default().fg(Color::Gray),
+                )));
+            }
+
             // Cannabinoids on another line
             lines.push(Line::from(vec![
                 Span::raw("   THC: "),
@@ -142,6 +327,32 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
                 ),
             ]));
 
+            // Quality breakdown (older saves don't have one)
+            if let Some(ref breakdown) = harvest.breakdown {
+                lines.push(Line::from(Span::styled(
+                    format!("   {}", breakdown.summary()),
+                    Style::default().fg(Color::Gray),
+                )));
+            }
+
+            // Flavor report (older saves have no terpene data)
+            if !harvest.aroma.is_empty() || harvest.terpene_score > 0.0 {
+                lines.push(Line::from(Span::styled(
+                    format!("   {}", harvest.flavor_report()),
+                    Style::default().fg(Color::LightYellow),
+                )));
+            }
+            if !harvest.dominant_terpenes.is_empty() {
+                lines.push(Line::from(Span::styled(
+                    format!(
+                        "   Terpenes: {} | Aroma: {}",
+                        harvest.dominant_terpenes.join(", "),
+                        harvest.aroma.join(", ")
+                    ),
+                    Style::default().fg(Color::Gray),
+                )));
+            }
+
             lines.push(Line::from("")); // Spacing between harvests
         }
     }
@@ -157,11 +368,39 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
     lines.push(Line::from("Each plant is unique with different genetics"));
     lines.push(Line::from("by ZeD - zednaked@gmail.com"));
     lines.push(Line::from(""));
+    lines.push(Line::from(format!(
+        "Strain database: {} ({} strains)",
+        strain_db.source,
+        strain_db.strains.len()
+    )));
+    for pack in &strain_db.packs {
+        lines.push(Line::from(format!("+ pack: {}", pack.display())));
+    }
+    for note in &strain_db.overrides {
+        lines.push(Line::from(Span::styled(note.clone(), Style::default().fg(Color::DarkGray))));
+    }
+    for warning in &strain_db.warnings {
+        lines.push(Line::from(Span::styled(
+            warning.clone(),
+            Style::default().fg(Color::Yellow),
+        )));
+    }
+    lines.push(Line::from(""));
     lines.push(Line::from("Press [1] to return to Growing Room"));
 
     let paragraph = Paragraph::new(lines)
         .block(Block::default().borders(Borders::ALL).title("[ Statistics & About ]"))
         .alignment(Alignment::Center);
 
-    f.render_widget(paragraph, area);
+    // Active season goal gets a progress gauge above the stats
+    let Some(ref goal) = app.goal else {
+        f.render_widget(paragraph, area);
+        return;
+    };
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)])
+        .split(area);
+    f.render_widget(goals::goal_gauge(app, goal), chunks[0]);
+    f.render_widget(paragraph, chunks[1]);
 }
@@ -0,0 +1,53 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::{Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+
+/// Render an error on the bottom row of the screen
+pub fn render(f: &mut Frame, error: &str, area: Rect) {
+    if area.height == 0 {
+        return;
+    }
+
+    let line = Rect {
+        x: area.x,
+        y: area.y + area.height - 1,
+        width: area.width,
+        height: 1,
+    };
+
+    let widget = Paragraph::new(format!(" ! {}", error))
+        .style(Style::default().fg(Color::White).bg(Color::Red).add_modifier(Modifier::BOLD));
+
+    f.render_widget(Clear, line);
+    f.render_widget(widget, line);
+}
+
+/// Bottom-right save marker: "Saved" just after a manual save, a dim "*"
+/// while there are unsaved changes (nothing when the session isn't saved)
+pub fn render_save_state(f: &mut Frame, app: &App, area: Rect) {
+    if app.save_dir.is_none() || area.height == 0 {
+        return;
+    }
+
+    let (text, style) = if app.saved_flash_frames > 0 {
+        (" Saved ", Style::default().fg(Color::Black).bg(Color::Green).add_modifier(Modifier::BOLD))
+    } else if app.save_state.is_dirty() {
+        ("*", Style::default().fg(Color::DarkGray))
+    } else {
+        return;
+    };
+
+    let width = (text.len() as u16).min(area.width);
+    let marker = Rect {
+        x: area.x + area.width - width,
+        y: area.y + area.height - 1,
+        width,
+        height: 1,
+    };
+    f.render_widget(Paragraph::new(text).style(style), marker);
+}
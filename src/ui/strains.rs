@@ -0,0 +1,270 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::app::App;
+use crate::config::Action;
+use crate::domain::genetics::StrainInfo;
+use crate::domain::strains;
+use crate::domain::HarvestResult;
+use crate::ui::units::format_weight;
+
+/// Rows moved by PageUp/PageDown on the strain library
+pub const LIBRARY_PAGE: isize = 10;
+
+/// Sort order of the strain library table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LibrarySort {
+    #[default]
+    Name,
+    Thc,
+    TimesGrown,
+}
+
+impl LibrarySort {
+    pub fn next(&self) -> Self {
+        match self {
+            LibrarySort::Name => LibrarySort::Thc,
+            LibrarySort::Thc => LibrarySort::TimesGrown,
+            LibrarySort::TimesGrown => LibrarySort::Name,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            LibrarySort::Name => "name",
+            LibrarySort::Thc => "THC",
+            LibrarySort::TimesGrown => "times grown",
+        }
+    }
+}
+
+/// Player's record with one strain
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StrainRecord {
+    pub times_grown: usize,
+    pub best_yield: f32,
+    pub best_quality: f32,
+}
+
+impl StrainRecord {
+    pub fn from_history(name: &str, history: &[HarvestResult]) -> Self {
+        history
+            .iter()
+            .filter(|h| h.strain_name == name)
+            .fold(Self::default(), |record, h| Self {
+                times_grown: record.times_grown + 1,
+                best_yield: record.best_yield.max(h.weight_grams),
+                best_quality: record.best_quality.max(h.quality_score),
+            })
+    }
+}
+
+/// Case-insensitive name search used by the library filter
+pub fn matches_query(name: &str, query: &str) -> bool {
+    name.to_lowercase().contains(&query.to_lowercase())
+}
+
+/// Library entries matching the filter in display order, paired with the player's record
+pub fn sorted_entries(app: &App, strains: &[StrainInfo]) -> Vec<(usize, StrainRecord)> {
+    let mut entries: Vec<(usize, StrainRecord)> = strains
+        .iter()
+        .enumerate()
+        .filter(|(_, s)| matches_query(&s.name, &app.library_query))
+        .map(|(i, s)| (i, StrainRecord::from_history(&s.name, &app.harvest_history)))
+        .collect();
+
+    match app.library_sort {
+        LibrarySort::Name => entries.sort_by(|a, b| strains[a.0].name.cmp(&strains[b.0].name)),
+        LibrarySort::Thc => entries.sort_by(|a, b| strains[b.0].thc_max.total_cmp(&strains[a.0].thc_max)),
+        LibrarySort::TimesGrown => entries.sort_by_key(|e| std::cmp::Reverse(e.1.times_grown)),
+    }
+    entries
+}
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(8),    // Table + detail
+            Constraint::Length(3), // Footer
+        ])
+        .split(area);
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(62), Constraint::Percentage(38)])
+        .split(chunks[0]);
+
+    let db = strains::database();
+    let entries = sorted_entries(app, &db.strains);
+    let cursor = app.library_cursor.min(entries.len().saturating_sub(1));
+
+    render_table(f, app, &db.strains, &entries, cursor, columns[0]);
+
+    let detail = match entries.get(cursor) {
+        Some(&(i, record)) => detail_lines(app, &db.strains[i], record),
+        None if !app.library_query.is_empty() => vec![Line::from("No strain matches the search")],
+        None => vec![Line::from("No strains loaded")],
+    };
+    let detail = Paragraph::new(detail)
+        .block(Block::default().borders(Borders::ALL).title("[ Strain Details ]"))
+        .alignment(Alignment::Left);
+    f.render_widget(detail, columns[1]);
+
+    let footer = if app.library_typing {
+        "Type to filter by name  [Backspace] Edit  [Enter] Done  [Esc] Clear".to_string()
+    } else {
+        let keys = &app.keys;
+        format!(
+            "[{}/{}/{}/{}] Browse  [{}] Search  [{}] Sort: {}  |  [1] Growing Room  [s] Stats",
+            keys.label(Action::CursorUp),
+            keys.label(Action::CursorDown),
+            keys.label(Action::PageUp),
+            keys.label(Action::PageDown),
+            keys.label(Action::Search),
+            keys.label(Action::CycleSort),
+            app.library_sort.name()
+        )
+    };
+    let footer = Paragraph::new(footer)
+    .block(Block::default().borders(Borders::ALL))
+    .alignment(Alignment::Center);
+    f.render_widget(footer, chunks[1]);
+}
+
+fn render_table(
+    f: &mut Frame,
+    app: &App,
+    strains: &[StrainInfo],
+    entries: &[(usize, StrainRecord)],
+    cursor: usize,
+    area: Rect,
+) {
+    let header = Row::new(["Name", "Type", "THC", "Flower", "Difficulty", "Grown", "Best"])
+        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+
+    let rows = entries.iter().map(|&(i, record)| {
+        let strain = &strains[i];
+        let best = if record.times_grown > 0 {
+            format_weight(record.best_yield, app.weight_unit)
+        } else {
+            "-".to_string()
+        };
+        Row::new([
+            Cell::from(strain.name.clone()),
+            Cell::from(strain.strain_type.clone()),
+            Cell::from(format!("{:.0}-{:.0}%", strain.thc_min, strain.thc_max)),
+            Cell::from(format!("{}d", strain.flowering_time)),
+            Cell::from(strain.difficulty.clone()),
+            Cell::from(record.times_grown.to_string()),
+            Cell::from(best),
+        ])
+    });
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(16),
+            Constraint::Length(8),
+            Constraint::Length(7),
+            Constraint::Length(6),
+            Constraint::Length(10),
+            Constraint::Length(5),
+            Constraint::Length(8),
+        ],
+    )
+    .header(header)
+    .row_highlight_style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+    .highlight_symbol("> ")
+    .block(Block::default().borders(Borders::ALL).title(library_title(app, strains.len(), entries.len())));
+
+    let mut state = TableState::default().with_selected(Some(cursor));
+    f.render_stateful_widget(table, area, &mut state);
+}
+
+/// Table title: the strain count, or the search and how many strains match it
+fn library_title(app: &App, total: usize, shown: usize) -> String {
+    if app.library_query.is_empty() && !app.library_typing {
+        format!("[ Strain Library ({}) ]", total)
+    } else {
+        let caret = if app.library_typing { "_" } else { "" };
+        format!("[ Strain Library - /{}{} ({} of {}) ]", app.library_query, caret, shown, total)
+    }
+}
+
+/// Full strain card plus the player's record with it
+fn detail_lines(app: &App, strain: &StrainInfo, record: StrainRecord) -> Vec<Line<'static>> {
+    let mut lines = vec![
+        Line::from(Span::styled(
+            strain.name.clone(),
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("Type: {}", strain.strain_type),
+            Style::default().fg(Color::Yellow),
+        )),
+        Line::from(""),
+        section_heading("Genetics:"),
+        Line::from(strain.genetics.clone()),
+        Line::from(""),
+        section_heading("Cannabinoids:"),
+        Line::from(format!("THC: {:.1}-{:.1}%", strain.thc_min, strain.thc_max)),
+        Line::from(format!("CBD: {:.1}-{:.1}%", strain.cbd_min, strain.cbd_max)),
+        Line::from(""),
+    ];
+    lines.extend(characteristics_lines(strain));
+    lines.push(Line::from(""));
+    lines.extend(profile_lines(strain, "Terpenes:".to_string()));
+    lines.push(Line::from(""));
+    lines.push(section_heading("Your grows:"));
+    if record.times_grown == 0 {
+        lines.push(Line::from("Not grown yet"));
+    } else {
+        lines.push(Line::from(format!("Times grown: {}", record.times_grown)));
+        lines.push(Line::from(format!(
+            "Best yield: {}",
+            format_weight(record.best_yield, app.weight_unit)
+        )));
+        lines.push(Line::from(format!("Best quality: {:.0}%", record.best_quality)));
+    }
+    lines
+}
+
+/// Bold green heading used by the strain panels
+pub fn section_heading(title: &str) -> Line<'static> {
+    Line::from(Span::styled(
+        title.to_string(),
+        Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+    ))
+}
+
+/// Difficulty, yield, height and flowering time
+pub fn characteristics_lines(strain: &StrainInfo) -> Vec<Line<'static>> {
+    vec![
+        section_heading("Characteristics:"),
+        Line::from(format!("Difficulty: {}", strain.difficulty)),
+        Line::from(format!("Yield: {}", strain.yield_potential)),
+        Line::from(format!("Height: {}", strain.height)),
+        Line::from(format!("Flowering: {} days", strain.flowering_time)),
+    ]
+}
+
+/// Terpenes, aroma and effects; the terpene heading can carry extra info
+pub fn profile_lines(strain: &StrainInfo, terpene_heading: String) -> Vec<Line<'static>> {
+    vec![
+        section_heading(&terpene_heading),
+        Line::from(strain.dominant_terpenes.join(", ")),
+        Line::from(""),
+        section_heading("Aroma:"),
+        Line::from(strain.aroma.join(", ")),
+        Line::from(""),
+        section_heading("Effects:"),
+        Line::from(strain.effects.join(", ")),
+    ]
+}
@@ -0,0 +1,353 @@
+//! Player-made color palettes
+//!
+//! A palette is a TOML file in `~/.config/ganjatui/palettes/<name>.toml`
+//! (see `palettes/nord.toml` for every key). Anything a file leaves out is
+//! drawn with the built-in truecolor palette.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use ratatui::style::Color;
+use serde::Deserialize;
+
+use crate::domain::GrowthStage;
+use crate::ui::colors::{condition_foliage, weather_trunk, ColorDepth, ColorPalette, FlowerIntensity, TrueColorPalette};
+
+/// Prefix that picks a custom palette in `--theme custom:<name>`
+pub const CUSTOM_PREFIX: &str = "custom:";
+
+type Rgb = (u8, u8, u8);
+
+/// Flower variants and the intensities each lists (early to harvest)
+const FLOWER_VARIANTS: usize = 6;
+const FLOWER_INTENSITIES: usize = 4;
+const FOLIAGE_VARIANTS: usize = 4;
+const TRUNK_VARIANTS: usize = 3;
+
+/// The palette file as written; colors are still hex strings
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct PaletteFile {
+    flowers: Vec<Vec<String>>,
+    foliage: Vec<String>,
+    trunk: Vec<String>,
+    soil: SoilFile,
+    water: Vec<String>,
+    nutrients: Vec<String>,
+    background: BackgroundFile,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct SoilFile {
+    dry: Option<String>,
+    wet: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct BackgroundFile {
+    seed: Option<String>,
+    seedling: Option<String>,
+    vegetative: Option<String>,
+    pre_flower: Option<String>,
+    flowering: Option<String>,
+    ready: Option<String>,
+}
+
+/// A palette loaded from a file; unset colors fall back to `TrueColorPalette`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CustomPalette {
+    pub name: String,
+    flowers: [[Option<Rgb>; FLOWER_INTENSITIES]; FLOWER_VARIANTS],
+    foliage: [Option<Rgb>; FOLIAGE_VARIANTS],
+    trunk: [Option<Rgb>; TRUNK_VARIANTS],
+    soil_dry: Option<Rgb>,
+    soil_wet: Option<Rgb>,
+    water: Vec<Rgb>,     // Gradient stops, evenly spaced from 0% to 100%
+    nutrients: Vec<Rgb>, // Gradient stops, evenly spaced from 0% to 100%
+    background: [Option<Rgb>; 6], // Seed, seedling, vegetative, pre-flower, flowering, ready
+}
+
+/// Directory custom palettes are read from
+pub fn palettes_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("ganjatui").join("palettes"))
+}
+
+/// Names of the palettes in `dir`, sorted (empty when it doesn't exist)
+pub fn list(dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+        .filter_map(|path| path.file_stem().and_then(|stem| stem.to_str()).map(str::to_string))
+        .collect();
+    names.sort();
+    names
+}
+
+/// Read `<dir>/<name>.toml`; the error names the file, or the key at fault
+pub fn load(dir: &Path, name: &str) -> Result<CustomPalette, String> {
+    let path = dir.join(format!("{}.toml", name));
+    let text = fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    parse(name, &text)
+}
+
+/// Parse a palette file's text
+pub fn parse(name: &str, text: &str) -> Result<CustomPalette, String> {
+    let file: PaletteFile = toml::from_str(text).map_err(|e| match e.span() {
+        Some(span) => format!("line {}: {}", text[..span.start].matches('\n').count() + 1, e.message()),
+        None => e.message().to_string(),
+    })?;
+    let mut palette = CustomPalette {
+        name: name.to_string(),
+        ..CustomPalette::default()
+    };
+
+    if file.flowers.len() > FLOWER_VARIANTS {
+        return Err(format!("flowers: at most {} variants", FLOWER_VARIANTS));
+    }
+    for (variant, row) in file.flowers.iter().enumerate() {
+        let key = format!("flowers[{}]", variant);
+        palette.flowers[variant] = fill(&key, row)?;
+    }
+    palette.foliage = fill("foliage", &file.foliage)?;
+    palette.trunk = fill("trunk", &file.trunk)?;
+    palette.soil_dry = optional("soil.dry", file.soil.dry.as_deref())?;
+    palette.soil_wet = optional("soil.wet", file.soil.wet.as_deref())?;
+    palette.water = stops("water", &file.water)?;
+    palette.nutrients = stops("nutrients", &file.nutrients)?;
+
+    let bg = &file.background;
+    palette.background = [
+        optional("background.seed", bg.seed.as_deref())?,
+        optional("background.seedling", bg.seedling.as_deref())?,
+        optional("background.vegetative", bg.vegetative.as_deref())?,
+        optional("background.pre_flower", bg.pre_flower.as_deref())?,
+        optional("background.flowering", bg.flowering.as_deref())?,
+        optional("background.ready", bg.ready.as_deref())?,
+    ];
+    Ok(palette)
+}
+
+/// `#rrggbb` (the `#` is optional) as RGB
+pub fn parse_hex(text: &str) -> Option<Rgb> {
+    let hex = text.trim().strip_prefix('#').unwrap_or(text.trim());
+    if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some((channel(0)?, channel(2)?, channel(4)?))
+}
+
+fn color(key: &str, text: &str) -> Result<Rgb, String> {
+    parse_hex(text).ok_or_else(|| format!("{}: '{}' is not a #rrggbb color", key, text))
+}
+
+fn optional(key: &str, text: Option<&str>) -> Result<Option<Rgb>, String> {
+    text.map(|t| color(key, t)).transpose()
+}
+
+/// The first N slots from a list; shorter lists leave the rest to the fallback
+fn fill<const N: usize>(key: &str, list: &[String]) -> Result<[Option<Rgb>; N], String> {
+    if list.len() > N {
+        return Err(format!("{}: at most {} colors", key, N));
+    }
+    let mut slots = [None; N];
+    for (i, text) in list.iter().enumerate() {
+        slots[i] = Some(color(&format!("{}[{}]", key, i), text)?);
+    }
+    Ok(slots)
+}
+
+fn stops(key: &str, list: &[String]) -> Result<Vec<Rgb>, String> {
+    list.iter()
+        .enumerate()
+        .map(|(i, text)| color(&format!("{}[{}]", key, i), text))
+        .collect()
+}
+
+fn rgb((r, g, b): Rgb) -> Color {
+    Color::Rgb(r, g, b)
+}
+
+fn channels(color: Color) -> Rgb {
+    match color {
+        Color::Rgb(r, g, b) => (r, g, b),
+        _ => (0, 0, 0),
+    }
+}
+
+fn mix(a: Rgb, b: Rgb, t: f32) -> Rgb {
+    let lerp = |x: u8, y: u8| (x as f32 + (y as f32 - x as f32) * t).round() as u8;
+    (lerp(a.0, b.0), lerp(a.1, b.1), lerp(a.2, b.2))
+}
+
+/// Color at `level` (0-100) along evenly spaced stops
+fn gradient(stops: &[Rgb], level: f32) -> Color {
+    let position = level.clamp(0.0, 100.0) / 100.0 * (stops.len() - 1) as f32;
+    let index = (position as usize).min(stops.len().saturating_sub(2));
+    match stops {
+        [only] => rgb(*only),
+        _ => rgb(mix(stops[index], stops[index + 1], position - index as f32)),
+    }
+}
+
+fn intensity_index(intensity: FlowerIntensity) -> usize {
+    match intensity {
+        FlowerIntensity::Early => 0,
+        FlowerIntensity::Developing => 1,
+        FlowerIntensity::Peak => 2,
+        FlowerIntensity::Harvest => 3,
+    }
+}
+
+fn stage_index(stage: GrowthStage) -> usize {
+    match stage {
+        GrowthStage::Seed | GrowthStage::Germination => 0,
+        GrowthStage::Seedling => 1,
+        GrowthStage::Vegetative => 2,
+        GrowthStage::PreFlower => 3,
+        GrowthStage::Flowering => 4,
+        GrowthStage::ReadyToHarvest => 5,
+    }
+}
+
+impl ColorPalette for CustomPalette {
+    fn flower_color(&self, variant: u8, intensity: FlowerIntensity, stage: GrowthStage) -> Color {
+        match self.flowers[variant as usize % FLOWER_VARIANTS][intensity_index(intensity)] {
+            Some(color) => rgb(color),
+            None => TrueColorPalette.flower_color(variant, intensity, stage),
+        }
+    }
+
+    fn foliage_color(&self, variant: u8, health: f32, water: f32) -> Color {
+        match self.foliage[variant as usize % FOLIAGE_VARIANTS] {
+            Some(base) => condition_foliage(base, health, water),
+            None => TrueColorPalette.foliage_color(variant, health, water),
+        }
+    }
+
+    fn trunk_color(&self, variant: u8, age_days: u32) -> Color {
+        match self.trunk[variant as usize % TRUNK_VARIANTS] {
+            Some(base) => weather_trunk(base, age_days),
+            None => TrueColorPalette.trunk_color(variant, age_days),
+        }
+    }
+
+    fn soil_color(&self, moisture: f32) -> Color {
+        if self.soil_dry.is_none() && self.soil_wet.is_none() {
+            return TrueColorPalette.soil_color(moisture);
+        }
+        let dry = self.soil_dry.unwrap_or_else(|| channels(TrueColorPalette.soil_color(0.0)));
+        let wet = self.soil_wet.unwrap_or_else(|| channels(TrueColorPalette.soil_color(100.0)));
+        rgb(mix(dry, wet, moisture.clamp(0.0, 100.0) / 100.0))
+    }
+
+    fn water_color(&self, level: f32) -> Color {
+        if self.water.is_empty() {
+            return TrueColorPalette.water_color(level);
+        }
+        gradient(&self.water, level)
+    }
+
+    fn nutrient_color(&self, level: f32) -> Color {
+        if self.nutrients.is_empty() {
+            return TrueColorPalette.nutrient_color(level);
+        }
+        gradient(&self.nutrients, level)
+    }
+
+    fn background_tint(&self, stage: GrowthStage) -> Option<Color> {
+        match self.background[stage_index(stage)] {
+            Some(color) => Some(rgb(color)),
+            None => TrueColorPalette.background_tint(stage),
+        }
+    }
+
+    fn depth(&self) -> ColorDepth {
+        ColorDepth::TrueColor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_shipped_example_sets_every_key() {
+        let nord = parse("nord", include_str!("../../palettes/nord.toml")).unwrap();
+        assert!(nord.flowers.iter().flatten().all(Option::is_some));
+        assert!(nord.foliage.iter().chain(&nord.trunk).chain(&nord.background).all(Option::is_some));
+        assert!(nord.soil_dry.is_some() && nord.soil_wet.is_some());
+        assert!(!nord.water.is_empty() && !nord.nutrients.is_empty());
+    }
+
+    #[test]
+    fn partial_files_fall_back_to_truecolor() {
+        let palette = parse(
+            "partial",
+            r##"
+            flowers = [["#ff0000"]]
+            water = ["#000000", "#ffffff"]
+
+            [soil]
+            wet = "#102030"
+            "##,
+        )
+        .unwrap();
+        let stock = TrueColorPalette;
+
+        let early = palette.flower_color(0, FlowerIntensity::Early, GrowthStage::Flowering);
+        assert_eq!(early, Color::Rgb(255, 0, 0));
+        for (variant, intensity) in [(0, FlowerIntensity::Peak), (3, FlowerIntensity::Early)] {
+            assert_eq!(
+                palette.flower_color(variant, intensity, GrowthStage::Flowering),
+                stock.flower_color(variant, intensity, GrowthStage::Flowering)
+            );
+        }
+        assert_eq!(palette.water_color(50.0), Color::Rgb(128, 128, 128));
+        assert_eq!(palette.nutrient_color(40.0), stock.nutrient_color(40.0));
+        assert_eq!(palette.trunk_color(1, 60), stock.trunk_color(1, 60));
+        assert_eq!(palette.soil_color(100.0), Color::Rgb(16, 32, 48));
+        assert_eq!(palette.soil_color(0.0), stock.soil_color(0.0), "the dry end falls back");
+        assert_eq!(palette.background_tint(GrowthStage::Vegetative), stock.background_tint(GrowthStage::Vegetative));
+    }
+
+    #[test]
+    fn custom_foliage_still_shows_the_plants_condition() {
+        let palette = parse("green", r##"foliage = ["#3c8c3c"]"##).unwrap();
+        for (health, water) in [(100.0, 60.0), (30.0, 20.0), (70.0, 95.0)] {
+            assert_eq!(palette.foliage_color(0, health, water), TrueColorPalette.foliage_color(0, health, water));
+        }
+    }
+
+    #[test]
+    fn bad_colors_name_the_offending_key() {
+        let err = parse("bad", "[soil]\ndry = \"#12345\"").unwrap_err();
+        assert!(err.contains("soil.dry") && err.contains("#12345"), "{}", err);
+
+        let err = parse("bad", r##"flowers = [[], ["#abcdef", "#zzzzzz"]]"##).unwrap_err();
+        assert!(err.starts_with("flowers[1][1]"), "{}", err);
+
+        let err = parse("bad", r##"trunk = ["#000000", "#000000", "#000000", "#000000"]"##).unwrap_err();
+        assert!(err.starts_with("trunk:"), "{}", err);
+
+        let err = parse("bad", "[background]\nharvest = \"#000000\"").unwrap_err();
+        assert!(err.contains("harvest"), "unknown keys are reported: {}", err);
+
+        let err = parse("bad", "water = [\"#000000\"]\nfoliage = \"#3c8c3c\"").unwrap_err();
+        assert!(err.starts_with("line 2:"), "{}", err);
+    }
+
+    #[test]
+    fn hex_colors_parse_with_or_without_the_hash() {
+        assert_eq!(parse_hex("#2e3440"), Some((0x2e, 0x34, 0x40)));
+        assert_eq!(parse_hex("ECEFF4"), Some((0xec, 0xef, 0xf4)));
+        assert_eq!(parse_hex("#ggg000"), None);
+        assert_eq!(parse_hex("#fff"), None);
+        assert_eq!(parse_hex("#ééééé"), None);
+    }
+}
@@ -0,0 +1,56 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::app::Toast;
+use crate::ascii::get_grade_ascii;
+use crate::ui::colors::grade_color;
+
+/// Columns taken by the grade letter (3 wide plus spacing)
+const GRADE_WIDTH: u16 = 5;
+
+/// Render a toast just above the bottom edge of the screen
+pub fn render(f: &mut Frame, toast: &Toast, area: Rect) {
+    let grade_width = if toast.grade.is_some() { GRADE_WIDTH } else { 0 };
+
+    // Long messages wrap onto extra lines instead of being cut off
+    let text_width = toast.text.chars().count() as u16;
+    let width = (text_width + grade_width + 4).min(area.width.saturating_sub(4)).max(3);
+    let inner_width = width.saturating_sub(grade_width + 4).max(1);
+    let text_rows = text_width.div_ceil(inner_width);
+    let rows = if toast.grade.is_some() { text_rows.max(3) } else { text_rows };
+    let height = (rows + 2).min(area.height);
+    let popup = Rect {
+        x: area.x + area.width.saturating_sub(width) / 2,
+        y: area.y + area.height.saturating_sub(height + 3),
+        width,
+        height,
+    };
+
+    let block = Block::default().borders(Borders::ALL);
+    let inner = block.inner(popup);
+    f.render_widget(Clear, popup);
+    f.render_widget(block, popup);
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(grade_width), Constraint::Min(1)])
+        .split(inner);
+
+    if let Some(grade) = toast.grade {
+        let letter: Vec<Line> = get_grade_ascii(grade).iter().map(|row| Line::from(format!(" {}", row))).collect();
+        let letter = Paragraph::new(letter)
+            .style(Style::default().fg(grade_color(grade)).add_modifier(Modifier::BOLD));
+        f.render_widget(letter, columns[0]);
+    }
+
+    let widget = Paragraph::new(toast.text.as_str())
+        .style(Style::default().fg(Color::LightGreen).add_modifier(Modifier::BOLD))
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+    f.render_widget(widget, columns[1]);
+}
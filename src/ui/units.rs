@@ -0,0 +1,75 @@
+use chrono::TimeDelta;
+use serde::{Deserialize, Serialize};
+
+/// Grams in one avoirdupois ounce
+pub const GRAMS_PER_OUNCE: f32 = 28.3495;
+
+/// Unit used when displaying weights
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum WeightUnit {
+    #[default]
+    Grams,
+    Ounces,
+}
+
+impl WeightUnit {
+    /// Switch to the other unit
+    pub fn toggle(&self) -> Self {
+        match self {
+            WeightUnit::Grams => WeightUnit::Ounces,
+            WeightUnit::Ounces => WeightUnit::Grams,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            WeightUnit::Grams => "grams",
+            WeightUnit::Ounces => "ounces",
+        }
+    }
+}
+
+/// Format a weight for display: "12.5g" or "0.44oz"
+pub fn format_weight(grams: f32, unit: WeightUnit) -> String {
+    match unit {
+        WeightUnit::Grams => format!("{:.1}g", grams),
+        WeightUnit::Ounces => format!("{:.2}oz", grams / GRAMS_PER_OUNCE),
+    }
+}
+
+/// Real time elapsed in words: "moments ago", "5 minutes ago", "3 days ago", "2 weeks ago"
+pub fn format_ago(elapsed: TimeDelta) -> String {
+    let plural = |n: i64, unit: &str| format!("{} {}{} ago", n, unit, if n == 1 { "" } else { "s" });
+    match elapsed {
+        e if e < TimeDelta::minutes(1) => "moments ago".to_string(),
+        e if e < TimeDelta::hours(1) => plural(e.num_minutes(), "minute"),
+        e if e < TimeDelta::days(1) => plural(e.num_hours(), "hour"),
+        e if e < TimeDelta::weeks(2) => plural(e.num_days(), "day"),
+        e if e < TimeDelta::days(60) => plural(e.num_weeks(), "week"),
+        e => plural(e.num_days() / 30, "month"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn real_time_reads_naturally() {
+        assert_eq!(format_ago(TimeDelta::seconds(-5)), "moments ago");
+        assert_eq!(format_ago(TimeDelta::seconds(20)), "moments ago");
+        assert_eq!(format_ago(TimeDelta::minutes(1)), "1 minute ago");
+        assert_eq!(format_ago(TimeDelta::minutes(59)), "59 minutes ago");
+        assert_eq!(format_ago(TimeDelta::hours(5)), "5 hours ago");
+        assert_eq!(format_ago(TimeDelta::days(3)), "3 days ago");
+        assert_eq!(format_ago(TimeDelta::days(15)), "2 weeks ago");
+        assert_eq!(format_ago(TimeDelta::days(95)), "3 months ago");
+    }
+
+    #[test]
+    fn formats_both_units() {
+        assert_eq!(format_weight(56.699, WeightUnit::Grams), "56.7g");
+        assert_eq!(format_weight(56.699, WeightUnit::Ounces), "2.00oz");
+        assert_eq!(WeightUnit::Grams.toggle().toggle(), WeightUnit::Grams);
+    }
+}
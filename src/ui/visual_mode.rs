@@ -1,9 +1,10 @@
 use serde::{Deserialize, Serialize};
 
 /// Visual modes for different aesthetic themes
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum VisualMode {
     /// Current RGB/256/16 color system (default)
+    #[default]
     Normal,
     /// Zen Garden - minimalist, soft colors, slow breathing
     Zen,
@@ -24,6 +25,16 @@ impl VisualMode {
         }
     }
 
+    /// Cycle to the previous visual mode
+    pub fn prev(&self) -> Self {
+        match self {
+            VisualMode::Normal => VisualMode::Matrix,
+            VisualMode::Zen => VisualMode::Normal,
+            VisualMode::Rainbow => VisualMode::Zen,
+            VisualMode::Matrix => VisualMode::Rainbow,
+        }
+    }
+
     /// Get the display name of the mode
     pub fn name(&self) -> &'static str {
         match self {
@@ -35,8 +46,23 @@ impl VisualMode {
     }
 }
 
-impl Default for VisualMode {
-    fn default() -> Self {
-        VisualMode::Normal
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [VisualMode; 4] = [
+        VisualMode::Normal,
+        VisualMode::Zen,
+        VisualMode::Rainbow,
+        VisualMode::Matrix,
+    ];
+
+    #[test]
+    fn next_and_prev_are_inverses() {
+        for mode in ALL {
+            assert_eq!(mode.next().prev(), mode);
+            assert_eq!(mode.prev().next(), mode);
+        }
     }
 }
@@ -1,11 +1,24 @@
 use chrono::Utc;
+use uuid::Uuid;
 
 use crate::app::App;
+use crate::domain::goals::Goal;
+use crate::domain::plant::GrowthStage;
 use crate::message::Message;
+use crate::ui::export::Export;
 
 /// Update function - pure state transformation (The Elm Architecture)
 /// Takes current state + message, returns new state
 pub fn update(mut app: App, message: Message) -> App {
+    if message.changes_save() {
+        app.save_state.mark_dirty();
+    }
+
+    // Any other action closes the window for undoing a harvest
+    if !matches!(message, Message::Tick | Message::FocusChanged(_) | Message::UndoHarvest) {
+        app.last_harvest_undo = None;
+    }
+
     match message {
         Message::Tick => {
             // Calculate elapsed time since last tick
@@ -15,7 +28,13 @@ pub fn update(mut app: App, message: Message) -> App {
 
             // Update time-based state
             if elapsed_seconds > 0.0 {
+                let before = milestones(&app);
                 app.update_time(elapsed_seconds);
+                // Plain growth is caught by the exit save; a new stage,
+                // harvest or finished batch is worth an autosave
+                if milestones(&app) != before {
+                    app.save_state.mark_dirty();
+                }
             }
         }
 
@@ -24,19 +43,299 @@ pub fn update(mut app: App, message: Message) -> App {
         }
 
         Message::Quit => {
+            app.request_quit();
+        }
+
+        Message::SaveAndQuit => {
+            // The event loop saves unsaved changes on the way out
+            app.running = false;
+        }
+
+        Message::QuitWithoutSaving => {
+            app.save_state.forget_changes();
             app.running = false;
         }
 
+        Message::CancelQuit => {
+            app.confirm_quit = false;
+            app.toast = None;
+        }
+
+        Message::SaveNow => {
+            app.save_requested = true;
+        }
+
+        Message::CycleAutosave => {
+            app.cycle_autosave();
+        }
+
+        Message::CycleOfflineGrowth => {
+            app.cycle_offline_growth();
+        }
+
         Message::HarvestPlant => {
             // Harvest and automatically replant
             app.harvest_and_replant();
         }
 
+        Message::PreviewHarvest => {
+            app.preview_harvest();
+        }
+
+        Message::ConfirmHarvest => {
+            if app.pending_harvest.take().is_some() {
+                app.harvest_and_replant();
+            }
+        }
+
+        Message::CancelHarvest => {
+            app.pending_harvest = None;
+        }
+
+        Message::UndoHarvest => {
+            app.undo_harvest();
+        }
+
+        Message::PartialHarvest => {
+            app.partial_harvest();
+        }
+
+        Message::RequestNewGame => {
+            app.request_new_game();
+        }
+
+        Message::NewGame => {
+            app.start_new_game();
+        }
+
+        Message::CancelNewGame => {
+            app.confirm_new_game = false;
+            app.toast = None;
+        }
+
+        Message::FinishDrying => {
+            app.finish_drying();
+        }
+
+        Message::BurpJar => {
+            app.burp_jar();
+        }
+
+        Message::FinishCure => {
+            app.finish_cure();
+        }
+
         Message::ToggleAutoHarvest => {
             // Toggle full auto mode
             app.toggle_auto_harvest();
         }
 
+        Message::AdjustAutoHarvestDelay(delta) => {
+            app.adjust_auto_harvest_delay(delta);
+        }
+
+        Message::ToggleAutoCare => {
+            // Switch between auto-care and manual care
+            app.toggle_auto_care();
+        }
+
+        Message::ToggleHelp => {
+            app.toggle_help();
+        }
+
+        Message::WaterPlant => {
+            app.water_plant();
+        }
+
+        Message::FeedPlant => {
+            app.feed_plant();
+        }
+
+        Message::LightUp => {
+            app.adjust_light(1.0);
+        }
+
+        Message::LightDown => {
+            app.adjust_light(-1.0);
+        }
+
+        Message::ToggleCo2 => {
+            app.toggle_co2();
+        }
+
+        Message::ToggleBell => {
+            app.toggle_bell();
+        }
+
+        Message::TogglePauseWhenReady => {
+            app.toggle_pause_when_ready();
+        }
+
+        Message::LibraryCursor(delta) => {
+            app.move_library_cursor(delta);
+        }
+
+        Message::PlantStrainOfTheDay => {
+            app.plant_strain_of_the_day();
+        }
+
+        Message::GoalCursor(delta) => {
+            app.move_goal_cursor(delta);
+        }
+
+        Message::SetGoal => {
+            app.set_goal(Goal::PRESETS[app.goal_cursor.min(Goal::PRESETS.len() - 1)]);
+        }
+
+        Message::ClearGoal => {
+            app.clear_goal();
+        }
+
+        Message::CycleLibrarySort => {
+            app.cycle_library_sort();
+        }
+
+        Message::StartLibrarySearch => {
+            app.start_library_search();
+        }
+
+        Message::LibrarySearchInput(c) => {
+            app.library_search_input(c);
+        }
+
+        Message::LibrarySearchBackspace => {
+            app.library_search_backspace();
+        }
+
+        Message::FinishLibrarySearch => {
+            app.finish_library_search();
+        }
+
+        Message::CancelLibrarySearch => {
+            app.cancel_library_search();
+        }
+
+        Message::CompareCursor(delta) => {
+            app.move_compare_cursor(delta);
+        }
+
+        Message::SwapCompareFocus => {
+            app.swap_compare_focus();
+        }
+
+        Message::ReloadStrains => {
+            app.reload_strains();
+        }
+
+        Message::ToggleCompression => {
+            app.toggle_compression();
+        }
+
+        Message::CycleSaveFormat => {
+            app.cycle_save_format();
+        }
+
+        Message::ToggleWeightUnit => {
+            app.toggle_weight_unit();
+        }
+
+        Message::FastForward => {
+            // Skip ahead to harvest without redrawing every step
+            app.fast_forward();
+        }
+
+        Message::CycleVisualModePrev => {
+            app.cycle_visual_mode_prev();
+        }
+
+        Message::SetVisualMode(mode) => {
+            app.set_visual_mode(mode);
+        }
+
+        Message::CycleRenderStyle => {
+            app.cycle_render_style();
+        }
+
+        Message::CycleTheme => {
+            app.cycle_theme();
+        }
+
+        Message::ToggleReducedMotion => {
+            app.toggle_reduced_motion();
+        }
+
+        Message::CycleDifficulty => {
+            app.cycle_difficulty();
+        }
+
+        Message::CycleMedium => {
+            app.cycle_medium();
+        }
+
+        Message::BreedingCursor(delta) => {
+            app.move_breeding_cursor(delta);
+        }
+
+        Message::ToggleBreedingParent => {
+            app.toggle_breeding_parent();
+        }
+
+        Message::Breed => {
+            app.breed_selected();
+        }
+
+        Message::ExportPlantCode => {
+            app.export_plant_code();
+        }
+
+        Message::ExportPlantArt => {
+            app.request_export(Export::Art);
+        }
+
+        Message::ExportDiary => {
+            app.request_export(Export::Diary);
+        }
+
+        Message::CloseShareCode => {
+            app.share_code = None;
+        }
+
+        Message::StartCodeImport => {
+            app.code_input = Some(String::new());
+        }
+
+        Message::CodeInput(c) => {
+            if let Some(ref mut input) = app.code_input {
+                input.push(c);
+            }
+        }
+
+        Message::CodeInputBackspace => {
+            if let Some(ref mut input) = app.code_input {
+                input.pop();
+            }
+        }
+
+        Message::ImportPlantCode => {
+            app.import_plant_code();
+        }
+
+        Message::CancelCodeImport => {
+            app.code_input = None;
+        }
+
+        Message::DismissNotice => {
+            app.notice = None;
+        }
+
+        Message::CycleFrameRate => {
+            app.cycle_frame_rate();
+        }
+
+        Message::FocusChanged(focused) => {
+            app.focused = focused;
+        }
+
         Message::CycleVisualMode => {
             // Cycle to next visual mode
             app.cycle_visual_mode();
@@ -45,3 +344,14 @@ pub fn update(mut app: App, message: Message) -> App {
 
     app
 }
+
+/// What a tick can change that the player would miss after a crash: the
+/// plant and its stage, harvests taken and batches moving through the jar
+fn milestones(app: &App) -> (Option<(Uuid, GrowthStage)>, usize, usize, bool) {
+    (
+        app.current_plant.as_ref().map(|plant| (plant.id, plant.stage)),
+        app.harvest_history.len(),
+        app.drying.len(),
+        app.curing.is_some(),
+    )
+}
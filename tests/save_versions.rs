@@ -0,0 +1,23 @@
+use std::fs;
+use std::path::PathBuf;
+
+use ganjatui::storage::{migrate, SAVE_VERSION};
+
+fn fixtures() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+}
+
+#[test]
+fn every_historical_save_migrates_to_the_current_version() {
+    for version in 0..=SAVE_VERSION {
+        let path = fixtures().join(format!("save_v{}.json", version));
+        let json = fs::read_to_string(&path).unwrap_or_else(|_| panic!("missing fixture {}", path.display()));
+        let value = serde_json::from_str(&json).unwrap();
+
+        let app = migrate(value).unwrap_or_else(|e| panic!("save v{} failed to migrate: {}", version, e));
+        assert_eq!(app.version, SAVE_VERSION);
+        assert_eq!(app.harvest_history.len(), 1, "save v{} lost its harvest", version);
+        assert_eq!(app.harvest_history[0].dominant_terpenes.len(), 3, "save v{} lost its terpenes", version);
+        assert!(app.current_plant.is_some());
+    }
+}
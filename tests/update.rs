@@ -0,0 +1,655 @@
+use std::time::Instant;
+
+use chrono::Utc;
+
+use ganjatui::domain::{strains, Difficulty, Goal, GrowMedium, GrowthStage, HarvestResult, HealthStatus};
+use ganjatui::ui::{ColorDepth, Export, VisualMode};
+use ganjatui::{update, App, Message, Screen};
+
+/// Build an app (16-color palette) whose plant is forced into `stage`
+fn app_with_stage(stage: GrowthStage) -> App {
+    app_with_stage_from(App::new(ColorDepth::Basic16), stage)
+}
+
+/// Force the plant of an existing app into `stage`
+fn app_with_stage_from(mut app: App, stage: GrowthStage) -> App {
+    let plant = app.current_plant.as_mut().expect("new app starts with a plant");
+    plant.days_alive = plant.stage_start_day(stage);
+    plant.total_hours_elapsed = plant.days_alive as f32 * 24.0;
+    plant.stage = stage;
+    app
+}
+
+#[test]
+fn quit_stops_running() {
+    let app = update(App::new(ColorDepth::Basic16), Message::Quit);
+    assert!(!app.running);
+}
+
+#[test]
+fn quitting_with_autosave_off_asks_about_unsaved_changes() {
+    let mut app = App::new(ColorDepth::Basic16);
+    app.autosave_seconds = 0;
+    app.save_dir = Some(std::env::temp_dir());
+
+    let app = update(app, Message::WaterPlant);
+    assert!(app.save_state.is_dirty());
+    let app = update(app, Message::Quit);
+    assert!(app.running && app.confirm_quit, "the player is asked first");
+
+    let app = update(app, Message::CancelQuit);
+    assert!(app.running && !app.confirm_quit);
+
+    let app = update(update(app, Message::Quit), Message::QuitWithoutSaving);
+    assert!(!app.running);
+    assert!(!app.save_state.is_dirty(), "nothing is left for the exit save");
+    assert!(!app.save_state.exit_save_due());
+}
+
+#[test]
+fn the_quit_prompt_expires_with_its_toast() {
+    let mut app = App::new(ColorDepth::Basic16);
+    app.autosave_seconds = 0;
+    app.save_dir = Some(std::env::temp_dir());
+
+    let mut app = update(update(app, Message::WaterPlant), Message::Quit);
+    assert!(app.confirm_quit);
+    while app.toast.is_some() {
+        app.update_time(0.0);
+    }
+    assert!(app.running && !app.confirm_quit, "no prompt on screen, nothing to answer");
+
+    let app = update(app, Message::Quit);
+    assert!(app.running && app.confirm_quit && app.toast.is_some(), "quitting asks again");
+}
+
+#[test]
+fn only_changes_to_the_save_make_it_dirty() {
+    // Settle a seedling between stages first
+    let mut app = App::with_seed(ColorDepth::Basic16, 1333);
+    app.current_plant.as_mut().unwrap().total_hours_elapsed = 5.0 * 24.0;
+    app.last_tick = Utc::now() - chrono::Duration::milliseconds(50);
+    let mut app = update(app, Message::Tick);
+    assert_eq!(app.current_plant.as_ref().unwrap().stage, GrowthStage::Seedling);
+    app.save_state.mark_saved(Instant::now());
+
+    // Ticks that only move the clock, focus changes and browsing stay clean
+    app.last_tick = Utc::now() - chrono::Duration::milliseconds(50);
+    let mut app = update(app, Message::Tick);
+    for message in [
+        Message::FocusChanged(false),
+        Message::SwitchScreen(Screen::Stats),
+        Message::ToggleHelp,
+        Message::ToggleHelp,
+        Message::SaveNow,
+    ] {
+        app = update(app, message);
+    }
+    assert!(!app.save_state.is_dirty());
+
+    // A tick that carries the plant into a new stage is worth saving
+    let plant = app.current_plant.as_mut().unwrap();
+    plant.total_hours_elapsed = plant.stage_start_day(GrowthStage::Vegetative) as f32 * 24.0 - 1.0;
+    app.last_tick = Utc::now() - chrono::Duration::seconds(2);
+    let app = update(app, Message::Tick);
+    assert_eq!(app.current_plant.as_ref().unwrap().stage, GrowthStage::Vegetative);
+    assert!(app.save_state.is_dirty());
+
+    // So is any care the player gives
+    let mut app = app;
+    app.save_state.mark_saved(Instant::now());
+    let app = update(app, Message::WaterPlant);
+    assert!(app.save_state.is_dirty());
+}
+
+#[test]
+fn switch_screen_changes_screen() {
+    let app = update(App::new(ColorDepth::Basic16), Message::SwitchScreen(Screen::Stats));
+    assert_eq!(app.current_screen, Screen::Stats);
+}
+
+#[test]
+fn strain_comparison_moves_only_the_focused_side() {
+    let app = update(App::new(ColorDepth::Basic16), Message::SwitchScreen(Screen::StrainCompare));
+    assert_eq!(app.compare_cursors, [0, 1]);
+
+    let app = update(app, Message::CompareCursor(2));
+    assert_eq!(app.compare_cursors, [2, 1]);
+
+    let app = update(update(app, Message::SwapCompareFocus), Message::CompareCursor(-5));
+    assert_eq!(app.compare_cursors, [2, 0], "the right side stops at the top of the list");
+    assert_eq!(app.compare_focus, 1);
+}
+
+#[test]
+fn library_search_filters_by_name_and_clamps_the_cursor() {
+    let db = strains::database();
+    let name = db.strains[0].name.clone();
+    let matching = |query: &str| db.strains.iter().filter(|s| s.name.to_lowercase().contains(query)).count();
+
+    let app = update(App::new(ColorDepth::Basic16), Message::SwitchScreen(Screen::StrainLibrary));
+    let app = update(app, Message::LibraryCursor(3));
+    let app = update(app, Message::StartLibrarySearch);
+    assert!(app.library_typing);
+
+    // Typing is case-insensitive and jumps back to the first match
+    let query: String = name.chars().take(3).collect::<String>().to_uppercase();
+    let app = query.chars().fold(app, |app, c| update(app, Message::LibrarySearchInput(c)));
+    assert_eq!(app.library_cursor, 0);
+    let app = update(app, Message::LibraryCursor(100));
+    assert_eq!(app.library_cursor, matching(&query.to_lowercase()) - 1, "the cursor stays on the matches");
+
+    // Nothing matches: the cursor sits at the top; backspace widens the search again
+    let app = update(app, Message::LibrarySearchInput('#'));
+    assert_eq!(update(app.clone(), Message::LibraryCursor(1)).library_cursor, 0);
+    let app = update(app, Message::LibrarySearchBackspace);
+    assert_eq!(app.library_query, query);
+
+    let app = update(app, Message::FinishLibrarySearch);
+    assert!(!app.library_typing && !app.library_query.is_empty(), "Enter keeps the filter");
+    let app = update(app, Message::CancelLibrarySearch);
+    assert!(app.library_query.is_empty());
+}
+
+#[test]
+fn harvest_ready_plant_records_and_replants() {
+    let app = app_with_stage(GrowthStage::ReadyToHarvest);
+    let old_id = app.current_plant.as_ref().unwrap().id;
+
+    let app = update(app, Message::HarvestPlant);
+
+    assert_eq!(app.drying.len(), 1);
+    assert!(app.harvest_history.is_empty(), "harvests dry before they are recorded");
+    assert_eq!(app.total_harvests, 1);
+    let new_plant = app.current_plant.as_ref().expect("harvest replants");
+    assert_ne!(new_plant.id, old_id);
+    assert_eq!(new_plant.stage, GrowthStage::Seed);
+}
+
+#[test]
+fn harvest_preview_waits_for_confirmation() {
+    let app = update(app_with_stage(GrowthStage::Vegetative), Message::PreviewHarvest);
+    assert!(app.pending_harvest.is_none(), "only flowering plants can be harvested");
+
+    let app = update(app_with_stage(GrowthStage::ReadyToHarvest), Message::PreviewHarvest);
+    let projected = app.pending_harvest.as_ref().expect("ready plant gets a preview").weight_grams;
+    assert!(app.drying.is_empty());
+
+    let app = update(app, Message::CancelHarvest);
+    assert!(app.pending_harvest.is_none());
+    assert!(app.drying.is_empty());
+
+    let app = update(app, Message::PreviewHarvest);
+    let app = update(app, Message::ConfirmHarvest);
+    assert!(app.pending_harvest.is_none());
+    assert_eq!(app.drying.len(), 1);
+    assert!((app.drying[0].harvest.weight_grams - projected).abs() < 1e-3);
+}
+
+#[test]
+fn a_fresh_harvest_can_be_undone_until_the_next_action() {
+    let app = app_with_stage(GrowthStage::ReadyToHarvest);
+    let old_id = app.current_plant.as_ref().unwrap().id;
+
+    let app = update(update(app, Message::HarvestPlant), Message::UndoHarvest);
+    assert_eq!(app.current_plant.as_ref().unwrap().id, old_id, "the plant is back");
+    assert!(app.drying.is_empty());
+    assert_eq!(app.total_harvests, 0);
+    assert_eq!(app.lifetime.harvests, 0);
+
+    let app = update(app, Message::HarvestPlant);
+    let app = update(update(app, Message::ToggleWeightUnit), Message::UndoHarvest);
+    assert_ne!(app.current_plant.as_ref().unwrap().id, old_id, "another key closed the window");
+    assert_eq!(app.drying.len(), 1);
+    assert_eq!(app.total_harvests, 1);
+}
+
+#[test]
+fn partial_harvest_takes_the_tops_and_finishes_later() {
+    let app = app_with_stage(GrowthStage::ReadyToHarvest);
+    let plant_id = app.current_plant.as_ref().unwrap().id;
+    let full = app.current_plant.as_ref().map(HarvestResult::from_plant).unwrap();
+
+    let app = update(app, Message::PartialHarvest);
+    assert_eq!(app.drying.len(), 1);
+    let tops = &app.drying[0].harvest;
+    assert!(tops.partial);
+    assert!((tops.weight_grams - full.weight_grams * 0.4).abs() < 1e-3);
+    assert_eq!(app.current_plant.as_ref().unwrap().id, plant_id, "the plant keeps growing");
+    assert_eq!(app.total_harvests, 0);
+
+    // Only one partial harvest per plant
+    let mut app = update(app, Message::PartialHarvest);
+    assert_eq!(app.drying.len(), 1);
+
+    // The lower buds come down on their own after about 10 days
+    app.update_time(11.0 * 24.0 * 3600.0 / 130_000.0);
+    assert_ne!(app.current_plant.as_ref().unwrap().id, plant_id);
+    assert_eq!(app.total_harvests, 1);
+    assert_eq!(app.drying.len(), 2);
+    assert!(!app.drying[1].harvest.partial);
+}
+
+#[test]
+fn dry_batches_are_recorded_at_dry_weight() {
+    let mut app = update(app_with_stage(GrowthStage::ReadyToHarvest), Message::HarvestPlant);
+    let wet = app.drying[0].wet_grams;
+
+    // Taking down a wet batch does nothing
+    app = update(app, Message::FinishDrying);
+    assert_eq!(app.drying.len(), 1);
+
+    // 12 in-game days is past every drying schedule
+    app.update_time(12.0 * 24.0 * 3600.0 / 130_000.0);
+    app = update(app, Message::FinishDrying);
+
+    assert!(app.drying.is_empty());
+    assert_eq!(app.harvest_history.len(), 1);
+    let harvest = &app.harvest_history[0];
+    assert_eq!(harvest.wet_weight_grams, Some(wet));
+    assert!(harvest.weight_grams < wet * 0.26);
+}
+
+#[test]
+fn jarred_harvest_returns_to_history_when_cured() {
+    let mut app = update(app_with_stage(GrowthStage::ReadyToHarvest), Message::HarvestPlant);
+    app.update_time(12.0 * 24.0 * 3600.0 / 130_000.0);
+    app = update(app, Message::FinishDrying);
+
+    app = update(app, Message::BurpJar);
+    assert!(app.curing.is_some());
+    assert!(app.harvest_history.is_empty(), "the jarred harvest leaves the history");
+
+    app = update(app, Message::FinishCure);
+    assert!(app.curing.is_none());
+    assert_eq!(app.harvest_history.len(), 1);
+    assert_eq!(app.harvest_history[0].cure_days, Some(0.0));
+
+    // A cured harvest can't be jarred twice
+    app = update(app, Message::BurpJar);
+    assert!(app.curing.is_none());
+}
+
+#[test]
+fn goal_completes_once_and_can_be_cleared() {
+    let mut app = update(App::new(ColorDepth::Basic16), Message::GoalCursor(1));
+    app = update(app, Message::SetGoal);
+    assert_eq!(app.goal, Some(Goal::PRESETS[1]));
+
+    app.set_goal(Goal::Harvests { count: 1 });
+    assert!(!app.goal_completed);
+
+    app = app_with_stage_from(app, GrowthStage::ReadyToHarvest);
+    app = update(app, Message::HarvestPlant);
+    app.update_time(12.0 * 24.0 * 3600.0 / 130_000.0);
+    app = update(app, Message::FinishDrying);
+    assert!(app.goal_completed);
+
+    app = update(app, Message::ClearGoal);
+    assert_eq!(app.goal, None);
+    assert!(!app.goal_completed);
+}
+
+#[test]
+fn jarring_a_harvest_never_sets_a_goal_back() {
+    let mut app = app_with_stage(GrowthStage::ReadyToHarvest);
+    app.set_goal(Goal::TotalYield { grams: 10_000.0 });
+    app = update(app, Message::HarvestPlant);
+    app.update_time(12.0 * 24.0 * 3600.0 / 130_000.0);
+    app = update(app, Message::FinishDrying);
+    let goal = app.goal.unwrap();
+    let dried = goal.progress(&app.lifetime);
+    assert!(dried > 0.0);
+
+    // The jar takes the harvest out of the history, not out of the season
+    app = update(app, Message::BurpJar);
+    assert!(app.curing.is_some() && app.harvest_history.is_empty());
+    assert_eq!(goal.progress(&app.lifetime), dried);
+}
+
+#[test]
+fn new_game_needs_confirmation_and_keeps_settings() {
+    let app = update(app_with_stage(GrowthStage::ReadyToHarvest), Message::HarvestPlant);
+    let app = update(app, Message::ToggleWeightUnit);
+    let unit = app.weight_unit;
+
+    let app = update(app, Message::RequestNewGame);
+    assert!(app.confirm_new_game);
+    let app = update(app, Message::CancelNewGame);
+    assert!(!app.confirm_new_game);
+    assert_eq!(app.total_harvests, 1, "cancelling keeps the run");
+
+    let app = update(update(app, Message::RequestNewGame), Message::NewGame);
+    assert!(!app.confirm_new_game);
+    assert_eq!(app.total_harvests, 0);
+    assert!(app.drying.is_empty());
+    assert_eq!(app.current_plant.as_ref().unwrap().stage, GrowthStage::Seed);
+    assert_eq!(app.weight_unit, unit);
+}
+
+#[test]
+fn harder_difficulties_narrow_the_health_bands_and_outlast_new_games() {
+    // Inside the Normal water band, below the Hard one
+    let health = |app: App| {
+        let mut app = update(app, Message::ToggleAutoCare);
+        let plant = app.current_plant.as_mut().unwrap();
+        plant.water_level = 45.0;
+        plant.nutrient_level = 65.0;
+        app.update_time(0.0);
+        app.current_plant.unwrap().health
+    };
+    let app = app_with_stage(GrowthStage::Vegetative);
+    assert_eq!(app.difficulty, Difficulty::Normal);
+    assert_eq!(health(app), HealthStatus::Good);
+
+    let app = update(app_with_stage(GrowthStage::Vegetative), Message::CycleDifficulty);
+    assert_eq!(app.difficulty, Difficulty::Hard);
+    assert_eq!(health(app), HealthStatus::Fair);
+
+    let app = update(app_with_stage(GrowthStage::Vegetative), Message::CycleDifficulty);
+    let app = update(update(app, Message::RequestNewGame), Message::NewGame);
+    assert_eq!(app.difficulty, Difficulty::Hard, "a new game keeps the difficulty");
+}
+
+#[test]
+fn lifetime_stats_survive_new_games() {
+    let mut app = update(app_with_stage(GrowthStage::ReadyToHarvest), Message::HarvestPlant);
+    app.update_time(12.0 * 24.0 * 3600.0 / 130_000.0);
+    app = update(app, Message::FinishDrying);
+    let harvest = app.harvest_history[0].clone();
+    assert_eq!(app.lifetime.harvests, 1);
+    assert_eq!(app.lifetime.bud_grams, harvest.bud_grams);
+    assert_eq!(app.lifetime.best_quality, harvest.quality_score);
+    assert!((app.lifetime.days_simulated - 12.0).abs() < 0.1, "the new plant grew for 12 days");
+
+    let app = update(update(app, Message::RequestNewGame), Message::NewGame);
+    assert!(app.harvest_history.is_empty());
+    assert_eq!(app.lifetime.harvests, 1);
+    assert_eq!(app.lifetime.bud_grams, harvest.bud_grams);
+}
+
+#[test]
+fn a_missing_palette_is_reported_and_the_theme_is_kept() {
+    let mut app = App::new(ColorDepth::TrueColor);
+    app.palettes_dir = Some(std::env::temp_dir().join(format!("ganjatui-no-palettes-{}", std::process::id())));
+    app.theme = Some("no-such-palette-anywhere".to_string());
+    app.load_theme();
+    assert!(app.custom_palette.is_none());
+    let error = app.status_error.clone().expect("the missing file is reported");
+    assert!(error.contains("no-such-palette-anywhere"), "{}", error);
+    assert_eq!(app.color_palette.depth(), ColorDepth::TrueColor);
+
+    // A later startup error is shown next to it, not instead of it
+    app.report_error("Could not load save".to_string());
+    let error = app.status_error.clone().unwrap();
+    assert!(error.contains("no-such-palette-anywhere") && error.contains("Could not load save"), "{}", error);
+
+    let app = update(update(app, Message::RequestNewGame), Message::NewGame);
+    assert_eq!(app.theme.as_deref(), Some("no-such-palette-anywhere"));
+}
+
+#[test]
+fn the_theme_key_cycles_the_palettes_then_the_built_in_colors() {
+    let dir = std::env::temp_dir().join(format!("ganjatui-palettes-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::copy("palettes/nord.toml", dir.join("nord.toml")).unwrap();
+    std::fs::write(dir.join("red.toml"), "water = [\"#ff0000\"]").unwrap();
+    let mut app = App::new(ColorDepth::TrueColor);
+    app.palettes_dir = Some(dir.clone());
+
+    let app = update(app, Message::CycleTheme);
+    assert_eq!(app.theme.as_deref(), Some("nord"));
+    assert_eq!(app.custom_palette.as_ref().map(|p| p.name.as_str()), Some("nord"));
+    let app = update(app, Message::CycleTheme);
+    assert_eq!(app.theme.as_deref(), Some("red"));
+    let app = update(app, Message::CycleTheme);
+    assert_eq!(app.theme, None);
+    assert!(app.custom_palette.is_none());
+    assert_eq!(app.toast.as_ref().unwrap().text, "Palette: built-in colors");
+    assert!(app.status_error.is_none());
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn grow_medium_is_picked_at_planting_and_changes_the_drain() {
+    // A fresh game's plant hasn't sprouted, so it is repotted right away
+    let app = update(App::new(ColorDepth::Basic16), Message::CycleMedium);
+    assert_eq!(app.medium, GrowMedium::Coco);
+    assert_eq!(app.current_plant.as_ref().unwrap().medium, GrowMedium::Coco);
+
+    // A growing plant keeps its medium; the next one gets the new choice
+    let app = app_with_stage_from(app, GrowthStage::ReadyToHarvest);
+    let app = update(app, Message::CycleMedium);
+    assert_eq!(app.current_plant.as_ref().unwrap().medium, GrowMedium::Coco);
+    let app = update(app, Message::HarvestPlant);
+    assert_eq!(app.current_plant.as_ref().unwrap().medium, GrowMedium::Hydro);
+
+    let drained = |medium: GrowMedium| {
+        let mut app = app_with_stage(GrowthStage::Vegetative);
+        app.auto_care = false;
+        app.current_plant.as_mut().unwrap().medium = medium;
+        app.update_time(10.0 * 3600.0 / 130_000.0);
+        60.0 - app.current_plant.unwrap().water_level
+    };
+    assert!(drained(GrowMedium::Hydro) > drained(GrowMedium::Soil));
+}
+
+#[test]
+fn shared_plant_codes_import_as_seeds() {
+    let sender = update(App::new(ColorDepth::Basic16), Message::ExportPlantCode);
+    let code = sender.share_code.clone().expect("code shown");
+    let sent = sender.current_plant.as_ref().unwrap();
+
+    // Paste the code, with a mistyped character fixed by backspace
+    let mut app = update(App::new(ColorDepth::Basic16), Message::StartCodeImport);
+    for c in code.chars().chain(['!']) {
+        app = update(app, Message::CodeInput(c));
+    }
+    app = update(app, Message::CodeInputBackspace);
+    let app = update(app, Message::ImportPlantCode);
+
+    assert!(app.code_input.is_none());
+    let seed = app.seed_inventory.last().expect("seed imported");
+    assert_eq!(seed.strain_name, sent.strain_name);
+    assert_eq!(seed.genetics.quality_ceiling, sent.genetics.quality_ceiling);
+
+    // Garbage is refused with a message, not a seed
+    let mut app = update(app, Message::StartCodeImport);
+    app = update(app, Message::CodeInput('x'));
+    let app = update(app, Message::ImportPlantCode);
+    assert_eq!(app.seed_inventory.len(), 1);
+    assert!(app.toast.unwrap().text.contains("invalid code"));
+}
+
+#[test]
+fn fast_forward_grows_what_ticking_does_without_the_frames() {
+    const STEP: f32 = 6.0 * 3600.0 / 130_000.0;
+    let fast = update(App::with_seed(ColorDepth::Basic16, 3), Message::FastForward);
+    let mut ticked = App::with_seed(ColorDepth::Basic16, 3);
+    while ticked.current_plant.as_ref().unwrap().stage != GrowthStage::ReadyToHarvest {
+        ticked.update_time(STEP);
+    }
+
+    // Everything but the wall-clock planting time
+    let grown = |app: &App| {
+        let mut plant = serde_json::to_value(app.current_plant.as_ref().unwrap()).unwrap();
+        plant.as_object_mut().unwrap().remove("planted_at");
+        plant
+    };
+    assert!(fast.current_plant.as_ref().unwrap().days_alive > 60);
+    assert_eq!(grown(&fast), grown(&ticked));
+
+    // No frames were drawn: the animation held and only the summary toast is up
+    assert_eq!(fast.animation_frame, 0);
+    assert!(fast.toast.as_ref().unwrap().text.starts_with("Fast-forwarded"));
+}
+
+#[test]
+fn closed_games_only_grow_up_to_the_offline_cap() {
+    let mut app = app_with_stage(GrowthStage::Vegetative);
+    let day = app.current_plant.as_ref().unwrap().days_alive;
+    let now = chrono::Utc::now();
+
+    // A month away with offline growth off: nothing happens, the clock restarts
+    app.last_tick = now - chrono::TimeDelta::days(30);
+    assert_eq!(app.resume(now), 0);
+    assert_eq!(app.current_plant.as_ref().unwrap().days_alive, day);
+    assert_eq!(app.last_tick, now);
+
+    // With a 3-day cap the same month only counts for 3 game days
+    app.offline_growth_days = 3;
+    app.last_tick = now - chrono::TimeDelta::days(30);
+    assert_eq!(app.resume(now), 3);
+    assert_eq!(app.current_plant.as_ref().unwrap().days_alive, day + 3);
+    assert_eq!(app.last_tick, now);
+}
+
+#[test]
+fn toggle_auto_harvest_flips_flag() {
+    let app = App::new(ColorDepth::Basic16);
+    let before = app.auto_harvest;
+
+    let app = update(app, Message::ToggleAutoHarvest);
+    assert_eq!(app.auto_harvest, !before);
+
+    let app = update(app, Message::ToggleAutoHarvest);
+    assert_eq!(app.auto_harvest, before);
+}
+
+#[test]
+fn auto_harvest_waits_the_configured_delay() {
+    const DAY: f32 = 24.0 * 3600.0 / 130_000.0;
+    let app = app_with_stage(GrowthStage::ReadyToHarvest);
+    assert_eq!(app.auto_harvest_delay_days, 10);
+
+    // The delay only changes while auto mode is on
+    let app = update(app, Message::AdjustAutoHarvestDelay(-1));
+    assert_eq!(app.auto_harvest_delay_days, 10);
+    let app = update(app, Message::ToggleAutoHarvest);
+    let mut app = (0..5).fold(app, |app, _| update(app, Message::AdjustAutoHarvestDelay(-1)));
+    assert_eq!(app.auto_harvest_delay_days, 5);
+
+    app.update_time(3.0 * DAY);
+    assert_eq!(app.total_harvests, 0, "still curing on the plant");
+    app.update_time(3.0 * DAY);
+    assert_eq!(app.total_harvests, 1);
+
+    let app = (0..50).fold(app, |app, _| update(app, Message::AdjustAutoHarvestDelay(1)));
+    assert_eq!(app.auto_harvest_delay_days, 30, "the delay tops out");
+}
+
+#[test]
+fn auto_harvest_closes_an_open_preview() {
+    const DAY: f32 = 24.0 * 3600.0 / 130_000.0;
+    let mut app = update(app_with_stage(GrowthStage::ReadyToHarvest), Message::ToggleAutoHarvest);
+    app.auto_harvest_delay_days = 0;
+    let mut app = update(app, Message::PreviewHarvest);
+    assert!(app.pending_harvest.is_some());
+
+    app.update_time(DAY);
+    assert_eq!(app.total_harvests, 1);
+    assert!(app.pending_harvest.is_none(), "the preview was of the plant auto-harvest took");
+
+    // Confirming afterwards must not cut the new seedling
+    let app = update(app, Message::ConfirmHarvest);
+    assert_eq!(app.total_harvests, 1);
+    assert_eq!(app.current_plant.as_ref().unwrap().stage, GrowthStage::Seed);
+}
+
+#[test]
+fn ready_plants_pause_until_harvested_when_asked() {
+    let mut app = update(app_with_stage(GrowthStage::ReadyToHarvest), Message::TogglePauseWhenReady);
+    assert!(app.pause_when_ready && app.paused_for_harvest());
+
+    let hours = app.current_plant.as_ref().unwrap().total_hours_elapsed;
+    app.update_time(1.0);
+    assert_eq!(app.current_plant.as_ref().unwrap().total_hours_elapsed, hours);
+
+    // Auto-harvest takes care of ready plants itself, so the clock runs again
+    let mut app = update(app, Message::ToggleAutoHarvest);
+    assert!(!app.paused_for_harvest());
+    app.update_time(1.0);
+    assert!(app.current_plant.as_ref().unwrap().total_hours_elapsed > hours);
+}
+
+#[test]
+fn cycle_visual_mode_is_noop_on_16_colors() {
+    let app = update(App::new(ColorDepth::Basic16), Message::CycleVisualMode);
+    assert_eq!(app.visual_mode, VisualMode::Normal);
+    assert!(app.toast.unwrap().text.contains("truecolor"), "the key explains why nothing changed");
+}
+
+#[test]
+fn crossing_into_a_new_stage_is_logged_once() {
+    const HOUR: f32 = 3600.0 / 130_000.0;
+    let mut app = app_with_stage(GrowthStage::Seedling);
+    let plant = app.current_plant.as_mut().unwrap();
+    plant.total_hours_elapsed = plant.stage_start_day(GrowthStage::Vegetative) as f32 * 24.0 - 1.0;
+    let name = plant.strain_name.clone();
+
+    app.update_time(2.0 * HOUR);
+    let entered = format!("{} entered {}", name, GrowthStage::Vegetative.as_str());
+    assert_eq!(app.events.last().map(|e| e.text.as_str()), Some(entered.as_str()));
+
+    // Growing on within the stage adds nothing
+    let logged = app.events.len();
+    app.update_time(2.0 * HOUR);
+    assert_eq!(app.events.len(), logged);
+}
+
+#[test]
+fn becoming_ready_flashes_the_border_until_it_runs_out() {
+    // Tick measures real time, so each one is dated a frame after the last
+    let tick = |mut app: App| {
+        app.last_tick = Utc::now() - chrono::Duration::milliseconds(20);
+        update(app, Message::Tick)
+    };
+    let mut app = app_with_stage(GrowthStage::Flowering);
+    let plant = app.current_plant.as_mut().unwrap();
+    plant.total_hours_elapsed = plant.stage_start_day(GrowthStage::ReadyToHarvest) as f32 * 24.0 - 0.1;
+
+    let mut app = tick(app);
+    assert_eq!(app.current_plant.as_ref().unwrap().stage, GrowthStage::ReadyToHarvest);
+    let frames = app.harvest_flash_frames;
+    assert!(frames > 0, "the flash starts on the stage change");
+
+    for left in (0..frames).rev() {
+        app = tick(app);
+        assert_eq!(app.harvest_flash_frames, left);
+    }
+    // Staying ready does not flash again
+    assert_eq!(tick(app).harvest_flash_frames, 0);
+}
+
+#[test]
+fn visual_modes_can_be_picked_directly() {
+    let app = update(App::new(ColorDepth::TrueColor), Message::SetVisualMode(VisualMode::Matrix));
+    assert_eq!(app.visual_mode, VisualMode::Matrix);
+    let app = update(app, Message::SetVisualMode(VisualMode::Normal));
+    assert_eq!(app.visual_mode, VisualMode::Normal);
+
+    let app = update(App::new(ColorDepth::Indexed256), Message::SetVisualMode(VisualMode::Rainbow));
+    assert_eq!(app.visual_mode, VisualMode::Rainbow, "256-color terminals get the modes too");
+
+    let app = update(App::new(ColorDepth::Basic16), Message::SetVisualMode(VisualMode::Zen));
+    assert_eq!(app.visual_mode, VisualMode::Normal);
+    assert!(app.toast.unwrap().text.contains("truecolor"));
+
+    // Normal mode is what 16-color terminals already show, so picking it is no refusal
+    let app = update(App::new(ColorDepth::Basic16), Message::SetVisualMode(VisualMode::Normal));
+    assert_eq!(app.visual_mode, VisualMode::Normal);
+    assert!(app.toast.is_none());
+}
+
+#[test]
+fn exports_wait_for_the_event_loop_and_need_a_plant() {
+    let app = update(App::new(ColorDepth::Basic16), Message::ExportDiary);
+    assert_eq!(app.export_requested, Some(Export::Diary));
+
+    let mut app = App::new(ColorDepth::Basic16);
+    app.current_plant = None;
+    let app = update(app, Message::ExportPlantArt);
+    assert_eq!(app.export_requested, None);
+    assert!(app.toast.unwrap().text.contains("No plant"));
+}